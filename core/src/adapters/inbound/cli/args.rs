@@ -0,0 +1,388 @@
+//! Command-line arguments for the `desk-buddy` binary.
+use crate::adapters::inbound::views::parse_history_bound;
+use crate::bootstrap::ConfigLayer;
+use crate::domain::export::{ExportTable, ImportStrategy};
+use crate::domain::history::{HistoryCursor, HistoryQuery, SortOrder, DEFAULT_HISTORY_PAGE_SIZE};
+use crate::domain::hydration::IntakeSource;
+use crate::domain::stats::StatsPeriod;
+use chrono::{DateTime, Utc};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
+use tracing::level_filters::LevelFilter;
+
+/// Desk Buddy: hydration reminders and a daily journal.
+#[derive(Debug, Parser)]
+#[command(name = "desk-buddy", version)]
+pub struct Cli {
+    /// Config file to read instead of desk-buddy/config.toml in the user config directory.
+    #[arg(long, global = true, value_name = "PATH", env = "DESK_BUDDY_CONFIG")]
+    pub config: Option<PathBuf>,
+    /// SQLite database to use [default: sqlite://desk-buddy.db].
+    #[arg(long, global = true, value_name = "URL")]
+    pub database_url: Option<String>,
+    /// Most verbose log output on stderr: off, error, warn, info, debug or trace.
+    #[arg(long, global = true, value_name = "LEVEL", value_parser = parse_log_level)]
+    pub log_level: Option<LevelFilter>,
+    /// Print machine-readable JSON instead of text.
+    #[arg(long, global = true)]
+    pub json: bool,
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+impl Cli {
+    /// Returns the options given on the command line, which override every config layer.
+    pub fn config_overrides(&self) -> ConfigLayer {
+        ConfigLayer {
+            database_url: self.database_url.clone(),
+            log_level: self.log_level,
+            ..ConfigLayer::default()
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Run reminders in the foreground until Ctrl-C.
+    Run(RunArgs),
+    /// Fire one reminder now.
+    Remind,
+    /// Read or change settings.
+    #[command(subcommand)]
+    Config(ConfigCommand),
+    /// Record what you drank.
+    #[command(subcommand)]
+    Log(LogCommand),
+    /// Show today's progress and statistics for recent days.
+    Stats(StatsArgs),
+    /// List achievements and progress towards them.
+    Achievements,
+    /// List past records.
+    #[command(subcommand)]
+    History(HistoryCommand),
+    /// Write settings and history as a JSON document or per-table CSV files.
+    Export(ExportArgs),
+    /// Merge a JSON export into this database.
+    Import(ImportArgs),
+    /// Manage the database.
+    #[command(subcommand)]
+    Db(DbCommand),
+}
+
+#[derive(Debug, Default, Args)]
+pub struct RunArgs {
+    /// Also serve the newline-delimited JSON control protocol on this Unix socket.
+    #[cfg(unix)]
+    #[arg(long, value_name = "PATH")]
+    pub ipc_socket: Option<PathBuf>,
+    /// Also serve the HTTP API on this loopback address (for example 127.0.0.1:7878).
+    #[cfg(feature = "http")]
+    #[arg(long, value_name = "ADDR")]
+    pub http: Option<std::net::SocketAddr>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ConfigCommand {
+    /// Print one setting, or all of them.
+    Get { key: Option<ConfigKey> },
+    /// Change a setting; `none` clears optional settings.
+    Set { key: ConfigKey, value: String },
+}
+
+/// Settings reachable from `config get` and `config set`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ConfigKey {
+    /// Minutes between reminders.
+    Interval,
+    /// Five-field cron schedule used instead of the interval.
+    Cron,
+    /// Daily window in which reminders fire: `09:00-17:00` or `09:00-17:00@mon,tue,wed`.
+    ActiveWindow,
+    /// Daily span without reminders: `22:00-07:00`, `none`, or `default` for the configured default.
+    QuietHours,
+    /// What to do with reminders missed during sleep: fire_once, skip or fire_all.
+    CatchUp,
+    /// Daily intake goal in ml.
+    DailyGoal,
+    /// Local hour (0-23) at which a new hydration day begins.
+    DayStartHour,
+}
+
+impl ConfigKey {
+    pub const ALL: [Self; 7] = [
+        Self::Interval,
+        Self::Cron,
+        Self::ActiveWindow,
+        Self::QuietHours,
+        Self::CatchUp,
+        Self::DailyGoal,
+        Self::DayStartHour,
+    ];
+
+    /// Returns the key as typed on the command line.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Interval => "interval",
+            Self::Cron => "cron",
+            Self::ActiveWindow => "active-window",
+            Self::QuietHours => "quiet-hours",
+            Self::CatchUp => "catch-up",
+            Self::DailyGoal => "daily-goal",
+            Self::DayStartHour => "day-start-hour",
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+pub enum LogCommand {
+    /// Log a drink, in ml.
+    Drink {
+        amount_ml: u32,
+        /// What you drank from: glass, bottle, cup or other.
+        #[arg(long, value_parser = parse_intake_source)]
+        source: Option<IntakeSource>,
+    },
+}
+
+#[derive(Debug, Args)]
+pub struct StatsArgs {
+    /// Days to summarize, ending today: day, week or month.
+    #[arg(long, default_value = "week", value_parser = parse_stats_period)]
+    pub period: StatsPeriod,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum HistoryCommand {
+    /// Recent journal entries, newest first.
+    Journal {
+        #[arg(long, default_value_t = 10)]
+        limit: u32,
+    },
+    /// Logged reminders and how they were answered.
+    Reminders(HistoryArgs),
+    /// Logged drinks.
+    Intake(HistoryArgs),
+}
+
+/// Range, order and page of a history listing.
+#[derive(Debug, Args)]
+pub struct HistoryArgs {
+    /// First local day (YYYY-MM-DD) or RFC 3339 instant to include.
+    #[arg(long, value_name = "WHEN", value_parser = parse_history_start)]
+    pub from: Option<DateTime<Utc>>,
+    /// Last local day to include, or an RFC 3339 instant to stop before.
+    #[arg(long, value_name = "WHEN", value_parser = parse_history_end)]
+    pub to: Option<DateTime<Utc>>,
+    /// Listing order: newest-first or oldest-first [default: newest-first].
+    #[arg(long, value_parser = parse_sort_order)]
+    pub order: Option<SortOrder>,
+    /// Entries per page (at most 500).
+    #[arg(long, default_value_t = DEFAULT_HISTORY_PAGE_SIZE)]
+    pub limit: u32,
+    /// Continue from the `next_cursor` printed with the previous page.
+    #[arg(long, value_parser = parse_history_cursor)]
+    pub cursor: Option<HistoryCursor>,
+}
+
+impl HistoryArgs {
+    /// Returns the page these arguments ask for.
+    pub fn query(&self) -> HistoryQuery {
+        HistoryQuery {
+            from: self.from,
+            until: self.to,
+            order: self.order.unwrap_or_default(),
+            limit: self.limit,
+            after: self.cursor,
+        }
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct ExportArgs {
+    /// Output format.
+    #[arg(long, value_enum, default_value_t)]
+    pub format: ExportFormat,
+    /// JSON file to write (standard output when omitted), or the directory for CSV files.
+    #[arg(long, short, required_if_eq("format", "csv"))]
+    pub output: Option<PathBuf>,
+    /// Only export this table; repeat for more [default: all].
+    #[arg(long = "table", value_name = "TABLE", value_parser = parse_export_table)]
+    pub tables: Vec<ExportTable>,
+}
+
+impl ExportArgs {
+    /// Returns the tables to export, every one when none were named.
+    pub fn tables(&self) -> Vec<ExportTable> {
+        if self.tables.is_empty() {
+            ExportTable::ALL.to_vec()
+        } else {
+            self.tables.clone()
+        }
+    }
+}
+
+/// Formats `export` can write.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    /// One versioned document with settings and every table.
+    #[default]
+    Json,
+    /// One `<table>.csv` file per table.
+    Csv,
+}
+
+#[derive(Debug, Args)]
+pub struct ImportArgs {
+    /// JSON document written by `export`.
+    pub file: PathBuf,
+    /// What to do with records that differ from stored ones at the same time or date:
+    /// skip-duplicates, overwrite or append [default: skip-duplicates].
+    #[arg(long, value_parser = parse_import_strategy)]
+    pub strategy: Option<ImportStrategy>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum DbCommand {
+    /// Apply pending schema migrations.
+    Migrate,
+}
+
+fn parse_log_level(raw: &str) -> Result<LevelFilter, String> {
+    raw.parse::<LevelFilter>()
+        .map_err(|_| "expected off, error, warn, info, debug or trace".to_string())
+}
+
+fn parse_intake_source(raw: &str) -> Result<IntakeSource, String> {
+    raw.parse::<IntakeSource>()
+        .map_err(|()| "expected glass, bottle, cup or other".to_string())
+}
+
+fn parse_history_start(raw: &str) -> Result<DateTime<Utc>, String> {
+    parse_history_bound(raw, false).ok_or_else(|| "expected YYYY-MM-DD or an RFC 3339 time".to_string())
+}
+
+fn parse_history_end(raw: &str) -> Result<DateTime<Utc>, String> {
+    parse_history_bound(raw, true).ok_or_else(|| "expected YYYY-MM-DD or an RFC 3339 time".to_string())
+}
+
+fn parse_stats_period(raw: &str) -> Result<StatsPeriod, String> {
+    raw.parse::<StatsPeriod>()
+        .map_err(|()| "expected day, week or month".to_string())
+}
+
+fn parse_sort_order(raw: &str) -> Result<SortOrder, String> {
+    raw.parse::<SortOrder>()
+        .map_err(|()| "expected newest-first or oldest-first".to_string())
+}
+
+fn parse_export_table(raw: &str) -> Result<ExportTable, String> {
+    raw.parse::<ExportTable>()
+        .map_err(|()| "expected reminder-logs, water-intake or journal-entries".to_string())
+}
+
+fn parse_import_strategy(raw: &str) -> Result<ImportStrategy, String> {
+    raw.parse::<ImportStrategy>()
+        .map_err(|()| "expected skip-duplicates, overwrite or append".to_string())
+}
+
+fn parse_history_cursor(raw: &str) -> Result<HistoryCursor, String> {
+    raw.parse::<HistoryCursor>()
+        .map_err(|()| "expected a next_cursor value from an earlier page".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn arguments_are_consistent() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn parses_nested_subcommands_and_global_flags() {
+        let cli: Cli = Cli::try_parse_from(["desk-buddy", "log", "drink", "250", "--source", "Bottle", "--json"])
+            .expect("valid arguments");
+
+        assert!(cli.json);
+        assert!(matches!(
+            cli.command,
+            Command::Log(LogCommand::Drink {
+                amount_ml: 250,
+                source: Some(IntakeSource::Bottle)
+            })
+        ));
+        let config: Cli = Cli::try_parse_from(["desk-buddy", "config", "set", "quiet-hours", "none"])
+            .expect("valid arguments");
+        assert!(matches!(
+            config.command,
+            Command::Config(ConfigCommand::Set {
+                key: ConfigKey::QuietHours,
+                ..
+            })
+        ));
+        assert!(Cli::try_parse_from(["desk-buddy", "config", "set", "volume", "3"]).is_err());
+        assert!(Cli::try_parse_from(["desk-buddy", "log", "drink", "250", "--source", "bucket"]).is_err());
+    }
+
+    #[test]
+    fn history_arguments_become_a_query() {
+        let cli: Cli = Cli::try_parse_from([
+            "desk-buddy",
+            "history",
+            "intake",
+            "--from",
+            "2026-03-01T08:00:00Z",
+            "--to",
+            "2026-03-02T00:00:00+01:00",
+            "--order",
+            "oldest-first",
+            "--limit",
+            "20",
+            "--cursor",
+            "1772352000000000000.7",
+        ])
+        .expect("valid arguments");
+        let Command::History(HistoryCommand::Intake(args)) = cli.command else {
+            panic!("expected history intake");
+        };
+
+        let at = |raw: &str| DateTime::parse_from_rfc3339(raw).expect("valid time").with_timezone(&Utc);
+        assert_eq!(
+            args.query(),
+            HistoryQuery {
+                from: Some(at("2026-03-01T08:00:00Z")),
+                until: Some(at("2026-03-01T23:00:00Z")),
+                order: SortOrder::OldestFirst,
+                limit: 20,
+                after: Some(HistoryCursor {
+                    at: at("2026-03-01T08:00:00Z"),
+                    id: 7,
+                }),
+            }
+        );
+        assert!(Cli::try_parse_from(["desk-buddy", "history", "reminders", "--from", "March"]).is_err());
+        assert!(Cli::try_parse_from(["desk-buddy", "history", "reminders", "--cursor", "next"]).is_err());
+    }
+
+    #[test]
+    fn global_options_become_config_overrides() {
+        let cli: Cli = Cli::try_parse_from(["desk-buddy", "stats", "--log-level", "debug", "--database-url", "sqlite::memory:"])
+            .expect("valid arguments");
+
+        assert_eq!(
+            cli.config_overrides(),
+            ConfigLayer {
+                database_url: Some("sqlite::memory:".to_string()),
+                log_level: Some(LevelFilter::DEBUG),
+                ..ConfigLayer::default()
+            }
+        );
+        assert_eq!(
+            Cli::try_parse_from(["desk-buddy", "stats"]).expect("valid arguments").config_overrides(),
+            ConfigLayer::default()
+        );
+        assert!(Cli::try_parse_from(["desk-buddy", "stats", "--log-level", "loud"]).is_err());
+    }
+}
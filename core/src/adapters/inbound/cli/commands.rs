@@ -0,0 +1,567 @@
+//! Runs parsed CLI commands against a core runtime and prints text or JSON.
+use super::args::{
+    Cli, Command, ConfigCommand, ConfigKey, DbCommand, ExportArgs, ExportFormat, HistoryCommand, ImportArgs, LogCommand,
+    RunArgs,
+};
+use super::error::CliResult;
+use super::settings::{self, Settings};
+use crate::adapters::inbound::views::{
+    AchievementView, GoalView, HistoryPageView, ImportCountsView, ImportSummaryView, IntakeView, JournalEntryView,
+    ProgressView, ReminderEventView, ReminderLogView, StatsView,
+};
+use crate::adapters::outbound::export::{read_json_export, CsvExportWriter, JsonExportWriter};
+use crate::adapters::outbound::sqlite::{connect_sqlite, run_migrations, schema_version};
+use crate::api::CoreApi;
+use crate::bootstrap::{shutdown_signal, CoreBuilder, CoreConfig, CoreOptions, CoreRuntime, ShutdownSignal};
+use crate::domain::export::{ExportSummary, ExportTable, ImportDocument, ImportStrategy, ImportSummary};
+use crate::domain::hydration::{HydrationProgress, IntakeSource, ReminderEvent, WaterIntake};
+use crate::error::CoreResult;
+use chrono::{DateTime, Local, Utc};
+use serde::Serialize;
+use serde_json::json;
+use sqlx::SqlitePool;
+use std::fs::File;
+use std::future::Future;
+use std::io::{BufReader, BufWriter, Stdout};
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::Arc;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+
+/// Where command results go: text for people or one JSON document per result.
+#[derive(Debug, Clone, Copy)]
+struct Output {
+    json: bool,
+}
+
+impl Output {
+    fn emit<T: Serialize>(self, value: &T, text: impl FnOnce() -> String) -> CliResult<()> {
+        if self.json {
+            println!("{}", serde_json::to_string(value)?);
+        } else {
+            println!("{}", text());
+        }
+        Ok(())
+    }
+}
+
+/// Merges the config file, environment and command-line options.
+pub fn load_config(cli: &Cli) -> CliResult<CoreConfig> {
+    Ok(CoreConfig::load(cli.config.as_deref(), cli.config_overrides())?)
+}
+
+/// Runs one command to completion with the options from `load_config`.
+pub async fn run(cli: Cli, config: CoreConfig) -> CliResult<()> {
+    let output: Output = Output { json: cli.json };
+    let mut options: CoreOptions = config.options;
+    // JSON mode keeps standard output machine-readable, so built-in sinks stay off.
+    if cli.json {
+        options.reminder_sinks.clear();
+    }
+
+    match cli.command {
+        Command::Db(DbCommand::Migrate) => migrate(&options.database_url, output).await,
+        command => {
+            let runtime: CoreRuntime = CoreBuilder::new(options).build().await?;
+            run_command(runtime, command, output).await
+        }
+    }
+}
+
+/// Runs `command`, then shuts the runtime down whether or not the command succeeded.
+async fn run_command(runtime: CoreRuntime, command: Command, output: Output) -> CliResult<()> {
+    if let Command::Run(args) = command {
+        return run_foreground(Arc::new(runtime), args, output).await;
+    }
+
+    let result: CliResult<()> = execute(runtime.api(), command, output).await;
+    let shut_down: CoreResult<()> = runtime.shutdown().await;
+    result?;
+    shut_down?;
+    Ok(())
+}
+
+async fn execute(api: &CoreApi, command: Command, output: Output) -> CliResult<()> {
+    match command {
+        Command::Remind => {
+            let event: ReminderEvent = api.trigger_hydration_reminder_once().await?;
+            // In text mode the configured sinks already showed the reminder.
+            if output.json {
+                output.emit(&ReminderEventView::from(&event), String::new)?;
+            }
+        }
+        Command::Config(ConfigCommand::Get { key }) => {
+            let settings: Settings = Settings::load(api).await?;
+            let keys: Vec<ConfigKey> = key.map_or_else(|| ConfigKey::ALL.to_vec(), |key: ConfigKey| vec![key]);
+            output.emit(&settings.json_object(&keys), || settings_text(&settings, &keys))?;
+        }
+        Command::Config(ConfigCommand::Set { key, value }) => {
+            settings::apply(api, key, &value).await?;
+            let settings: Settings = Settings::load(api).await?;
+            output.emit(&settings.json_object(&[key]), || settings_text(&settings, &[key]))?;
+        }
+        Command::Log(LogCommand::Drink { amount_ml, source }) => {
+            let intake: WaterIntake = api.log_water_intake(amount_ml, source).await?;
+            let progress: HydrationProgress = api.hydration_progress_today().await?;
+            let view: serde_json::Value = json!({
+                "intake": IntakeView::from(&intake),
+                "progress": ProgressView::from(progress),
+            });
+            output.emit(&view, || {
+                let source: String = intake
+                    .source
+                    .map(|source: IntakeSource| format!(" from a {}", source.as_str()))
+                    .unwrap_or_default();
+                format!("Logged {} ml{source}. {}", intake.amount_ml, progress_text(progress))
+            })?;
+        }
+        Command::Stats(args) => {
+            let progress: HydrationProgress = api.hydration_progress_today().await?;
+            let reminders: i64 = api.hydration_log_count().await?;
+            let stats: StatsView = StatsView::from(&api.hydration_stats(args.period).await?);
+            let text: String = format!(
+                "{}\nReminders logged: {reminders}\n{}",
+                progress_text(progress),
+                stats_text(&stats)
+            );
+            let view: serde_json::Value = json!({
+                "progress": ProgressView::from(progress),
+                "goal": GoalView::from(api.hydration_goal().await?),
+                "reminders_logged": reminders,
+                "stats": stats,
+            });
+            output.emit(&view, || text)?;
+        }
+        Command::Achievements => {
+            let achievements: Vec<AchievementView> = api
+                .achievements()
+                .await?
+                .iter()
+                .map(AchievementView::from)
+                .collect();
+            output.emit(&achievements, || achievements_text(&achievements))?;
+        }
+        Command::History(HistoryCommand::Journal { limit }) => {
+            let entries: Vec<JournalEntryView> = api
+                .journal_entries(limit)
+                .await?
+                .into_iter()
+                .map(JournalEntryView::from)
+                .collect();
+            output.emit(&entries, || journal_text(&entries))?;
+        }
+        Command::History(HistoryCommand::Reminders(args)) => {
+            let page: HistoryPageView<ReminderLogView> =
+                HistoryPageView::new(&api.hydration_reminder_history(args.query()).await?);
+            output.emit(&page, || reminder_history_text(&page))?;
+        }
+        Command::History(HistoryCommand::Intake(args)) => {
+            let page: HistoryPageView<IntakeView> = HistoryPageView::new(&api.water_intake_history(args.query()).await?);
+            output.emit(&page, || intake_history_text(&page))?;
+        }
+        Command::Export(args) => export(api, args, output).await?,
+        Command::Import(args) => import(api, args, output).await?,
+        Command::Run(_) | Command::Db(DbCommand::Migrate) => unreachable!("handled before the command runs"),
+    }
+
+    Ok(())
+}
+
+/// Starts reminders and any requested servers, then shuts down cleanly on SIGINT or SIGTERM.
+async fn run_foreground(runtime: Arc<CoreRuntime>, args: RunArgs, output: Output) -> CliResult<()> {
+    let signal: Pin<Box<dyn Future<Output = ShutdownSignal> + Send>> = match shutdown_signal() {
+        Ok(signal) => Box::pin(signal),
+        Err(err) => {
+            runtime.shutdown().await?;
+            return Err(err.into());
+        }
+    };
+    let mut servers: Servers = match Servers::start(&runtime, args).await {
+        Ok(servers) => servers,
+        Err(err) => {
+            runtime.shutdown().await?;
+            return Err(err.into());
+        }
+    };
+    let printer: Option<JoinHandle<()>> = output
+        .json
+        .then(|| tokio::spawn(print_reminders(runtime.subscribe_reminder_events())));
+    eprintln!("Desk Buddy is running; press Ctrl-C to stop.");
+
+    let stopped: CoreResult<ShutdownSignal> = runtime
+        .run_until(async {
+            let signal: ShutdownSignal = signal.await;
+            // Stop taking requests before reminders stop and the database closes.
+            if let Err(err) = servers.stop().await {
+                tracing::warn!("could not stop servers cleanly: {err}");
+            }
+            signal
+        })
+        .await;
+    // The reminder loop can also end on its own after a fatal error.
+    let servers_stopped: CoreResult<()> = servers.stop().await;
+    if let Some(printer) = printer {
+        printer.abort();
+    }
+
+    let signal: ShutdownSignal = stopped?;
+    servers_stopped?;
+    eprintln!("Desk Buddy stopped on {}.", signal.as_str());
+    Ok(())
+}
+
+/// Inbound servers requested for `run`.
+#[derive(Default)]
+struct Servers {
+    #[cfg(unix)]
+    ipc: Option<crate::adapters::inbound::ipc::IpcServer>,
+    #[cfg(feature = "http")]
+    http: Option<crate::adapters::inbound::http::HttpServer>,
+}
+
+impl Servers {
+    /// Starts each requested server; ones already started are stopped if a later one fails.
+    async fn start(runtime: &Arc<CoreRuntime>, args: RunArgs) -> CoreResult<Self> {
+        #[cfg_attr(not(any(unix, feature = "http")), allow(unused_mut))]
+        let mut servers: Self = Self::default();
+        #[cfg(unix)]
+        if let Some(path) = args.ipc_socket {
+            servers.ipc = Some(crate::adapters::inbound::ipc::IpcServer::start(path, runtime.clone()).await?);
+        }
+        #[cfg(feature = "http")]
+        if let Some(addr) = args.http {
+            match crate::adapters::inbound::http::HttpServer::start(addr, runtime.clone()).await {
+                Ok(server) => servers.http = Some(server),
+                Err(err) => {
+                    servers.stop().await?;
+                    return Err(err);
+                }
+            }
+        }
+        #[cfg(not(any(unix, feature = "http")))]
+        let _: (&Arc<CoreRuntime>, RunArgs) = (runtime, args);
+        Ok(servers)
+    }
+
+    /// Stops every running server; stopping twice is a no-op.
+    async fn stop(&mut self) -> CoreResult<()> {
+        #[cfg(feature = "http")]
+        if let Some(server) = self.http.as_mut() {
+            server.stop().await?;
+        }
+        #[cfg(unix)]
+        if let Some(server) = self.ipc.as_mut() {
+            server.stop().await?;
+        }
+        Ok(())
+    }
+}
+
+/// Prints every reminder as one JSON line.
+async fn print_reminders(mut events: broadcast::Receiver<ReminderEvent>) {
+    loop {
+        match events.recv().await {
+            Ok(event) => match serde_json::to_string(&ReminderEventView::from(&event)) {
+                Ok(line) => println!("{line}"),
+                Err(err) => tracing::warn!("could not print reminder {}: {err}", event.id),
+            },
+            Err(broadcast::error::RecvError::Lagged(missed)) => {
+                tracing::warn!("skipped {missed} reminder(s) while printing");
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+        }
+    }
+}
+
+/// Streams the export to standard output, a JSON file or a directory of CSV files.
+async fn export(api: &CoreApi, args: ExportArgs, output: Output) -> CliResult<()> {
+    let tables: Vec<ExportTable> = args.tables();
+    let (summary, files): (ExportSummary, Vec<PathBuf>) = match (args.format, args.output) {
+        (ExportFormat::Json, None) => {
+            let mut writer: JsonExportWriter<BufWriter<Stdout>> = JsonExportWriter::new(BufWriter::new(std::io::stdout()));
+            let _: ExportSummary = api.export(&tables, &mut writer).await?;
+            return Ok(());
+        }
+        (ExportFormat::Json, Some(path)) => {
+            let file: File = File::create(&path)?;
+            let mut writer: JsonExportWriter<BufWriter<File>> = JsonExportWriter::new(BufWriter::new(file));
+            (api.export(&tables, &mut writer).await?, vec![path])
+        }
+        (ExportFormat::Csv, Some(directory)) => {
+            let mut writer: CsvExportWriter = CsvExportWriter::new(directory)?;
+            let summary: ExportSummary = api.export(&tables, &mut writer).await?;
+            (summary, writer.files().to_vec())
+        }
+        (ExportFormat::Csv, None) => unreachable!("clap requires --output for CSV"),
+    };
+
+    let value: serde_json::Value = json!({
+        "files": files,
+        "reminder_logs": summary.reminder_logs,
+        "water_intake": summary.water_intake,
+        "journal_entries": summary.journal_entries,
+    });
+    output.emit(&value, || {
+        let files: Vec<String> = files.iter().map(|path: &PathBuf| path.display().to_string()).collect();
+        format!(
+            "Exported {} reminder(s), {} drink(s) and {} journal entries to {}",
+            summary.reminder_logs,
+            summary.water_intake,
+            summary.journal_entries,
+            files.join(", ")
+        )
+    })
+}
+
+/// Reads a JSON export and merges it, printing the counts per table.
+async fn import(api: &CoreApi, args: ImportArgs, output: Output) -> CliResult<()> {
+    let document: ImportDocument = read_json_export(BufReader::new(File::open(&args.file)?))?;
+    let strategy: ImportStrategy = args.strategy.unwrap_or_default();
+    let summary: ImportSummary = api.import(&document, strategy).await?;
+
+    let view: ImportSummaryView = ImportSummaryView::new(strategy, &summary);
+    let tables: [(&str, ImportCountsView); 4] = [
+        ("settings", view.settings),
+        (ExportTable::ReminderLogs.as_str(), view.reminder_logs),
+        (ExportTable::WaterIntake.as_str(), view.water_intake),
+        (ExportTable::JournalEntries.as_str(), view.journal_entries),
+    ];
+    output.emit(&view, || {
+        let mut text: String = format!("Imported {} with {}:", args.file.display(), strategy.as_str());
+        for (table, counts) in tables {
+            text.push_str(&format!(
+                "\n  {table}: {} inserted, {} skipped, {} conflicting",
+                counts.inserted, counts.skipped, counts.conflicting
+            ));
+        }
+        text
+    })
+}
+
+async fn migrate(database_url: &str, output: Output) -> CliResult<()> {
+    let pool: SqlitePool = connect_sqlite(database_url).await?;
+    let from_version: i64 = schema_version(&pool).await?;
+    run_migrations(&pool).await?;
+    let to_version: i64 = schema_version(&pool).await?;
+    pool.close().await;
+
+    output.emit(&json!({ "from_version": from_version, "to_version": to_version }), || {
+        if from_version == to_version {
+            format!("Database is already at schema version {to_version}.")
+        } else {
+            format!("Migrated database from schema version {from_version} to {to_version}.")
+        }
+    })
+}
+
+fn settings_text(settings: &Settings, keys: &[ConfigKey]) -> String {
+    keys.iter()
+        .map(|key: &ConfigKey| format!("{} = {}", key.as_str(), settings.text(*key)))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn progress_text(progress: HydrationProgress) -> String {
+    format!(
+        "Today: {} / {} ml ({:.0}%), {} ml to go",
+        progress.consumed_ml, progress.goal_ml, progress.percent, progress.remaining_ml
+    )
+}
+
+fn stats_text(stats: &StatsView) -> String {
+    let mut lines: Vec<String> = vec![
+        format!(
+            "{} to {}: {} ml, {:.0} ml a day on average",
+            stats.first_day, stats.last_day, stats.total_ml, stats.average_daily_ml
+        ),
+        format!(
+            "Goal met on {:.0}% of days; current streak {} days (longest {})",
+            stats.goal_hit_percent, stats.current_streak_days, stats.longest_streak_days
+        ),
+    ];
+    let busiest: Option<(usize, f64)> = stats
+        .average_ml_by_hour
+        .iter()
+        .copied()
+        .enumerate()
+        .filter(|(_, average_ml): &(usize, f64)| *average_ml > 0.0)
+        .max_by(|left: &(usize, f64), right: &(usize, f64)| left.1.total_cmp(&right.1));
+    if let Some((hour, average_ml)) = busiest {
+        lines.push(format!("Busiest hour: {hour:02}:00 ({average_ml:.0} ml a day on average)"));
+    }
+    lines.push(match stats.reminders.drink_response_percent {
+        Some(percent) => format!(
+            "Reminders: {} fired, {} answered with a drink ({percent:.0}%)",
+            stats.reminders.fired, stats.reminders.drank
+        ),
+        None => "Reminders: none fired".to_string(),
+    });
+    lines.join("\n")
+}
+
+fn achievements_text(achievements: &[AchievementView]) -> String {
+    achievements
+        .iter()
+        .map(|achievement: &AchievementView| {
+            let status: String = match achievement.unlocked_at {
+                Some(unlocked_at) => format!("unlocked {}", unlocked_at.with_timezone(&Local).format("%Y-%m-%d")),
+                None => format!("{}/{}", achievement.progress, achievement.threshold),
+            };
+            let mark: char = if achievement.unlocked_at.is_some() { 'x' } else { ' ' };
+            format!("[{mark}] {}: {} ({status})", achievement.title, achievement.description)
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn journal_text(entries: &[JournalEntryView]) -> String {
+    if entries.is_empty() {
+        return "No journal entries yet.".to_string();
+    }
+
+    entries
+        .iter()
+        .map(|entry: &JournalEntryView| format!("{}  {}", entry.entry_date, entry.body))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn reminder_history_text(page: &HistoryPageView<ReminderLogView>) -> String {
+    history_text(page, "No reminders in this range.", |reminder: &ReminderLogView| {
+        let outcome: String = match (reminder.outcome, reminder.snooze_minutes) {
+            (Some(outcome), Some(minutes)) => format!("{outcome} {minutes}m"),
+            (Some(outcome), None) => outcome.to_string(),
+            (None, _) => "unanswered".to_string(),
+        };
+        format!("{}  {outcome}  {}", local_time(reminder.triggered_at), reminder.message)
+    })
+}
+
+fn intake_history_text(page: &HistoryPageView<IntakeView>) -> String {
+    history_text(page, "No drinks in this range.", |intake: &IntakeView| {
+        let source: String = intake.source.map(|source: &str| format!("  {source}")).unwrap_or_default();
+        format!("{}  {} ml{source}", local_time(intake.consumed_at), intake.amount_ml)
+    })
+}
+
+/// Lists one line per item, then how to fetch the next page when there is one.
+fn history_text<T>(page: &HistoryPageView<T>, empty: &str, line: impl Fn(&T) -> String) -> String {
+    if page.items.is_empty() {
+        return empty.to_string();
+    }
+
+    let mut lines: Vec<String> = page.items.iter().map(line).collect();
+    if let Some(cursor) = &page.next_cursor {
+        lines.push(format!("More: --cursor {cursor}"));
+    }
+    lines.join("\n")
+}
+
+fn local_time(at: DateTime<Utc>) -> String {
+    at.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::journal::{EntryDate, JournalEntry};
+    use crate::domain::stats::{
+        DailyIntake, GoalStreaks, HourlyIntake, HydrationStats, ReminderResponseCounts, StatsPeriod,
+    };
+    use chrono::NaiveDate;
+
+    #[test]
+    fn text_output_summarizes_progress_and_entries() {
+        assert_eq!(
+            progress_text(HydrationProgress::new(750, 2_000)),
+            "Today: 750 / 2000 ml (38%), 1250 ml to go"
+        );
+        assert_eq!(journal_text(&[]), "No journal entries yet.");
+
+        let entry: JournalEntry = JournalEntry {
+            id: 1,
+            entry_date: EntryDate::new(NaiveDate::from_ymd_opt(2026, 3, 1).expect("valid date")),
+            prompt_id: None,
+            body: "calm day".to_string(),
+            created_at: chrono::DateTime::UNIX_EPOCH,
+            updated_at: chrono::DateTime::UNIX_EPOCH,
+        };
+        assert_eq!(journal_text(&[JournalEntryView::from(entry)]), "2026-03-01  calm day");
+
+        let drink: IntakeView = IntakeView {
+            id: 3,
+            amount_ml: 250,
+            consumed_at: chrono::DateTime::UNIX_EPOCH,
+            source: Some("glass"),
+        };
+        let page: HistoryPageView<IntakeView> = HistoryPageView {
+            items: vec![drink],
+            next_cursor: Some("0.3".to_string()),
+        };
+        let expected: String = format!("{}  250 ml  glass\nMore: --cursor 0.3", local_time(chrono::DateTime::UNIX_EPOCH));
+        assert_eq!(intake_history_text(&page), expected);
+        let empty: HistoryPageView<ReminderLogView> = HistoryPageView {
+            items: Vec::new(),
+            next_cursor: None,
+        };
+        assert_eq!(reminder_history_text(&empty), "No reminders in this range.");
+    }
+
+    #[test]
+    fn achievements_text_marks_unlocked_ones() {
+        let achievement = |unlocked_at: Option<chrono::DateTime<Utc>>| AchievementView {
+            id: "goal_streak_7",
+            title: "Hydrated week",
+            description: "Meet your hydration goal 7 days in a row",
+            progress: 3,
+            threshold: 7,
+            unlocked_at,
+        };
+        let unlocked_at: chrono::DateTime<Utc> = chrono::DateTime::UNIX_EPOCH + chrono::Duration::days(1);
+
+        assert_eq!(
+            achievements_text(&[achievement(None), achievement(Some(unlocked_at))]),
+            format!(
+                "[ ] Hydrated week: Meet your hydration goal 7 days in a row (3/7)\n\
+                 [x] Hydrated week: Meet your hydration goal 7 days in a row (unlocked {})",
+                unlocked_at.with_timezone(&Local).format("%Y-%m-%d")
+            )
+        );
+    }
+
+    #[test]
+    fn stats_text_summarizes_the_period() {
+        let today: NaiveDate = NaiveDate::from_ymd_opt(2026, 3, 7).expect("valid date");
+        let stats: HydrationStats = HydrationStats::new(
+            StatsPeriod::Week,
+            today,
+            2_000,
+            &[DailyIntake {
+                day: today,
+                total_ml: 2_100,
+            }],
+            &[HourlyIntake {
+                hour: 9,
+                total_ml: 700,
+            }],
+            GoalStreaks {
+                current_days: 1,
+                longest_days: 4,
+            },
+            ReminderResponseCounts {
+                fired: 4,
+                drank: 1,
+                ..ReminderResponseCounts::default()
+            },
+        );
+
+        assert_eq!(
+            stats_text(&StatsView::from(&stats)),
+            "2026-03-01 to 2026-03-07: 2100 ml, 300 ml a day on average\n\
+             Goal met on 14% of days; current streak 1 days (longest 4)\n\
+             Busiest hour: 09:00 (100 ml a day on average)\n\
+             Reminders: 4 fired, 1 answered with a drink (25%)"
+        );
+    }
+}
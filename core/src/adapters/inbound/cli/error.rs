@@ -0,0 +1,47 @@
+//! Errors reported by CLI commands.
+use crate::error::CoreError;
+use std::fmt::{Display, Formatter};
+
+pub type CliResult<T> = Result<T, CliError>;
+
+#[derive(Debug)]
+pub enum CliError {
+    Core(CoreError),
+    /// A `config set` value that does not parse for its key.
+    InvalidSetting {
+        key: &'static str,
+        value: String,
+        expected: &'static str,
+    },
+}
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Core(err) => write!(f, "{err}"),
+            Self::InvalidSetting { key, value, expected } => {
+                write!(f, "invalid value {value:?} for {key}: expected {expected}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+impl From<CoreError> for CliError {
+    fn from(value: CoreError) -> Self {
+        Self::Core(value)
+    }
+}
+
+impl From<std::io::Error> for CliError {
+    fn from(value: std::io::Error) -> Self {
+        Self::Core(CoreError::Io(value))
+    }
+}
+
+impl From<serde_json::Error> for CliError {
+    fn from(value: serde_json::Error) -> Self {
+        Self::Core(CoreError::Io(value.into()))
+    }
+}
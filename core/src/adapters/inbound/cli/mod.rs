@@ -0,0 +1,9 @@
+//! Command-line adapter behind the `desk-buddy` binary.
+mod args;
+mod commands;
+mod error;
+mod settings;
+
+pub use args::{Cli, Command, ConfigCommand, ConfigKey, DbCommand, ExportArgs, ExportFormat, HistoryCommand, ImportArgs, LogCommand, RunArgs};
+pub use commands::{load_config, run};
+pub use error::{CliError, CliResult};
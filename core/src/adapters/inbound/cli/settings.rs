@@ -0,0 +1,247 @@
+//! Text and JSON forms of the settings reachable from `config get` and `config set`.
+use super::args::ConfigKey;
+use super::error::{CliError, CliResult};
+use crate::adapters::inbound::views::{
+    parse_clock_time, parse_weekday, ActiveWindowView, GoalView, QuietHoursView, ReminderConfigView,
+};
+use crate::api::CoreApi;
+use crate::domain::hydration::{ActiveWindow, CatchUpPolicy, QuietHours, ReminderConfig, WeekdaySet};
+use chrono::{NaiveTime, Weekday};
+use serde_json::{json, Map, Value};
+
+/// Current reminder settings and goal, read together for display.
+pub(super) struct Settings {
+    config: ReminderConfigView,
+    goal: GoalView,
+}
+
+impl Settings {
+    pub(super) async fn load(api: &CoreApi) -> CliResult<Self> {
+        Ok(Self {
+            config: ReminderConfigView::from(api.hydration_reminder_config().await?),
+            goal: GoalView::from(api.hydration_goal().await?),
+        })
+    }
+
+    /// Returns `key`'s value as typed JSON (`null` when unset).
+    pub(super) fn json(&self, key: ConfigKey) -> Value {
+        match key {
+            ConfigKey::Interval => json!(self.config.interval_minutes),
+            ConfigKey::Cron => json!(self.config.cron),
+            ConfigKey::ActiveWindow => json!(self.config.active_window),
+            ConfigKey::QuietHours => json!(self.config.quiet_hours),
+            ConfigKey::CatchUp => json!(self.config.catch_up),
+            ConfigKey::DailyGoal => json!(self.goal.daily_goal_ml),
+            ConfigKey::DayStartHour => json!(self.goal.day_start_hour),
+        }
+    }
+
+    /// Returns `{key: value}` for the given keys.
+    pub(super) fn json_object(&self, keys: &[ConfigKey]) -> Value {
+        let entries: Map<String, Value> = keys
+            .iter()
+            .map(|key: &ConfigKey| (key.as_str().to_string(), self.json(*key)))
+            .collect();
+        Value::Object(entries)
+    }
+
+    /// Returns `key`'s value in the same form `config set` accepts (`none` when unset).
+    pub(super) fn text(&self, key: ConfigKey) -> String {
+        match key {
+            ConfigKey::Interval => self.config.interval_minutes.to_string(),
+            ConfigKey::Cron => self.config.cron.clone().unwrap_or_else(|| NONE.to_string()),
+            ConfigKey::ActiveWindow => self
+                .config
+                .active_window
+                .as_ref()
+                .map_or_else(|| NONE.to_string(), active_window_text),
+            ConfigKey::QuietHours => self
+                .config
+                .quiet_hours
+                .as_ref()
+                .map_or_else(|| NONE.to_string(), |hours: &QuietHoursView| format!("{}-{}", hours.start, hours.end)),
+            ConfigKey::CatchUp => self.config.catch_up.to_string(),
+            ConfigKey::DailyGoal => self.goal.daily_goal_ml.to_string(),
+            ConfigKey::DayStartHour => self.goal.day_start_hour.to_string(),
+        }
+    }
+}
+
+/// Value that clears an optional setting.
+const NONE: &str = "none";
+/// Value that forgets saved quiet hours so the configured default applies again.
+const DEFAULT: &str = "default";
+
+fn active_window_text(window: &ActiveWindowView) -> String {
+    if window.days.len() == 7 {
+        format!("{}-{}", window.start, window.end)
+    } else {
+        format!("{}-{}@{}", window.start, window.end, window.days.join(","))
+    }
+}
+
+/// Parses `raw` for `key` and saves it through the API.
+pub(super) async fn apply(api: &CoreApi, key: ConfigKey, raw: &str) -> CliResult<()> {
+    let raw: &str = raw.trim();
+    let cleared: bool = raw.eq_ignore_ascii_case(NONE);
+    match key {
+        ConfigKey::Interval => {
+            let minutes: u64 = parse_number(key, raw, "a number of minutes")?;
+            let _: u64 = api.set_hydration_interval_minutes(minutes).await?;
+        }
+        ConfigKey::Cron => {
+            let _: ReminderConfig = api
+                .set_hydration_cron_schedule((!cleared).then_some(raw))
+                .await?;
+        }
+        ConfigKey::ActiveWindow => {
+            let window: Option<ActiveWindow> = if cleared { None } else { Some(parse_active_window(raw)?) };
+            let _: ReminderConfig = api.set_hydration_active_window(window).await?;
+        }
+        ConfigKey::QuietHours if raw.eq_ignore_ascii_case(DEFAULT) => {
+            let _: ReminderConfig = api.reset_hydration_quiet_hours().await?;
+        }
+        ConfigKey::QuietHours => {
+            let quiet_hours: Option<QuietHours> = if cleared {
+                None
+            } else {
+                let (start, end): (NaiveTime, NaiveTime) = parse_span(key, raw, QUIET_HOURS_FORMAT)?;
+                Some(QuietHours::new(start, end).ok_or_else(|| invalid(key, raw, QUIET_HOURS_FORMAT))?)
+            };
+            let _: ReminderConfig = api.set_hydration_quiet_hours(quiet_hours).await?;
+        }
+        ConfigKey::CatchUp => {
+            let policy: CatchUpPolicy = raw
+                .parse::<CatchUpPolicy>()
+                .map_err(|()| invalid(key, raw, "fire_once, skip or fire_all"))?;
+            let _: ReminderConfig = api.set_hydration_catch_up_policy(policy).await?;
+        }
+        ConfigKey::DailyGoal => {
+            let goal_ml: u32 = parse_number(key, raw, "a number of ml")?;
+            let _: u32 = api.set_daily_hydration_goal_ml(goal_ml).await?;
+        }
+        ConfigKey::DayStartHour => {
+            let hour: u32 = parse_number(key, raw, "an hour from 0 to 23")?;
+            let _: u32 = api.set_hydration_day_start_hour(hour).await?;
+        }
+    }
+
+    Ok(())
+}
+
+const ACTIVE_WINDOW_FORMAT: &str = "HH:MM-HH:MM, optionally followed by @mon,tue,...";
+const QUIET_HOURS_FORMAT: &str = "HH:MM-HH:MM";
+
+fn parse_active_window(raw: &str) -> CliResult<ActiveWindow> {
+    let key: ConfigKey = ConfigKey::ActiveWindow;
+    let (span, days): (&str, Option<&str>) = match raw.split_once('@') {
+        Some((span, days)) => (span, Some(days)),
+        None => (raw, None),
+    };
+    let (start, end): (NaiveTime, NaiveTime) = parse_span(key, span, ACTIVE_WINDOW_FORMAT)?;
+    let days: WeekdaySet = match days {
+        Some(days) => WeekdaySet::from_days(
+            &days
+                .split(',')
+                .map(|day: &str| parse_weekday(day).ok_or_else(|| invalid(key, raw, ACTIVE_WINDOW_FORMAT)))
+                .collect::<CliResult<Vec<Weekday>>>()?,
+        ),
+        None => WeekdaySet::ALL,
+    };
+
+    ActiveWindow::new(start, end, days).ok_or_else(|| invalid(key, raw, "a start before the end, on at least one day"))
+}
+
+fn parse_span(key: ConfigKey, raw: &str, expected: &'static str) -> CliResult<(NaiveTime, NaiveTime)> {
+    raw.split_once('-')
+        .and_then(|(start, end): (&str, &str)| Some((parse_clock_time(start)?, parse_clock_time(end)?)))
+        .ok_or_else(|| invalid(key, raw, expected))
+}
+
+fn parse_number<T: std::str::FromStr>(key: ConfigKey, raw: &str, expected: &'static str) -> CliResult<T> {
+    raw.parse::<T>().map_err(|_| invalid(key, raw, expected))
+}
+
+fn invalid(key: ConfigKey, raw: &str, expected: &'static str) -> CliError {
+    CliError::InvalidSetting {
+        key: key.as_str(),
+        value: raw.to_string(),
+        expected,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::settings_service::ReminderDefaults;
+    use crate::bootstrap::{CoreBuilder, CoreOptions, CoreRuntime};
+
+    #[tokio::test]
+    async fn values_round_trip_through_text() -> CliResult<()> {
+        let runtime: CoreRuntime = CoreBuilder::new(CoreOptions::in_memory()).build().await?;
+        let api: &CoreApi = runtime.api();
+        let values: [(ConfigKey, &str); 7] = [
+            (ConfigKey::Interval, "45"),
+            (ConfigKey::Cron, "0,30 9-17 * * 1-5"),
+            (ConfigKey::ActiveWindow, "09:00-17:30@mon,wed,fri"),
+            (ConfigKey::QuietHours, "22:00-07:00"),
+            (ConfigKey::CatchUp, "fire_all"),
+            (ConfigKey::DailyGoal, "1800"),
+            (ConfigKey::DayStartHour, "4"),
+        ];
+
+        for (key, value) in values {
+            apply(api, key, value).await?;
+            assert_eq!(Settings::load(api).await?.text(key), value);
+        }
+        apply(api, ConfigKey::ActiveWindow, "08:00-16:00").await?;
+        apply(api, ConfigKey::QuietHours, "none").await?;
+        let settings: Settings = Settings::load(api).await?;
+        assert_eq!(settings.text(ConfigKey::ActiveWindow), "08:00-16:00");
+        assert_eq!(settings.json(ConfigKey::QuietHours), Value::Null);
+        assert_eq!(
+            settings.json_object(&[ConfigKey::Interval, ConfigKey::CatchUp]),
+            json!({"interval": 45, "catch-up": "fire_all"})
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn quiet_hours_turn_off_or_return_to_the_default() -> CliResult<()> {
+        let at = |hour: u32| NaiveTime::from_hms_opt(hour, 0, 0).expect("valid time");
+        let options: CoreOptions = CoreOptions {
+            reminder_defaults: ReminderDefaults {
+                quiet_hours: QuietHours::new(at(22), at(7)),
+                ..ReminderDefaults::default()
+            },
+            ..CoreOptions::in_memory()
+        };
+        let runtime: CoreRuntime = CoreBuilder::new(options).build().await?;
+        let api: &CoreApi = runtime.api();
+
+        apply(api, ConfigKey::QuietHours, "none").await?;
+        assert_eq!(Settings::load(api).await?.text(ConfigKey::QuietHours), "none");
+        apply(api, ConfigKey::QuietHours, "default").await?;
+        assert_eq!(Settings::load(api).await?.text(ConfigKey::QuietHours), "22:00-07:00");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn invalid_values_name_the_key() -> CliResult<()> {
+        let runtime: CoreRuntime = CoreBuilder::new(CoreOptions::in_memory()).build().await?;
+
+        let err: CliError = apply(runtime.api(), ConfigKey::ActiveWindow, "9-5")
+            .await
+            .expect_err("malformed window");
+        assert_eq!(
+            err.to_string(),
+            "invalid value \"9-5\" for active-window: expected HH:MM-HH:MM, optionally followed by @mon,tue,..."
+        );
+        assert!(apply(runtime.api(), ConfigKey::Interval, "soon").await.is_err());
+        assert!(matches!(
+            apply(runtime.api(), ConfigKey::DayStartHour, "24").await,
+            Err(CliError::Core(_))
+        ));
+        Ok(())
+    }
+}
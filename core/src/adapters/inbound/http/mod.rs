@@ -0,0 +1,5 @@
+//! Local HTTP adapter: REST endpoints over `CoreApi` and a Server-Sent Events stream.
+mod routes;
+mod server;
+
+pub use server::HttpServer;
@@ -0,0 +1,743 @@
+//! REST routes for every `CoreApi` operation, JSON export and import, and the
+//! `/events` reminder stream.
+use super::server::shutdown_requested;
+use crate::adapters::inbound::views::{
+    intake_source, parse_clock_time, parse_history_bound, parse_weekday, AchievementView, GoalView, HistoryPageView,
+    ImportSummaryView, IntakeView, JournalEntryView, JournalPromptView, PauseLengthRequest, PauseView, ProgressView,
+    ReminderConfigView, ReminderEventView, ReminderLogView, SchedulerStatusView, StatsView,
+};
+use crate::adapters::outbound::export::{read_json_export, JsonExportWriter};
+use crate::bootstrap::CoreRuntime;
+use crate::domain::achievements::Achievement;
+use crate::domain::export::{ExportSummary, ExportTable, ImportDocument, ImportStrategy, ImportSummary};
+use crate::domain::history::{
+    HistoryCursor, HistoryPage, HistoryQuery, ReminderLogEntry, SortOrder, DEFAULT_HISTORY_PAGE_SIZE,
+};
+use crate::domain::hydration::{
+    ActiveWindow, CatchUpPolicy, IntakeSource, QuietHours, ReminderConfig, ReminderEvent, ReminderPause,
+    ReminderResponse, WaterIntake, WeekdaySet,
+};
+use crate::domain::journal::{EntryDate, JournalEntry};
+use crate::domain::stats::{HydrationStats, StatsPeriod};
+use crate::error::CoreError;
+use axum::body::Bytes;
+use axum::extract::rejection::{BytesRejection, JsonRejection, PathRejection, QueryRejection};
+use axum::extract::{DefaultBodyLimit, Path, Query, Request, State};
+use axum::http::{header, HeaderMap, HeaderName, HeaderValue, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{delete, get, post, put};
+use axum::{Json, Router};
+use chrono::{DateTime, NaiveTime, Utc, Weekday};
+use futures_util::Stream;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::convert::Infallible;
+use std::net::IpAddr;
+use std::sync::Arc;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::{broadcast, watch};
+
+/// Journal entries listed when `limit` is not given.
+const DEFAULT_JOURNAL_LIMIT: u32 = 30;
+/// Largest export document `POST /import` accepts.
+const MAX_IMPORT_BYTES: usize = 64 * 1024 * 1024;
+
+#[derive(Clone)]
+pub(super) struct AppState {
+    pub(super) runtime: Arc<CoreRuntime>,
+    pub(super) shutdown_rx: watch::Receiver<bool>,
+}
+
+pub(super) fn router(state: AppState) -> Router {
+    Router::new()
+        .route("/reminders/trigger", post(trigger_reminder))
+        .route("/reminders/count", get(reminder_count))
+        .route("/reminders/{id}/response", post(respond_to_reminder))
+        .route("/reminders/config", get(reminder_config))
+        .route("/reminders/interval", put(set_interval))
+        .route("/reminders/cron", put(set_cron_schedule))
+        .route("/reminders/active-window", put(set_active_window))
+        .route(
+            "/reminders/quiet-hours",
+            put(set_quiet_hours).delete(reset_quiet_hours),
+        )
+        .route("/reminders/catch-up", put(set_catch_up_policy))
+        .route(
+            "/reminders/pause",
+            get(reminder_pause).put(pause_reminders).delete(resume_reminders),
+        )
+        .route("/scheduler/status", get(scheduler_status))
+        .route("/intake", post(log_intake))
+        .route("/intake/last", delete(undo_last_intake))
+        .route("/intake/today", get(intake_today))
+        .route("/goal", get(goal))
+        .route("/goal/daily", put(set_daily_goal))
+        .route("/goal/day-start", put(set_day_start_hour))
+        .route("/progress/today", get(progress_today))
+        .route("/stats", get(stats))
+        .route("/achievements", get(achievements))
+        .route("/history/reminders", get(reminder_history))
+        .route("/history/intake", get(intake_history))
+        .route("/journal", get(journal_entries))
+        .route("/journal/prompt/today", get(todays_prompt))
+        .route("/journal/today", put(save_todays_entry))
+        .route("/journal/{date}", get(journal_entry).put(save_entry))
+        .route("/export", get(export))
+        .route("/import", post(import).layer(DefaultBodyLimit::max(MAX_IMPORT_BYTES)))
+        .route("/events", get(reminder_events))
+        .layer(middleware::from_fn(require_loopback_host))
+        .with_state(state)
+}
+
+/// Rejects requests whose `Host`, or `Origin` when sent, is not a loopback name, so a
+/// web page cannot reach the API through DNS rebinding.
+async fn require_loopback_host(request: Request, next: Next) -> Response {
+    let headers: &HeaderMap = request.headers();
+    let header_value = |name: HeaderName| headers.get(name).and_then(|value: &HeaderValue| value.to_str().ok());
+    let host_allowed: bool = header_value(header::HOST).is_some_and(is_loopback_authority);
+    let origin_allowed: bool = header_value(header::ORIGIN).is_none_or(|origin: &str| {
+        origin
+            .split_once("://")
+            .is_some_and(|(_scheme, authority): (&str, &str)| is_loopback_authority(authority))
+    });
+    if !(host_allowed && origin_allowed) {
+        return HttpError {
+            status: StatusCode::FORBIDDEN,
+            message: "requests must be addressed to localhost or a loopback address".to_string(),
+        }
+        .into_response();
+    }
+
+    next.run(request).await
+}
+
+/// Returns true for `localhost` or a loopback IP, with or without a port.
+fn is_loopback_authority(authority: &str) -> bool {
+    let host: &str = match authority.strip_prefix('[') {
+        Some(bracketed) => bracketed.split_once(']').map_or(bracketed, |(host, _port): (&str, &str)| host),
+        None => authority.rsplit_once(':').map_or(authority, |(host, _port): (&str, &str)| host),
+    };
+    host.eq_ignore_ascii_case("localhost") || host.parse::<IpAddr>().is_ok_and(|ip: IpAddr| ip.is_loopback())
+}
+
+/// Error reply: a status code and `{"error": message}`.
+#[derive(Debug)]
+pub(super) struct HttpError {
+    status: StatusCode,
+    message: String,
+}
+
+impl HttpError {
+    fn bad_request(message: impl Into<String>) -> Self {
+        Self {
+            status: StatusCode::BAD_REQUEST,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<CoreError> for HttpError {
+    fn from(err: CoreError) -> Self {
+        let status: StatusCode = match &err {
+            CoreError::EmptyJournalEntry
+            | CoreError::InvalidIntakeAmount(_)
+            | CoreError::InvalidDayStartHour(_)
+            | CoreError::InvalidIntervalMinutes(_)
+            | CoreError::InvalidSnoozeMinutes(_)
+            | CoreError::InvalidPauseMinutes(_)
+            | CoreError::InvalidCronExpression(_)
+            | CoreError::InvalidHistoryRange { .. }
+            | CoreError::InvalidHistoryCursor(_)
+            | CoreError::UnsupportedExport { .. }
+            | CoreError::InvalidImport { .. } => StatusCode::BAD_REQUEST,
+            CoreError::ReminderNotFound(_) | CoreError::JobNotFound(_) => StatusCode::NOT_FOUND,
+            CoreError::SchedulerAlreadyRunning | CoreError::JobAlreadyExists(_) => StatusCode::CONFLICT,
+            CoreError::ReminderDeliveryFailed { .. } => StatusCode::BAD_GATEWAY,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        Self {
+            status,
+            message: err.to_string(),
+        }
+    }
+}
+
+impl From<JsonRejection> for HttpError {
+    fn from(rejection: JsonRejection) -> Self {
+        Self {
+            status: rejection.status(),
+            message: rejection.body_text(),
+        }
+    }
+}
+
+impl From<BytesRejection> for HttpError {
+    fn from(rejection: BytesRejection) -> Self {
+        Self {
+            status: rejection.status(),
+            message: rejection.body_text(),
+        }
+    }
+}
+
+impl From<PathRejection> for HttpError {
+    fn from(rejection: PathRejection) -> Self {
+        Self::bad_request(rejection.body_text())
+    }
+}
+
+impl From<QueryRejection> for HttpError {
+    fn from(rejection: QueryRejection) -> Self {
+        Self::bad_request(rejection.body_text())
+    }
+}
+
+impl IntoResponse for HttpError {
+    fn into_response(self) -> Response {
+        (self.status, Json(json!({ "error": self.message }))).into_response()
+    }
+}
+
+type HttpResult<T> = Result<T, HttpError>;
+
+async fn trigger_reminder(State(state): State<AppState>) -> HttpResult<Json<ReminderEventView>> {
+    let event: ReminderEvent = state.runtime.api().trigger_hydration_reminder_once().await?;
+    Ok(Json(ReminderEventView::from(&event)))
+}
+
+async fn reminder_count(State(state): State<AppState>) -> HttpResult<Json<Value>> {
+    let count: i64 = state.runtime.api().hydration_log_count().await?;
+    Ok(Json(json!({ "count": count })))
+}
+
+/// Body of `POST /reminders/{id}/response`, tagged by `response`.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "response", rename_all = "snake_case")]
+enum ResponseRequest {
+    Drank {
+        #[serde(default)]
+        amount_ml: Option<u32>,
+    },
+    Snoozed {
+        minutes: u32,
+    },
+    Dismissed,
+}
+
+impl From<ResponseRequest> for ReminderResponse {
+    fn from(request: ResponseRequest) -> Self {
+        match request {
+            ResponseRequest::Drank { amount_ml } => Self::Drank { amount_ml },
+            ResponseRequest::Snoozed { minutes } => Self::Snoozed { minutes },
+            ResponseRequest::Dismissed => Self::Dismissed,
+        }
+    }
+}
+
+async fn respond_to_reminder(
+    State(state): State<AppState>,
+    id: Result<Path<i64>, PathRejection>,
+    body: Result<Json<ResponseRequest>, JsonRejection>,
+) -> HttpResult<StatusCode> {
+    let Path(reminder_id) = id?;
+    let Json(request) = body?;
+    state
+        .runtime
+        .api()
+        .respond_to_hydration_reminder(reminder_id, request.into())
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn reminder_config(State(state): State<AppState>) -> HttpResult<Json<ReminderConfigView>> {
+    let config: ReminderConfig = state.runtime.api().hydration_reminder_config().await?;
+    Ok(Json(ReminderConfigView::from(config)))
+}
+
+#[derive(Debug, Deserialize)]
+struct IntervalRequest {
+    minutes: u64,
+}
+
+async fn set_interval(
+    State(state): State<AppState>,
+    body: Result<Json<IntervalRequest>, JsonRejection>,
+) -> HttpResult<Json<Value>> {
+    let Json(request) = body?;
+    let interval_minutes: u64 = state
+        .runtime
+        .api()
+        .set_hydration_interval_minutes(request.minutes)
+        .await?;
+    Ok(Json(json!({ "interval_minutes": interval_minutes })))
+}
+
+/// Body of `PUT /reminders/cron`; a `null` expression returns to the fixed interval.
+#[derive(Debug, Deserialize)]
+struct CronRequest {
+    expression: Option<String>,
+}
+
+async fn set_cron_schedule(
+    State(state): State<AppState>,
+    body: Result<Json<CronRequest>, JsonRejection>,
+) -> HttpResult<Json<ReminderConfigView>> {
+    let Json(request) = body?;
+    let config: ReminderConfig = state
+        .runtime
+        .api()
+        .set_hydration_cron_schedule(request.expression.as_deref())
+        .await?;
+    Ok(Json(ReminderConfigView::from(config)))
+}
+
+/// Body of `PUT /reminders/active-window`; `days` defaults to every day.
+#[derive(Debug, Deserialize)]
+struct ActiveWindowRequest {
+    start: String,
+    end: String,
+    #[serde(default)]
+    days: Option<Vec<String>>,
+}
+
+impl TryFrom<ActiveWindowRequest> for ActiveWindow {
+    type Error = HttpError;
+
+    fn try_from(request: ActiveWindowRequest) -> Result<Self, Self::Error> {
+        let days: WeekdaySet = match request.days {
+            Some(names) => WeekdaySet::from_days(
+                &names
+                    .iter()
+                    .map(|name: &String| weekday(name))
+                    .collect::<HttpResult<Vec<Weekday>>>()?,
+            ),
+            None => WeekdaySet::ALL,
+        };
+        ActiveWindow::new(clock_time(&request.start)?, clock_time(&request.end)?, days).ok_or_else(|| {
+            HttpError::bad_request("active window must start before it ends and include at least one day")
+        })
+    }
+}
+
+/// A `null` body lifts the window.
+async fn set_active_window(
+    State(state): State<AppState>,
+    body: Result<Json<Option<ActiveWindowRequest>>, JsonRejection>,
+) -> HttpResult<Json<ReminderConfigView>> {
+    let Json(request) = body?;
+    let active_window: Option<ActiveWindow> = request.map(ActiveWindow::try_from).transpose()?;
+    let config: ReminderConfig = state
+        .runtime
+        .api()
+        .set_hydration_active_window(active_window)
+        .await?;
+    Ok(Json(ReminderConfigView::from(config)))
+}
+
+#[derive(Debug, Deserialize)]
+struct QuietHoursRequest {
+    start: String,
+    end: String,
+}
+
+/// A `null` body turns quiet hours off.
+async fn set_quiet_hours(
+    State(state): State<AppState>,
+    body: Result<Json<Option<QuietHoursRequest>>, JsonRejection>,
+) -> HttpResult<Json<ReminderConfigView>> {
+    let Json(request) = body?;
+    let quiet_hours: Option<QuietHours> = match request {
+        Some(request) => Some(
+            QuietHours::new(clock_time(&request.start)?, clock_time(&request.end)?)
+                .ok_or_else(|| HttpError::bad_request("quiet hours must not start and end at the same time"))?,
+        ),
+        None => None,
+    };
+    let config: ReminderConfig = state.runtime.api().set_hydration_quiet_hours(quiet_hours).await?;
+    Ok(Json(ReminderConfigView::from(config)))
+}
+
+async fn reset_quiet_hours(State(state): State<AppState>) -> HttpResult<Json<ReminderConfigView>> {
+    let config: ReminderConfig = state.runtime.api().reset_hydration_quiet_hours().await?;
+    Ok(Json(ReminderConfigView::from(config)))
+}
+
+#[derive(Debug, Deserialize)]
+struct CatchUpRequest {
+    policy: String,
+}
+
+async fn set_catch_up_policy(
+    State(state): State<AppState>,
+    body: Result<Json<CatchUpRequest>, JsonRejection>,
+) -> HttpResult<Json<ReminderConfigView>> {
+    let Json(request) = body?;
+    let policy: CatchUpPolicy = request.policy.parse::<CatchUpPolicy>().map_err(|()| {
+        HttpError::bad_request(format!(
+            "unknown catch-up policy {:?} (expected fire_once, skip or fire_all)",
+            request.policy
+        ))
+    })?;
+    let config: ReminderConfig = state.runtime.api().set_hydration_catch_up_policy(policy).await?;
+    Ok(Json(ReminderConfigView::from(config)))
+}
+
+async fn reminder_pause(State(state): State<AppState>) -> HttpResult<Json<Option<PauseView>>> {
+    let pause: Option<ReminderPause> = state.runtime.api().hydration_reminder_pause().await?;
+    Ok(Json(pause.map(PauseView::from)))
+}
+
+/// Body of `PUT /reminders/pause`; without a `length` the pause lasts until resumed.
+#[derive(Debug, Deserialize)]
+struct PauseRequest {
+    #[serde(default)]
+    length: PauseLengthRequest,
+}
+
+async fn pause_reminders(
+    State(state): State<AppState>,
+    body: Result<Json<PauseRequest>, JsonRejection>,
+) -> HttpResult<Json<PauseView>> {
+    let Json(request) = body?;
+    let pause: ReminderPause = state.runtime.pause_reminders(request.length.into()).await?;
+    Ok(Json(PauseView::from(pause)))
+}
+
+async fn resume_reminders(State(state): State<AppState>) -> HttpResult<Json<ReminderConfigView>> {
+    let config: ReminderConfig = state.runtime.api().resume_hydration_reminders().await?;
+    Ok(Json(ReminderConfigView::from(config)))
+}
+
+async fn scheduler_status(State(state): State<AppState>) -> Json<SchedulerStatusView> {
+    Json(SchedulerStatusView::from(&state.runtime.scheduler_status().await))
+}
+
+#[derive(Debug, Deserialize)]
+struct IntakeRequest {
+    amount_ml: u32,
+    #[serde(default, deserialize_with = "intake_source")]
+    source: Option<IntakeSource>,
+}
+
+async fn log_intake(
+    State(state): State<AppState>,
+    body: Result<Json<IntakeRequest>, JsonRejection>,
+) -> HttpResult<(StatusCode, Json<IntakeView>)> {
+    let Json(request) = body?;
+    let intake: WaterIntake = state
+        .runtime
+        .api()
+        .log_water_intake(request.amount_ml, request.source)
+        .await?;
+    Ok((StatusCode::CREATED, Json(IntakeView::from(&intake))))
+}
+
+async fn undo_last_intake(State(state): State<AppState>) -> HttpResult<Json<Option<IntakeView>>> {
+    let removed: Option<WaterIntake> = state.runtime.api().undo_last_water_intake().await?;
+    Ok(Json(removed.as_ref().map(IntakeView::from)))
+}
+
+async fn intake_today(State(state): State<AppState>) -> HttpResult<Json<Value>> {
+    let total_ml: u64 = state.runtime.api().water_intake_today_ml().await?;
+    Ok(Json(json!({ "total_ml": total_ml })))
+}
+
+async fn goal(State(state): State<AppState>) -> HttpResult<Json<GoalView>> {
+    Ok(Json(GoalView::from(state.runtime.api().hydration_goal().await?)))
+}
+
+#[derive(Debug, Deserialize)]
+struct DailyGoalRequest {
+    daily_goal_ml: u32,
+}
+
+async fn set_daily_goal(
+    State(state): State<AppState>,
+    body: Result<Json<DailyGoalRequest>, JsonRejection>,
+) -> HttpResult<Json<Value>> {
+    let Json(request) = body?;
+    let daily_goal_ml: u32 = state
+        .runtime
+        .api()
+        .set_daily_hydration_goal_ml(request.daily_goal_ml)
+        .await?;
+    Ok(Json(json!({ "daily_goal_ml": daily_goal_ml })))
+}
+
+#[derive(Debug, Deserialize)]
+struct DayStartRequest {
+    day_start_hour: u32,
+}
+
+async fn set_day_start_hour(
+    State(state): State<AppState>,
+    body: Result<Json<DayStartRequest>, JsonRejection>,
+) -> HttpResult<Json<Value>> {
+    let Json(request) = body?;
+    let day_start_hour: u32 = state
+        .runtime
+        .api()
+        .set_hydration_day_start_hour(request.day_start_hour)
+        .await?;
+    Ok(Json(json!({ "day_start_hour": day_start_hour })))
+}
+
+async fn progress_today(State(state): State<AppState>) -> HttpResult<Json<ProgressView>> {
+    Ok(Json(ProgressView::from(state.runtime.api().hydration_progress_today().await?)))
+}
+
+async fn todays_prompt(State(state): State<AppState>) -> HttpResult<Json<Option<JournalPromptView>>> {
+    let prompt: Option<JournalPromptView> = state
+        .runtime
+        .api()
+        .todays_journal_prompt()
+        .await?
+        .map(JournalPromptView::from);
+    Ok(Json(prompt))
+}
+
+#[derive(Debug, Deserialize)]
+struct JournalRequest {
+    body: String,
+}
+
+async fn save_todays_entry(
+    State(state): State<AppState>,
+    body: Result<Json<JournalRequest>, JsonRejection>,
+) -> HttpResult<Json<JournalEntryView>> {
+    let Json(request) = body?;
+    let entry: JournalEntry = state.runtime.api().save_todays_journal_entry(&request.body).await?;
+    Ok(Json(JournalEntryView::from(entry)))
+}
+
+async fn save_entry(
+    State(state): State<AppState>,
+    date: Result<Path<String>, PathRejection>,
+    body: Result<Json<JournalRequest>, JsonRejection>,
+) -> HttpResult<Json<JournalEntryView>> {
+    let entry_date: EntryDate = entry_date(date?)?;
+    let Json(request) = body?;
+    let entry: JournalEntry = state
+        .runtime
+        .api()
+        .save_journal_entry(entry_date, &request.body)
+        .await?;
+    Ok(Json(JournalEntryView::from(entry)))
+}
+
+async fn journal_entry(
+    State(state): State<AppState>,
+    date: Result<Path<String>, PathRejection>,
+) -> HttpResult<Json<Option<JournalEntryView>>> {
+    let entry_date: EntryDate = entry_date(date?)?;
+    let entry: Option<JournalEntry> = state.runtime.api().journal_entry(entry_date).await?;
+    Ok(Json(entry.map(JournalEntryView::from)))
+}
+
+#[derive(Debug, Deserialize)]
+struct JournalQuery {
+    #[serde(default)]
+    limit: Option<u32>,
+}
+
+async fn journal_entries(
+    State(state): State<AppState>,
+    query: Result<Query<JournalQuery>, QueryRejection>,
+) -> HttpResult<Json<Vec<JournalEntryView>>> {
+    let Query(query) = query?;
+    let entries: Vec<JournalEntry> = state
+        .runtime
+        .api()
+        .journal_entries(query.limit.unwrap_or(DEFAULT_JOURNAL_LIMIT))
+        .await?;
+    Ok(Json(entries.into_iter().map(JournalEntryView::from).collect()))
+}
+
+async fn achievements(State(state): State<AppState>) -> HttpResult<Json<Vec<AchievementView>>> {
+    let achievements: Vec<Achievement> = state.runtime.api().achievements().await?;
+    Ok(Json(achievements.iter().map(AchievementView::from).collect()))
+}
+
+#[derive(Debug, Deserialize)]
+struct StatsQuery {
+    #[serde(default)]
+    period: Option<String>,
+}
+
+async fn stats(
+    State(state): State<AppState>,
+    query: Result<Query<StatsQuery>, QueryRejection>,
+) -> HttpResult<Json<StatsView>> {
+    let Query(query) = query?;
+    let period: StatsPeriod = match query.period {
+        Some(raw) => raw
+            .parse::<StatsPeriod>()
+            .map_err(|()| HttpError::bad_request("period: expected day, week or month"))?,
+        None => StatsPeriod::default(),
+    };
+    let stats: HydrationStats = state.runtime.api().hydration_stats(period).await?;
+    Ok(Json(StatsView::from(&stats)))
+}
+
+/// History listing parameters; `from` and `to` take local dates or RFC 3339 times.
+#[derive(Debug, Deserialize)]
+struct HistoryParams {
+    #[serde(default)]
+    from: Option<String>,
+    #[serde(default)]
+    to: Option<String>,
+    #[serde(default)]
+    order: Option<String>,
+    #[serde(default)]
+    limit: Option<u32>,
+    #[serde(default)]
+    cursor: Option<String>,
+}
+
+impl HistoryParams {
+    fn into_query(self) -> HttpResult<HistoryQuery> {
+        let bound = |raw: Option<String>, name: &str, end_of_day: bool| -> HttpResult<Option<DateTime<Utc>>> {
+            raw.map(|raw: String| {
+                parse_history_bound(&raw, end_of_day)
+                    .ok_or_else(|| HttpError::bad_request(format!("{name}: expected YYYY-MM-DD or an RFC 3339 time")))
+            })
+            .transpose()
+        };
+
+        Ok(HistoryQuery {
+            from: bound(self.from, "from", false)?,
+            until: bound(self.to, "to", true)?,
+            order: match self.order {
+                Some(raw) => raw
+                    .parse::<SortOrder>()
+                    .map_err(|()| HttpError::bad_request("order: expected newest_first or oldest_first"))?,
+                None => SortOrder::default(),
+            },
+            limit: self.limit.unwrap_or(DEFAULT_HISTORY_PAGE_SIZE),
+            after: self
+                .cursor
+                .map(|raw: String| raw.parse::<HistoryCursor>().map_err(|()| CoreError::InvalidHistoryCursor(raw)))
+                .transpose()?,
+        })
+    }
+}
+
+async fn reminder_history(
+    State(state): State<AppState>,
+    params: Result<Query<HistoryParams>, QueryRejection>,
+) -> HttpResult<Json<HistoryPageView<ReminderLogView>>> {
+    let Query(params) = params?;
+    let page: HistoryPage<ReminderLogEntry> = state.runtime.api().hydration_reminder_history(params.into_query()?).await?;
+    Ok(Json(HistoryPageView::new(&page)))
+}
+
+async fn intake_history(
+    State(state): State<AppState>,
+    params: Result<Query<HistoryParams>, QueryRejection>,
+) -> HttpResult<Json<HistoryPageView<IntakeView>>> {
+    let Query(params) = params?;
+    let page: HistoryPage<WaterIntake> = state.runtime.api().water_intake_history(params.into_query()?).await?;
+    Ok(Json(HistoryPageView::new(&page)))
+}
+
+/// Query of `GET /export`; `tables` is a comma-separated list and defaults to every table.
+#[derive(Debug, Deserialize)]
+struct ExportQuery {
+    #[serde(default)]
+    tables: Option<String>,
+}
+
+/// Replies with the same versioned JSON document as `desk-buddy export`.
+async fn export(
+    State(state): State<AppState>,
+    query: Result<Query<ExportQuery>, QueryRejection>,
+) -> HttpResult<Response> {
+    let Query(query) = query?;
+    let tables: Vec<ExportTable> = match query.tables {
+        Some(raw) => raw
+            .split(',')
+            .map(|name: &str| {
+                name.parse::<ExportTable>().map_err(|()| {
+                    HttpError::bad_request(format!(
+                        "unknown table {name:?} (expected reminder_logs, water_intake or journal_entries)"
+                    ))
+                })
+            })
+            .collect::<HttpResult<Vec<ExportTable>>>()?,
+        None => ExportTable::ALL.to_vec(),
+    };
+    let mut writer: JsonExportWriter<Vec<u8>> = JsonExportWriter::new(Vec::new());
+    let _: ExportSummary = state.runtime.api().export(&tables, &mut writer).await?;
+    Ok(([(header::CONTENT_TYPE, "application/json")], writer.into_inner()).into_response())
+}
+
+#[derive(Debug, Deserialize)]
+struct ImportQuery {
+    #[serde(default)]
+    strategy: Option<String>,
+}
+
+/// Merges an export document sent as the request body.
+async fn import(
+    State(state): State<AppState>,
+    query: Result<Query<ImportQuery>, QueryRejection>,
+    body: Result<Bytes, BytesRejection>,
+) -> HttpResult<Json<ImportSummaryView>> {
+    let Query(query) = query?;
+    let strategy: ImportStrategy = match query.strategy {
+        Some(raw) => raw
+            .parse::<ImportStrategy>()
+            .map_err(|()| HttpError::bad_request("strategy: expected skip_duplicates, overwrite or append"))?,
+        None => ImportStrategy::default(),
+    };
+    let document: ImportDocument = read_json_export(body?.as_ref())?;
+    let summary: ImportSummary = state.runtime.api().import(&document, strategy).await?;
+    Ok(Json(ImportSummaryView::new(strategy, &summary)))
+}
+
+/// Streams `reminder` events (and `lagged` when the client falls behind) until the
+/// client disconnects or the server stops.
+async fn reminder_events(State(state): State<AppState>) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let events: broadcast::Receiver<ReminderEvent> = state.runtime.subscribe_reminder_events();
+    let stream = futures_util::stream::unfold((events, state.shutdown_rx), next_sse_event);
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
+
+type SseState = (broadcast::Receiver<ReminderEvent>, watch::Receiver<bool>);
+
+async fn next_sse_event((mut events, shutdown_rx): SseState) -> Option<(Result<Event, Infallible>, SseState)> {
+    loop {
+        let received: Result<ReminderEvent, RecvError> = tokio::select! {
+            biased;
+
+            () = shutdown_requested(shutdown_rx.clone()) => return None,
+            received = events.recv() => received,
+        };
+        let event: Result<Event, axum::Error> = match received {
+            Ok(reminder) => Event::default()
+                .event("reminder")
+                .id(reminder.id.to_string())
+                .json_data(ReminderEventView::from(&reminder)),
+            Err(RecvError::Lagged(missed)) => Event::default().event("lagged").json_data(json!({ "missed": missed })),
+            Err(RecvError::Closed) => return None,
+        };
+        match event {
+            Ok(event) => return Some((Ok(event), (events, shutdown_rx))),
+            Err(err) => tracing::warn!("dropping reminder event that failed to serialize: {err}"),
+        }
+    }
+}
+
+fn clock_time(raw: &str) -> HttpResult<NaiveTime> {
+    parse_clock_time(raw).ok_or_else(|| HttpError::bad_request(format!("invalid time {raw:?} (expected HH:MM)")))
+}
+
+fn weekday(raw: &str) -> HttpResult<Weekday> {
+    parse_weekday(raw).ok_or_else(|| HttpError::bad_request(format!("unknown weekday {raw:?}")))
+}
+
+fn entry_date(Path(raw): Path<String>) -> HttpResult<EntryDate> {
+    EntryDate::parse(&raw).ok_or_else(|| HttpError::bad_request(format!("invalid date {raw:?} (expected YYYY-MM-DD)")))
+}
@@ -0,0 +1,71 @@
+//! Loopback-only HTTP server that serves the core routes until stopped.
+use super::routes::{router, AppState};
+use crate::bootstrap::CoreRuntime;
+use crate::error::{CoreError, CoreResult};
+use std::io::ErrorKind;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+
+/// Serves the HTTP/JSON API on a loopback address.
+pub struct HttpServer {
+    local_addr: SocketAddr,
+    shutdown_tx: watch::Sender<bool>,
+    join_handle: Option<JoinHandle<std::io::Result<()>>>,
+}
+
+impl HttpServer {
+    /// Binds `addr` and starts serving in the background; port `0` picks a free port.
+    ///
+    /// Only loopback addresses are accepted, so the API is never exposed to the network.
+    pub async fn start(addr: SocketAddr, runtime: Arc<CoreRuntime>) -> CoreResult<Self> {
+        if !addr.ip().is_loopback() {
+            return Err(CoreError::Io(std::io::Error::new(
+                ErrorKind::AddrNotAvailable,
+                format!("{addr} is not a loopback address"),
+            )));
+        }
+        let listener: TcpListener = TcpListener::bind(addr).await?;
+        let local_addr: SocketAddr = listener.local_addr()?;
+
+        let (shutdown_tx, shutdown_rx): (watch::Sender<bool>, watch::Receiver<bool>) = watch::channel(false);
+        let state: AppState = AppState {
+            runtime,
+            shutdown_rx: shutdown_rx.clone(),
+        };
+        let join_handle: JoinHandle<std::io::Result<()>> = tokio::spawn(async move {
+            axum::serve(listener, router(state))
+                .with_graceful_shutdown(shutdown_requested(shutdown_rx))
+                .await
+        });
+
+        Ok(Self {
+            local_addr,
+            shutdown_tx,
+            join_handle: Some(join_handle),
+        })
+    }
+
+    /// Returns the bound address, including the port picked for port `0`.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Ends event streams, finishes in-flight requests and closes the listener.
+    pub async fn stop(&mut self) -> CoreResult<()> {
+        let _send_result: Result<(), watch::error::SendError<bool>> = self.shutdown_tx.send(true);
+        if let Some(join_handle) = self.join_handle.take() {
+            join_handle.await??;
+        }
+
+        Ok(())
+    }
+}
+
+/// Resolves once shutdown is signalled or the server handle is dropped.
+pub(super) async fn shutdown_requested(mut shutdown_rx: watch::Receiver<bool>) {
+    let _: Result<watch::Ref<'_, bool>, watch::error::RecvError> =
+        shutdown_rx.wait_for(|stopping: &bool| *stopping).await;
+}
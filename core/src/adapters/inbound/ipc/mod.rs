@@ -0,0 +1,6 @@
+//! Local IPC adapter: a Unix domain socket speaking newline-delimited JSON.
+mod protocol;
+mod server;
+
+pub use protocol::{IpcCommand, IpcEvent, IpcRequest, IpcResponse, IpcResult};
+pub use server::{IpcServer, MAX_REQUEST_BYTES};
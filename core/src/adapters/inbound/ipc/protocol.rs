@@ -0,0 +1,95 @@
+//! Newline-delimited JSON messages exchanged over the IPC socket.
+use crate::adapters::inbound::views::{
+    intake_source, IntakeView, PauseLengthRequest, PauseView, ReminderEventView, SchedulerStatusView,
+};
+use crate::domain::hydration::IntakeSource;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// One request line; `id` is optional and echoed back on the response.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct IpcRequest {
+    #[serde(default)]
+    pub id: Option<Value>,
+    #[serde(flatten)]
+    pub command: IpcCommand,
+}
+
+/// Operations a client can run, selected by the `command` field.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum IpcCommand {
+    /// Fires one reminder now.
+    Trigger,
+    SetInterval {
+        minutes: u64,
+    },
+    Status,
+    /// Pauses reminders; without a `length` the pause lasts until resumed.
+    Pause {
+        #[serde(default)]
+        length: PauseLengthRequest,
+    },
+    Resume,
+    LogIntake {
+        amount_ml: u32,
+        #[serde(default, deserialize_with = "intake_source")]
+        source: Option<IntakeSource>,
+    },
+    /// Starts pushing reminder events on this connection.
+    Subscribe,
+}
+
+/// Reply to one request line.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct IpcResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<Value>,
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<IpcResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl IpcResponse {
+    pub(super) fn success(id: Option<Value>, result: IpcResult) -> Self {
+        Self {
+            id,
+            ok: true,
+            result: Some(result),
+            error: None,
+        }
+    }
+
+    pub(super) fn failure(id: Option<Value>, error: String) -> Self {
+        Self {
+            id,
+            ok: false,
+            result: None,
+            error: Some(error),
+        }
+    }
+}
+
+/// Command results; each serializes as its bare view (`null` for `Resumed`).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum IpcResult {
+    Reminder(ReminderEventView),
+    Interval { interval_minutes: u64 },
+    Status(SchedulerStatusView),
+    Paused(PauseView),
+    Resumed,
+    Intake(IntakeView),
+    Subscribed { subscribed: bool },
+}
+
+/// Unsolicited line pushed to subscribed connections.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum IpcEvent {
+    Reminder { reminder: ReminderEventView },
+    /// The connection fell behind and `missed` reminders were dropped.
+    Lagged { missed: u64 },
+}
@@ -0,0 +1,275 @@
+//! Unix domain socket server that runs NDJSON requests against a core runtime.
+use super::protocol::{IpcCommand, IpcEvent, IpcRequest, IpcResponse, IpcResult};
+use crate::adapters::inbound::views::{IntakeView, PauseView, ReminderEventView, SchedulerStatusView};
+use crate::bootstrap::CoreRuntime;
+use crate::domain::hydration::{ReminderEvent, ReminderPause, WaterIntake};
+use crate::error::{CoreError, CoreResult};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::unix::{OwnedReadHalf, OwnedWriteHalf};
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::{broadcast, watch};
+use tokio::task::{JoinHandle, JoinSet};
+
+/// Longest request line a client may send, newline included; longer ones close the connection.
+pub const MAX_REQUEST_BYTES: u64 = 64 * 1024;
+/// First pause after a failed accept, doubled on each further failure.
+const ACCEPT_RETRY_MIN: Duration = Duration::from_millis(10);
+/// Longest pause between accepts while they keep failing (for example on `EMFILE`).
+const ACCEPT_RETRY_MAX: Duration = Duration::from_secs(1);
+
+/// Accepts local clients on a socket file until stopped.
+pub struct IpcServer {
+    socket_path: PathBuf,
+    shutdown_tx: watch::Sender<bool>,
+    join_handle: Option<JoinHandle<()>>,
+}
+
+impl IpcServer {
+    /// Binds `socket_path` and starts serving connections in the background.
+    ///
+    /// A stale socket file left by a crashed instance is replaced; one that still
+    /// accepts connections fails with `AddrInUse`.
+    pub async fn start(socket_path: impl Into<PathBuf>, runtime: Arc<CoreRuntime>) -> CoreResult<Self> {
+        let socket_path: PathBuf = socket_path.into();
+        if socket_path.exists() {
+            if UnixStream::connect(&socket_path).await.is_ok() {
+                return Err(CoreError::Io(std::io::Error::new(
+                    ErrorKind::AddrInUse,
+                    format!("{} is already being served", socket_path.display()),
+                )));
+            }
+            std::fs::remove_file(&socket_path)?;
+        }
+        let listener: UnixListener = UnixListener::bind(&socket_path)?;
+
+        let (shutdown_tx, shutdown_rx): (watch::Sender<bool>, watch::Receiver<bool>) = watch::channel(false);
+        let join_handle: JoinHandle<()> = tokio::spawn(accept_loop(listener, runtime, shutdown_rx));
+
+        Ok(Self {
+            socket_path,
+            shutdown_tx,
+            join_handle: Some(join_handle),
+        })
+    }
+
+    /// Returns the socket file clients connect to.
+    pub fn socket_path(&self) -> &Path {
+        &self.socket_path
+    }
+
+    /// Closes every connection, waits for them to finish and removes the socket file.
+    pub async fn stop(&mut self) -> CoreResult<()> {
+        let _send_result: Result<(), watch::error::SendError<bool>> = self.shutdown_tx.send(true);
+        if let Some(join_handle) = self.join_handle.take() {
+            join_handle.await?;
+            match std::fs::remove_file(&self.socket_path) {
+                Err(err) if err.kind() != ErrorKind::NotFound => return Err(err.into()),
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+async fn accept_loop(listener: UnixListener, runtime: Arc<CoreRuntime>, mut shutdown_rx: watch::Receiver<bool>) {
+    let mut connections: JoinSet<CoreResult<()>> = JoinSet::new();
+    let mut retry_after: Duration = ACCEPT_RETRY_MIN;
+
+    loop {
+        tokio::select! {
+            biased;
+
+            changed = shutdown_rx.changed() => {
+                if changed.is_err() || *shutdown_rx.borrow() {
+                    break;
+                }
+            }
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _address)) => {
+                    retry_after = ACCEPT_RETRY_MIN;
+                    connections.spawn(serve_connection(stream, runtime.clone(), shutdown_rx.clone()));
+                }
+                Err(err) => {
+                    // Errors such as running out of file descriptors persist, so back
+                    // off rather than spinning; shutdown still interrupts the wait.
+                    tracing::warn!("ipc accept failed, retrying in {retry_after:?}: {err}");
+                    tokio::select! {
+                        changed = shutdown_rx.changed() => {
+                            if changed.is_err() || *shutdown_rx.borrow() {
+                                break;
+                            }
+                        }
+                        _ = tokio::time::sleep(retry_after) => {}
+                    }
+                    retry_after = (retry_after * 2).min(ACCEPT_RETRY_MAX);
+                }
+            },
+            Some(finished) = connections.join_next(), if !connections.is_empty() => {
+                if let Ok(Err(err)) = finished {
+                    tracing::debug!("ipc connection closed: {err}");
+                }
+            }
+        }
+    }
+
+    // Connections watch the same shutdown flag, so this only waits for in-flight replies.
+    while connections.join_next().await.is_some() {}
+}
+
+/// Answers request lines in order and, once subscribed, interleaves pushed events.
+async fn serve_connection(
+    stream: UnixStream,
+    runtime: Arc<CoreRuntime>,
+    mut shutdown_rx: watch::Receiver<bool>,
+) -> CoreResult<()> {
+    let (reader, mut writer): (OwnedReadHalf, OwnedWriteHalf) = stream.into_split();
+    let mut reader: BufReader<OwnedReadHalf> = BufReader::new(reader);
+    // Kept across iterations: a read interrupted by an event resumes where it stopped.
+    let mut pending: Vec<u8> = Vec::new();
+    let mut events: Option<broadcast::Receiver<ReminderEvent>> = None;
+
+    loop {
+        tokio::select! {
+            biased;
+
+            changed = shutdown_rx.changed() => {
+                if changed.is_err() || *shutdown_rx.borrow() {
+                    break;
+                }
+            }
+            line = read_request_line(&mut reader, &mut pending) => {
+                let line: String = match line? {
+                    RequestLine::Line(line) => line,
+                    RequestLine::TooLong => {
+                        let message: String = format!("invalid request: longer than {MAX_REQUEST_BYTES} bytes");
+                        write_line(&mut writer, &IpcResponse::failure(None, message)).await?;
+                        break;
+                    }
+                    RequestLine::Closed => break,
+                };
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let response: IpcResponse = handle_line(&runtime, &line, &mut events).await;
+                write_line(&mut writer, &response).await?;
+            }
+            received = next_event(&mut events) => {
+                let event: IpcEvent = match received {
+                    Ok(event) => IpcEvent::Reminder {
+                        reminder: ReminderEventView::from(&event),
+                    },
+                    Err(RecvError::Lagged(missed)) => IpcEvent::Lagged { missed },
+                    Err(RecvError::Closed) => {
+                        events = None;
+                        continue;
+                    }
+                };
+                write_line(&mut writer, &event).await?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_line(
+    runtime: &CoreRuntime,
+    line: &str,
+    events: &mut Option<broadcast::Receiver<ReminderEvent>>,
+) -> IpcResponse {
+    let request: IpcRequest = match serde_json::from_str::<IpcRequest>(line) {
+        Ok(request) => request,
+        Err(err) => return IpcResponse::failure(None, format!("invalid request: {err}")),
+    };
+
+    match dispatch(runtime, request.command, events).await {
+        Ok(result) => IpcResponse::success(request.id, result),
+        Err(err) => IpcResponse::failure(request.id, err.to_string()),
+    }
+}
+
+async fn dispatch(
+    runtime: &CoreRuntime,
+    command: IpcCommand,
+    events: &mut Option<broadcast::Receiver<ReminderEvent>>,
+) -> CoreResult<IpcResult> {
+    Ok(match command {
+        IpcCommand::Trigger => {
+            let event: ReminderEvent = runtime.api().trigger_hydration_reminder_once().await?;
+            IpcResult::Reminder(ReminderEventView::from(&event))
+        }
+        IpcCommand::SetInterval { minutes } => IpcResult::Interval {
+            interval_minutes: runtime.api().set_hydration_interval_minutes(minutes).await?,
+        },
+        IpcCommand::Status => IpcResult::Status(SchedulerStatusView::from(&runtime.scheduler_status().await)),
+        IpcCommand::Pause { length } => {
+            let pause: ReminderPause = runtime.pause_reminders(length.into()).await?;
+            IpcResult::Paused(PauseView::from(pause))
+        }
+        IpcCommand::Resume => {
+            runtime.resume_reminders().await?;
+            IpcResult::Resumed
+        }
+        IpcCommand::LogIntake { amount_ml, source } => {
+            let intake: WaterIntake = runtime.api().log_water_intake(amount_ml, source).await?;
+            IpcResult::Intake(IntakeView::from(&intake))
+        }
+        IpcCommand::Subscribe => {
+            if events.is_none() {
+                *events = Some(runtime.subscribe_reminder_events());
+            }
+            IpcResult::Subscribed { subscribed: true }
+        }
+    })
+}
+
+/// One read from a client connection.
+enum RequestLine {
+    Line(String),
+    /// The client sent `MAX_REQUEST_BYTES` without ending the line.
+    TooLong,
+    Closed,
+}
+
+/// Reads the next request line into `pending`, never buffering more than
+/// `MAX_REQUEST_BYTES`. Cancel safe: bytes read so far stay in `pending`.
+async fn read_request_line(reader: &mut BufReader<OwnedReadHalf>, pending: &mut Vec<u8>) -> CoreResult<RequestLine> {
+    let buffered: u64 = u64::try_from(pending.len()).unwrap_or(u64::MAX);
+    let read: usize = (&mut *reader)
+        .take(MAX_REQUEST_BYTES.saturating_sub(buffered))
+        .read_until(b'\n', pending)
+        .await?;
+
+    let ended: bool = pending.last() == Some(&b'\n');
+    if !ended && u64::try_from(pending.len()).unwrap_or(u64::MAX) >= MAX_REQUEST_BYTES {
+        return Ok(RequestLine::TooLong);
+    }
+    // Without a newline the client has closed its side; a final unterminated line still counts.
+    if read == 0 && pending.is_empty() {
+        return Ok(RequestLine::Closed);
+    }
+    let line: String = String::from_utf8(std::mem::take(pending))
+        .map_err(|err: std::string::FromUtf8Error| std::io::Error::new(ErrorKind::InvalidData, err))?;
+    Ok(RequestLine::Line(line.trim_end_matches(['\n', '\r']).to_string()))
+}
+
+/// Waits for the next pushed event, or forever when the connection is not subscribed.
+async fn next_event(events: &mut Option<broadcast::Receiver<ReminderEvent>>) -> Result<ReminderEvent, RecvError> {
+    match events {
+        Some(receiver) => receiver.recv().await,
+        None => std::future::pending().await,
+    }
+}
+
+async fn write_line<T: serde::Serialize>(writer: &mut OwnedWriteHalf, message: &T) -> CoreResult<()> {
+    let mut line: Vec<u8> = serde_json::to_vec(message).map_err(std::io::Error::from)?;
+    line.push(b'\n');
+    writer.write_all(&line).await?;
+    Ok(())
+}
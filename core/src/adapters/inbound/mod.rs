@@ -1,2 +1,8 @@
 //! Inbound adapters that invoke application use cases from external callers.
+pub mod cli;
+#[cfg(feature = "http")]
+pub mod http;
+#[cfg(unix)]
+pub mod ipc;
 pub mod scheduler;
+pub mod views;
@@ -1,8 +1,16 @@
-//! Tokio-backed scheduler loop for periodic hydration reminders.
+//! Tokio-backed scheduler loop for periodic hydration reminders, kept apart from
+//! `JobScheduler` for the reasons given in that module.
+use super::status::{SchedulerState, SchedulerStatus};
+use super::supervision::{panic_status, stop_status, Supervisor};
+use super::MAX_TICK;
 use crate::application::hydration_service::HydrationService;
-use crate::domain::hydration::ReminderConfig;
+use crate::application::reminder_service::ReminderService;
+use crate::domain::hydration::{
+    saturating_add, ReminderConfig, ReminderKind, ReminderPause, SnoozeRequest, MISSED_REMINDER_GRACE,
+};
 use crate::error::{CoreError, CoreResult};
-use crate::ports::reminder_event_sink::ReminderEventSink;
+use crate::ports::clock::Clock;
+use chrono::{DateTime, Local, Utc};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::watch;
@@ -11,14 +19,18 @@ use tokio::task::JoinHandle;
 pub struct HydrationScheduler {
     shutdown_tx: Option<watch::Sender<bool>>,
     join_handle: Option<JoinHandle<CoreResult<()>>>,
+    status_tx: watch::Sender<SchedulerStatus>,
 }
 
 impl HydrationScheduler {
     /// Creates an idle hydration scheduler.
     pub fn new() -> Self {
+        let (status_tx, _status_rx): (watch::Sender<SchedulerStatus>, watch::Receiver<SchedulerStatus>) =
+            watch::channel(SchedulerStatus::default());
         Self {
             shutdown_tx: None,
             join_handle: None,
+            status_tx,
         }
     }
 
@@ -27,12 +39,30 @@ impl HydrationScheduler {
         self.join_handle.is_some()
     }
 
+    /// Returns the loop's state, next and last fire times, and last error.
+    pub fn status(&self) -> SchedulerStatus {
+        self.status_tx.borrow().clone()
+    }
+
+    /// Subscribes to status changes of this and later runs.
+    pub fn subscribe_status(&self) -> watch::Receiver<SchedulerStatus> {
+        self.status_tx.subscribe()
+    }
+
     /// Starts the background reminder loop using the provided config.
+    ///
+    /// Fire times are wall-clock deadlines counted from the last persisted reminder,
+    /// so restarts and suspends keep the cadence. Configs published on
+    /// `config_updates` re-arm the pending reminder immediately.
+    ///
+    /// SQLite busy/locked errors are retried with backoff and sink failures are
+    /// recorded without stopping the loop; any other error, or a panic, ends the task
+    /// and is returned by `stop`. Either way the status shows the loop stopped.
     pub fn start(
         &mut self,
-        service: HydrationService,
-        event_sink: Arc<dyn ReminderEventSink>,
+        reminders: ReminderService,
         config: ReminderConfig,
+        mut config_updates: watch::Receiver<ReminderConfig>,
     ) -> CoreResult<()> {
         if self.is_running() {
             return Err(CoreError::SchedulerAlreadyRunning);
@@ -40,25 +70,87 @@ impl HydrationScheduler {
 
         let (shutdown_tx, mut shutdown_rx): (watch::Sender<bool>, watch::Receiver<bool>) =
             watch::channel(false);
-        let interval: Duration = config.interval_duration();
+        let mut snooze_rx: watch::Receiver<Option<SnoozeRequest>> =
+            reminders.hydration_service().subscribe_snoozes();
+        let clock: Arc<dyn Clock> = reminders.hydration_service().clock();
+        let mut supervisor: Supervisor = Supervisor::start(self.status_tx.clone(), clock.clone());
 
-        let join_handle: JoinHandle<CoreResult<()>> = tokio::spawn(async move {
-            loop {
+        let reminder_task: JoinHandle<CoreResult<()>> = tokio::spawn(async move {
+            let mut reminder_loop: ReminderLoop = loop {
+                let resumed: CoreResult<ReminderLoop> =
+                    ReminderLoop::resume(reminders.clone(), config, &supervisor).await;
+                let retry_in: Duration = match resumed {
+                    Ok(resumed) => break resumed,
+                    Err(err) => supervisor.failed(err)?,
+                };
                 tokio::select! {
-                    _ = tokio::time::sleep(interval) => {
-                        let message: String = service.trigger_reminder().await?;
-                        event_sink.emit_hydration_reminder(&message).await?;
+                    biased;
+
+                    changed = shutdown_rx.changed() => {
+                        if changed.is_err() || *shutdown_rx.borrow() {
+                            return Ok(());
+                        }
                     }
+                    _ = tokio::time::sleep(retry_in) => {}
+                }
+            };
+            supervisor.recovered();
+            let mut retry_in: Option<Duration> = None;
+
+            loop {
+                let (state, next_fire_at): (SchedulerState, Option<DateTime<Utc>>) = reminder_loop.pending();
+                supervisor.scheduled(state, next_fire_at);
+                let sleep_for: Duration =
+                    retry_in.unwrap_or_else(|| reminder_loop.time_until_due().min(MAX_TICK));
+
+                tokio::select! {
+                    // Shutdown wins ties so stop() never races with a reminder or re-arm.
+                    biased;
+
                     changed = shutdown_rx.changed() => {
                         if changed.is_err() || *shutdown_rx.borrow() {
                             break;
                         }
                     }
+                    _ = tokio::time::sleep(sleep_for) => {
+                        retry_in = match reminder_loop.tick(&mut supervisor).await {
+                            Ok(()) => {
+                                supervisor.recovered();
+                                None
+                            }
+                            Err(err) => Some(supervisor.failed(err)?),
+                        };
+                    }
+                    Ok(()) = snooze_rx.changed() => {
+                        let request: Option<SnoozeRequest> = *snooze_rx.borrow_and_update();
+                        if let Some(request) = request {
+                            reminder_loop.snooze(request.delay);
+                        }
+                    }
+                    Ok(()) = config_updates.changed() => {
+                        let config: ReminderConfig = *config_updates.borrow_and_update();
+                        reminder_loop.reconfigure(config);
+                    }
                 }
             }
 
             Ok(())
         });
+        // A panic unwinds past the supervisor, so this task records it instead.
+        let status_tx: watch::Sender<SchedulerStatus> = self.status_tx.clone();
+        let join_handle: JoinHandle<CoreResult<()>> = tokio::spawn(async move {
+            let finished: CoreResult<()> = match reminder_task.await {
+                Ok(result) => result,
+                Err(err) => {
+                    if err.is_panic() {
+                        panic_status(&status_tx, err.to_string(), clock.now());
+                    }
+                    Err(CoreError::from(err))
+                }
+            };
+            stop_status(&status_tx);
+            finished
+        });
 
         self.shutdown_tx = Some(shutdown_tx);
         self.join_handle = Some(join_handle);
@@ -73,10 +165,15 @@ impl HydrationScheduler {
                 shutdown_tx.send(true);
         }
 
-        if let Some(join_handle) = self.join_handle.take() {
-            let result: CoreResult<()> = join_handle.await?;
-            result?;
-        }
+        let joined: CoreResult<()> = match self.join_handle.take() {
+            Some(join_handle) => join_handle
+                .await
+                .map_err(CoreError::from)
+                .and_then(|result: CoreResult<()>| result),
+            None => Ok(()),
+        };
+        stop_status(&self.status_tx);
+        joined?;
 
         Ok(())
     }
@@ -87,3 +184,966 @@ impl Default for HydrationScheduler {
         Self::new()
     }
 }
+
+/// Why the pending deadline was set, which decides how lateness is treated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DueReason {
+    /// The regular slot after the anchor; lateness beyond the grace period means missed slots.
+    Interval,
+    /// One-off delay requested by snoozing a reminder.
+    Snooze,
+    /// Deferred to the opening of the active window or the end of quiet hours.
+    Window,
+    /// Held by a pause; the cadence restarts when it ends.
+    PauseEnd,
+}
+
+/// Wall-clock state of a running reminder loop.
+struct ReminderLoop {
+    reminders: ReminderService,
+    clock: Arc<dyn Clock>,
+    config: ReminderConfig,
+    /// The last reminder fired, or loop start; the next slot is computed from it.
+    anchor: DateTime<Utc>,
+    due: DateTime<Utc>,
+    due_reason: DueReason,
+}
+
+impl ReminderLoop {
+    /// Restores the cadence from the last persisted reminder.
+    async fn resume(
+        reminders: ReminderService,
+        config: ReminderConfig,
+        supervisor: &Supervisor,
+    ) -> CoreResult<Self> {
+        let service: HydrationService = reminders.hydration_service();
+        let clock: Arc<dyn Clock> = service.clock();
+        let now: DateTime<Utc> = clock.now();
+        let last_reminder_at: Option<DateTime<Utc>> = service.last_reminder_at().await?;
+        supervisor.resumed(last_reminder_at);
+        // A last reminder "in the future" means the clock moved back; count from now.
+        let anchor: DateTime<Utc> = last_reminder_at.map_or(now, |last: DateTime<Utc>| last.min(now));
+
+        let mut resumed: Self = Self {
+            reminders,
+            clock,
+            config,
+            anchor,
+            due: config.next_due(anchor, &Local),
+            due_reason: DueReason::Interval,
+        };
+        if let Some(pause) = config.active_pause(now) {
+            resumed.hold(pause);
+        }
+        Ok(resumed)
+    }
+
+    /// Returns whether reminders are held and when the loop next fires or resumes.
+    fn pending(&self) -> (SchedulerState, Option<DateTime<Utc>>) {
+        match self.due_reason {
+            DueReason::PauseEnd => (
+                SchedulerState::Paused,
+                Some(self.due).filter(|due: &DateTime<Utc>| *due != DateTime::<Utc>::MAX_UTC),
+            ),
+            DueReason::Interval | DueReason::Snooze | DueReason::Window => {
+                (SchedulerState::Running, Some(self.due))
+            }
+        }
+    }
+
+    fn time_until_due(&self) -> Duration {
+        (self.due - self.clock.now())
+            .to_std()
+            .unwrap_or(Duration::ZERO)
+    }
+
+    /// Re-reads the wall clock and fires whatever has come due.
+    ///
+    /// Sink failures are reported to `supervisor`; store errors are returned and the
+    /// same deadline is retried unless part of a catch-up batch already fired.
+    async fn tick(&mut self, supervisor: &mut Supervisor) -> CoreResult<()> {
+        let now: DateTime<Utc> = self.clock.now();
+
+        // Nothing fires while paused, snoozes included; reminders held back are not
+        // replayed, the cadence just restarts once the pause ends.
+        if let Some(pause) = self.config.active_pause(now) {
+            self.hold(pause);
+            return Ok(());
+        }
+        if self.due_reason == DueReason::PauseEnd {
+            self.rearm_from(now);
+            return Ok(());
+        }
+
+        // The clock moved back past the previous slot: restart the cadence from now.
+        if self.due_reason == DueReason::Interval && self.config.next_due(now, &Local) < self.due {
+            self.rearm_from(now);
+            return Ok(());
+        }
+        if now < self.due {
+            return Ok(());
+        }
+
+        let late_by: Duration = (now - self.due).to_std().unwrap_or(Duration::ZERO);
+        let (kind, count): (ReminderKind, u32) = match self.due_reason {
+            // Woken after sleep or suspend, possibly past several reminders.
+            DueReason::Interval if late_by > MISSED_REMINDER_GRACE => {
+                let missed: u32 = self.config.missed_reminders(self.due, now, &Local);
+                (ReminderKind::CatchUp, self.config.catch_up.reminders_to_fire(missed))
+            }
+            DueReason::Snooze => (ReminderKind::Snoozed, 1),
+            DueReason::Interval | DueReason::Window | DueReason::PauseEnd => (ReminderKind::Scheduled, 1),
+        };
+
+        match self.config.next_allowed(now, &Local) {
+            Some(allowed_at) if allowed_at <= now => {
+                for fired in 0..count {
+                    match self.reminders.remind(kind).await {
+                        Ok(event) => supervisor.delivered(event.fired_at),
+                        Err(err @ CoreError::ReminderDeliveryFailed { .. }) => {
+                            supervisor.delivery_failed(&err);
+                        }
+                        Err(err) => {
+                            // Retrying would replay what already fired; drop the rest.
+                            if fired > 0 {
+                                self.rearm_from(self.clock.now());
+                            }
+                            return Err(err);
+                        }
+                    }
+                }
+                self.rearm_from(self.clock.now());
+            }
+            // Outside the window or in quiet hours: wait until reminders are allowed
+            // again and fire one then.
+            Some(allowed_at) => {
+                self.due = allowed_at;
+                self.due_reason = DueReason::Window;
+            }
+            // No allowed time at all; re-check after an interval in case the settings
+            // change while the loop keeps running.
+            None => self.rearm_from(now),
+        }
+
+        Ok(())
+    }
+
+    /// Replaces whatever was pending with a one-off delay.
+    fn snooze(&mut self, delay: Duration) {
+        self.due = saturating_add(self.clock.now(), delay);
+        self.due_reason = DueReason::Snooze;
+    }
+
+    /// Applies new settings. A pending snooze keeps its deadline; otherwise the new
+    /// schedule counts from the same anchor, firing at once if already overdue.
+    fn reconfigure(&mut self, config: ReminderConfig) {
+        let now: DateTime<Utc> = self.clock.now();
+        let was_paused: bool = self.due_reason == DueReason::PauseEnd;
+        self.config = config;
+        if let Some(pause) = config.active_pause(now) {
+            self.hold(pause);
+        } else if was_paused {
+            // Resumed early: start a fresh interval rather than firing what was held.
+            self.rearm_from(now);
+        } else if self.due_reason != DueReason::Snooze {
+            self.due = config.next_due(self.anchor, &Local);
+            self.due_reason = DueReason::Interval;
+        }
+    }
+
+    /// Holds reminders until the pause ends; an indefinite pause waits for new settings.
+    fn hold(&mut self, pause: ReminderPause) {
+        self.due = pause.ends_at().unwrap_or(DateTime::<Utc>::MAX_UTC);
+        self.due_reason = DueReason::PauseEnd;
+    }
+
+    fn rearm_from(&mut self, anchor: DateTime<Utc>) {
+        self.anchor = anchor;
+        self.due = self.config.next_due(anchor, &Local);
+        self.due_reason = DueReason::Interval;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::inbound::scheduler::status::SchedulerState;
+    use crate::adapters::inbound::scheduler::supervision::{FailureKind, HealthState, SchedulerError};
+    use crate::adapters::inbound::scheduler::test_clock::{local_instant, TokioClock};
+    use crate::application::intake_service::IntakeService;
+    use crate::application::settings_service::SettingsService;
+    use crate::domain::history::{HistoryPage, HistoryQuery, ReminderLogEntry};
+    use crate::domain::stats::{DailyIntake, HourlyIntake, ReminderResponseCounts};
+    use crate::domain::hydration::{
+        ActiveWindow, CatchUpPolicy, CronSchedule, HydrationReminder, IntakeSource, QuietHours,
+        ReminderEvent, ReminderResponse, SavedReminderSettings, WaterIntake, WeekdaySet,
+    };
+    use crate::ports::hydration_log_repository::HydrationLogRepository;
+    use crate::ports::reminder_event_sink::ReminderEventSink;
+    use crate::ports::settings_repository::SettingsRepository;
+    use crate::ports::water_intake_repository::WaterIntakeRepository;
+    use async_trait::async_trait;
+    use chrono::{NaiveDate, NaiveTime};
+    use std::sync::{Mutex, MutexGuard};
+    use tokio::time::Instant;
+
+    /// In-memory log so scheduler tests never wait on SQLite I/O under paused time.
+    #[derive(Default)]
+    struct InMemoryLogRepository {
+        reminders: Mutex<Vec<HydrationReminder>>,
+        /// Last reminder persisted by an earlier run, if any.
+        previous_run_last_at: Option<DateTime<Utc>>,
+        /// Errors returned by the next inserts, last one first.
+        insert_failures: Mutex<Vec<CoreError>>,
+    }
+
+    #[async_trait]
+    impl HydrationLogRepository for InMemoryLogRepository {
+        async fn insert_hydration_log(
+            &self,
+            message: &str,
+            triggered_at: DateTime<Utc>,
+        ) -> CoreResult<HydrationReminder> {
+            if let Some(err) = self.insert_failures.lock().expect("log lock").pop() {
+                return Err(err);
+            }
+            let mut reminders: MutexGuard<'_, Vec<HydrationReminder>> =
+                self.reminders.lock().expect("log lock");
+            let reminder: HydrationReminder = HydrationReminder {
+                id: i64::try_from(reminders.len()).expect("small log") + 1,
+                message: message.to_string(),
+                triggered_at,
+            };
+            reminders.push(reminder.clone());
+            Ok(reminder)
+        }
+
+        async fn hydration_log_count(&self) -> CoreResult<i64> {
+            Ok(i64::try_from(self.reminders.lock().expect("log lock").len()).expect("small log"))
+        }
+
+        async fn latest_reminder_at(&self) -> CoreResult<Option<DateTime<Utc>>> {
+            let latest: Option<DateTime<Utc>> = self
+                .reminders
+                .lock()
+                .expect("log lock")
+                .last()
+                .map(|reminder: &HydrationReminder| reminder.triggered_at);
+            Ok(latest.or(self.previous_run_last_at))
+        }
+
+        async fn record_reminder_response(
+            &self,
+            reminder_id: i64,
+            _response: ReminderResponse,
+            _responded_at: DateTime<Utc>,
+        ) -> CoreResult<bool> {
+            let count: i64 = self.hydration_log_count().await?;
+            Ok((1..=count).contains(&reminder_id))
+        }
+
+        async fn list_hydration_logs(&self, _query: &HistoryQuery) -> CoreResult<HistoryPage<ReminderLogEntry>> {
+            Ok(HistoryPage::from_rows(Vec::new(), 1, ReminderLogEntry::cursor))
+        }
+
+        async fn reminder_response_counts(
+            &self,
+            _start: DateTime<Utc>,
+            _end: DateTime<Utc>,
+        ) -> CoreResult<ReminderResponseCounts> {
+            Ok(ReminderResponseCounts::default())
+        }
+    }
+
+    /// Intake log that stays empty; progress snapshots are not under test here.
+    #[derive(Default)]
+    struct NoIntakeRepository {
+        /// Errors returned by the next total reads, last one first.
+        total_failures: Mutex<Vec<CoreError>>,
+    }
+
+    #[async_trait]
+    impl WaterIntakeRepository for NoIntakeRepository {
+        async fn insert_water_intake(
+            &self,
+            amount_ml: u32,
+            consumed_at: DateTime<Utc>,
+            source: Option<IntakeSource>,
+        ) -> CoreResult<WaterIntake> {
+            Ok(WaterIntake {
+                id: 1,
+                amount_ml,
+                consumed_at,
+                source,
+            })
+        }
+
+        async fn delete_latest_water_intake(&self) -> CoreResult<Option<WaterIntake>> {
+            Ok(None)
+        }
+
+        async fn total_intake_ml_between(&self, _start: DateTime<Utc>, _end: DateTime<Utc>) -> CoreResult<u64> {
+            match self.total_failures.lock().expect("intake lock").pop() {
+                Some(err) => Err(err),
+                None => Ok(0),
+            }
+        }
+
+        async fn list_water_intake(&self, _query: &HistoryQuery) -> CoreResult<HistoryPage<WaterIntake>> {
+            Ok(HistoryPage::from_rows(Vec::new(), 1, WaterIntake::cursor))
+        }
+
+        async fn daily_intake_totals(
+            &self,
+            _start: DateTime<Utc>,
+            _end: DateTime<Utc>,
+            _day_start_hour: u32,
+        ) -> CoreResult<Vec<DailyIntake>> {
+            Ok(Vec::new())
+        }
+
+        async fn goal_met_days(
+            &self,
+            _goal_ml: u32,
+            _start: DateTime<Utc>,
+            _end: DateTime<Utc>,
+            _day_start_hour: u32,
+        ) -> CoreResult<Vec<NaiveDate>> {
+            Ok(Vec::new())
+        }
+
+        async fn intake_by_hour(&self, _start: DateTime<Utc>, _end: DateTime<Utc>) -> CoreResult<Vec<HourlyIntake>> {
+            Ok(Vec::new())
+        }
+    }
+
+    /// Settings store with nothing saved, so every setting uses its default.
+    struct DefaultSettingsRepository;
+
+    #[async_trait]
+    impl SettingsRepository for DefaultSettingsRepository {
+        async fn set_hydration_interval_minutes(&self, _interval_minutes: u64) -> CoreResult<()> {
+            Ok(())
+        }
+
+        async fn set_daily_goal_ml(&self, _daily_goal_ml: u32) -> CoreResult<()> {
+            Ok(())
+        }
+
+        async fn daily_goal_ml(&self) -> CoreResult<Option<u32>> {
+            Ok(None)
+        }
+
+        async fn set_day_start_hour(&self, _day_start_hour: u32) -> CoreResult<()> {
+            Ok(())
+        }
+
+        async fn day_start_hour(&self) -> CoreResult<Option<u32>> {
+            Ok(None)
+        }
+
+        async fn set_cron_schedule(&self, _cron: Option<CronSchedule>) -> CoreResult<()> {
+            Ok(())
+        }
+
+        async fn set_active_window(&self, _active_window: Option<ActiveWindow>) -> CoreResult<()> {
+            Ok(())
+        }
+
+        async fn set_quiet_hours(&self, _quiet_hours: Option<QuietHours>) -> CoreResult<()> {
+            Ok(())
+        }
+
+        async fn clear_quiet_hours(&self) -> CoreResult<()> {
+            Ok(())
+        }
+
+        async fn set_catch_up_policy(&self, _policy: CatchUpPolicy) -> CoreResult<()> {
+            Ok(())
+        }
+
+        async fn set_reminder_pause(&self, _pause: Option<ReminderPause>) -> CoreResult<()> {
+            Ok(())
+        }
+
+        async fn reminder_settings(&self) -> CoreResult<SavedReminderSettings> {
+            Ok(SavedReminderSettings::default())
+        }
+    }
+
+    /// Records each event with the Tokio time elapsed when it reached the sink.
+    struct RecordingSink {
+        started_at: Instant,
+        fired: Mutex<Vec<(Duration, ReminderEvent)>>,
+    }
+
+    impl RecordingSink {
+        fn new() -> Self {
+            Self {
+                started_at: Instant::now(),
+                fired: Mutex::new(Vec::new()),
+            }
+        }
+
+        fn fired_minutes(&self) -> Vec<u64> {
+            self.fired
+                .lock()
+                .expect("sink lock")
+                .iter()
+                .map(|(elapsed, _): &(Duration, ReminderEvent)| elapsed.as_secs() / 60)
+                .collect()
+        }
+
+        fn fired_kinds(&self) -> Vec<ReminderKind> {
+            self.fired
+                .lock()
+                .expect("sink lock")
+                .iter()
+                .map(|(_, event): &(Duration, ReminderEvent)| event.kind)
+                .collect()
+        }
+
+        fn fired_local_times(&self) -> Vec<String> {
+            self.fired
+                .lock()
+                .expect("sink lock")
+                .iter()
+                .map(|(_, event): &(Duration, ReminderEvent)| {
+                    event
+                        .fired_at
+                        .with_timezone(&Local)
+                        .format("%a %H:%M")
+                        .to_string()
+                })
+                .collect()
+        }
+    }
+
+    #[async_trait]
+    impl ReminderEventSink for RecordingSink {
+        async fn emit_reminder(&self, event: &ReminderEvent) -> CoreResult<()> {
+            self.fired
+                .lock()
+                .expect("sink lock")
+                .push((self.started_at.elapsed(), event.clone()));
+            Ok(())
+        }
+    }
+
+    /// Sink whose delivery panics, like a bug in a custom sink.
+    struct PanickingSink;
+
+    #[async_trait]
+    impl ReminderEventSink for PanickingSink {
+        async fn emit_reminder(&self, _event: &ReminderEvent) -> CoreResult<()> {
+            panic!("sink bug");
+        }
+    }
+
+    /// Service over an empty log, starting on Monday 2026-01-05 at 10:00 local time.
+    fn in_memory_service() -> HydrationService {
+        in_memory_service_with_clock(Arc::new(TokioClock::starting_at_local("2026-01-05 10:00")))
+    }
+
+    fn in_memory_service_with_clock(clock: Arc<dyn Clock>) -> HydrationService {
+        HydrationService::new(Arc::new(InMemoryLogRepository::default()), clock)
+    }
+
+    /// Wires a reminder service that emits to `sink`, with no intake and default settings.
+    fn reminder_service(service: HydrationService, sink: Arc<dyn ReminderEventSink>) -> ReminderService {
+        reminder_service_with_intake(service, sink, NoIntakeRepository::default())
+    }
+
+    fn reminder_service_with_intake(
+        service: HydrationService,
+        sink: Arc<dyn ReminderEventSink>,
+        intake: NoIntakeRepository,
+    ) -> ReminderService {
+        let intake_service: IntakeService = IntakeService::new(Arc::new(intake), service.clock());
+        let settings_service: SettingsService = SettingsService::new(Arc::new(DefaultSettingsRepository));
+        ReminderService::new(service, intake_service, settings_service, sink)
+    }
+
+    type ConfigChannel = (watch::Sender<ReminderConfig>, watch::Receiver<ReminderConfig>);
+
+    fn time(raw: &str) -> NaiveTime {
+        NaiveTime::parse_from_str(raw, "%H:%M").expect("valid time")
+    }
+
+    async fn advance_minutes(minutes: u64) {
+        tokio::time::sleep(Duration::from_secs(minutes * 60)).await;
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn fires_once_per_interval() -> CoreResult<()> {
+        let sink: Arc<RecordingSink> = Arc::new(RecordingSink::new());
+        let mut scheduler: HydrationScheduler = HydrationScheduler::new();
+        let (_config_tx, config_rx): ConfigChannel = watch::channel(ReminderConfig::new(30));
+
+        scheduler.start(
+            reminder_service(in_memory_service(), sink.clone()),
+            ReminderConfig::new(30),
+            config_rx,
+        )?;
+        advance_minutes(95).await;
+        scheduler.stop().await?;
+
+        assert_eq!(sink.fired_minutes(), vec![30, 60, 90]);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn snooze_reschedules_the_next_reminder() -> CoreResult<()> {
+        let service: HydrationService = in_memory_service();
+        let sink: Arc<RecordingSink> = Arc::new(RecordingSink::new());
+        let mut scheduler: HydrationScheduler = HydrationScheduler::new();
+        let (_config_tx, config_rx): ConfigChannel = watch::channel(ReminderConfig::new(30));
+        scheduler.start(
+            reminder_service(service.clone(), sink.clone()),
+            ReminderConfig::new(30),
+            config_rx,
+        )?;
+
+        advance_minutes(31).await;
+        service
+            .respond_to_reminder(1, ReminderResponse::Snoozed { minutes: 5 })
+            .await?;
+        advance_minutes(40).await;
+        scheduler.stop().await?;
+
+        // Snoozed at minute 31: fires at 36, then resumes the 30-minute cadence at 66.
+        assert_eq!(sink.fired_minutes(), vec![30, 36, 66]);
+        assert_eq!(
+            sink.fired_kinds(),
+            vec![ReminderKind::Scheduled, ReminderKind::Snoozed, ReminderKind::Scheduled]
+        );
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn no_reminders_fire_outside_the_active_window() -> CoreResult<()> {
+        // 2026-01-05 is a Monday; start shortly before the window closes.
+        let clock: Arc<TokioClock> = Arc::new(TokioClock::starting_at_local("2026-01-05 17:10"));
+        let sink: Arc<RecordingSink> = Arc::new(RecordingSink::new());
+        let window: ActiveWindow =
+            ActiveWindow::new(time("09:00"), time("18:00"), WeekdaySet::WEEKDAYS).expect("valid window");
+        let config: ReminderConfig = ReminderConfig::new(30).with_active_window(Some(window));
+        let mut scheduler: HydrationScheduler = HydrationScheduler::new();
+        let (_config_tx, config_rx): ConfigChannel = watch::channel(config);
+
+        scheduler.start(
+            reminder_service(in_memory_service_with_clock(clock), sink.clone()),
+            config,
+            config_rx,
+        )?;
+        // Run until Tuesday 10:15.
+        advance_minutes(17 * 60 + 5).await;
+        scheduler.stop().await?;
+
+        assert_eq!(
+            sink.fired_local_times(),
+            vec!["Mon 17:40", "Tue 09:00", "Tue 09:30", "Tue 10:00"]
+        );
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn quiet_hours_defer_reminders_until_they_end() -> CoreResult<()> {
+        let clock: Arc<TokioClock> = Arc::new(TokioClock::starting_at_local("2026-01-05 11:50"));
+        let sink: Arc<RecordingSink> = Arc::new(RecordingSink::new());
+        let config: ReminderConfig = ReminderConfig::new(30)
+            .with_quiet_hours(QuietHours::new(time("12:00"), time("13:00")));
+        let mut scheduler: HydrationScheduler = HydrationScheduler::new();
+        let (_config_tx, config_rx): ConfigChannel = watch::channel(config);
+
+        scheduler.start(
+            reminder_service(in_memory_service_with_clock(clock), sink.clone()),
+            config,
+            config_rx,
+        )?;
+        advance_minutes(105).await;
+        scheduler.stop().await?;
+
+        assert_eq!(sink.fired_local_times(), vec!["Mon 13:00", "Mon 13:30"]);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn shorter_interval_rearms_from_the_last_reminder() -> CoreResult<()> {
+        let sink: Arc<RecordingSink> = Arc::new(RecordingSink::new());
+        let mut scheduler: HydrationScheduler = HydrationScheduler::new();
+        let (config_tx, config_rx): ConfigChannel = watch::channel(ReminderConfig::new(30));
+        scheduler.start(
+            reminder_service(in_memory_service(), sink.clone()),
+            ReminderConfig::new(30),
+            config_rx,
+        )?;
+
+        advance_minutes(10).await;
+        config_tx.send_replace(ReminderConfig::new(15));
+        advance_minutes(25).await;
+        // Five minutes after the reminder at 30, a 4-minute interval is already overdue.
+        config_tx.send_replace(ReminderConfig::new(4));
+        advance_minutes(16).await;
+        scheduler.stop().await?;
+
+        assert_eq!(sink.fired_minutes(), vec![15, 30, 35, 39, 43, 47]);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn longer_interval_pushes_back_the_pending_reminder() -> CoreResult<()> {
+        let sink: Arc<RecordingSink> = Arc::new(RecordingSink::new());
+        let mut scheduler: HydrationScheduler = HydrationScheduler::new();
+        let (config_tx, config_rx): ConfigChannel = watch::channel(ReminderConfig::new(30));
+        scheduler.start(
+            reminder_service(in_memory_service(), sink.clone()),
+            ReminderConfig::new(30),
+            config_rx,
+        )?;
+
+        advance_minutes(20).await;
+        config_tx.send_replace(ReminderConfig::new(60));
+        advance_minutes(50).await;
+        scheduler.stop().await?;
+
+        assert_eq!(sink.fired_minutes(), vec![60]);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn cron_schedule_drives_fire_times() -> CoreResult<()> {
+        // 2026-01-09 is a Friday.
+        let clock: Arc<TokioClock> = Arc::new(TokioClock::starting_at_local("2026-01-09 17:00"));
+        let sink: Arc<RecordingSink> = Arc::new(RecordingSink::new());
+        let cron: CronSchedule = "15,45 9-17 * * mon-fri".parse().expect("valid cron expression");
+        let config: ReminderConfig = ReminderConfig::new(30).with_cron(Some(cron));
+        let mut scheduler: HydrationScheduler = HydrationScheduler::new();
+        let (_config_tx, config_rx): ConfigChannel = watch::channel(config);
+
+        scheduler.start(
+            reminder_service(in_memory_service_with_clock(clock), sink.clone()),
+            config,
+            config_rx,
+        )?;
+        // Run until Monday 09:50.
+        advance_minutes((2 * 24 + 16) * 60 + 50).await;
+        scheduler.stop().await?;
+
+        assert_eq!(
+            sink.fired_local_times(),
+            vec!["Fri 17:15", "Fri 17:45", "Mon 09:15", "Mon 09:45"]
+        );
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn restart_keeps_the_cadence_of_the_last_persisted_reminder() -> CoreResult<()> {
+        let clock: Arc<TokioClock> = Arc::new(TokioClock::starting_at_local("2026-01-05 10:00"));
+        let repository: InMemoryLogRepository = InMemoryLogRepository {
+            previous_run_last_at: Some(local_instant("2026-01-05 09:50")),
+            ..InMemoryLogRepository::default()
+        };
+        let service: HydrationService = HydrationService::new(Arc::new(repository), clock);
+        let sink: Arc<RecordingSink> = Arc::new(RecordingSink::new());
+        let mut scheduler: HydrationScheduler = HydrationScheduler::new();
+        let (_config_tx, config_rx): ConfigChannel = watch::channel(ReminderConfig::new(30));
+
+        scheduler.start(
+            reminder_service(service, sink.clone()),
+            ReminderConfig::new(30),
+            config_rx,
+        )?;
+        advance_minutes(55).await;
+        scheduler.stop().await?;
+
+        assert_eq!(sink.fired_local_times(), vec!["Mon 10:20", "Mon 10:50"]);
+        Ok(())
+    }
+
+    /// Fires at 10:30, then the machine sleeps for two hours and wakes at 12:31:30.
+    async fn fired_across_suspend(policy: CatchUpPolicy) -> CoreResult<Arc<RecordingSink>> {
+        let clock: Arc<TokioClock> = Arc::new(TokioClock::starting_at_local("2026-01-05 10:00"));
+        let sink: Arc<RecordingSink> = Arc::new(RecordingSink::new());
+        let config: ReminderConfig = ReminderConfig::new(30).with_catch_up(policy);
+        let mut scheduler: HydrationScheduler = HydrationScheduler::new();
+        let (_config_tx, config_rx): ConfigChannel = watch::channel(config);
+        scheduler.start(
+            reminder_service(in_memory_service_with_clock(clock.clone()), sink.clone()),
+            config,
+            config_rx,
+        )?;
+
+        tokio::time::sleep(Duration::from_secs(31 * 60 + 30)).await;
+        clock.jump_minutes(120);
+        advance_minutes(5).await;
+        scheduler.stop().await?;
+
+        Ok(sink)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn wake_from_suspend_fires_one_catch_up_reminder_by_default() -> CoreResult<()> {
+        let sink: Arc<RecordingSink> = fired_across_suspend(CatchUpPolicy::default()).await?;
+
+        // Noticed on the next tick; the cadence then restarts from the catch-up reminder.
+        assert_eq!(sink.fired_local_times(), vec!["Mon 10:30", "Mon 12:32"]);
+        assert_eq!(sink.fired_kinds(), vec![ReminderKind::Scheduled, ReminderKind::CatchUp]);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn wake_from_suspend_can_skip_missed_reminders() -> CoreResult<()> {
+        let fired: Vec<String> = fired_across_suspend(CatchUpPolicy::Skip).await?.fired_local_times();
+
+        assert_eq!(fired, vec!["Mon 10:30"]);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn wake_from_suspend_can_replay_every_missed_reminder() -> CoreResult<()> {
+        let fired: Vec<String> = fired_across_suspend(CatchUpPolicy::FireAll).await?.fired_local_times();
+
+        // 11:00, 11:30, 12:00 and 12:30 were all slept through.
+        assert_eq!(
+            fired,
+            vec!["Mon 10:30", "Mon 12:32", "Mon 12:32", "Mon 12:32", "Mon 12:32"]
+        );
+        Ok(())
+    }
+
+    /// Sink that rejects every event, as a fan-out sink does when all of its sinks fail.
+    struct UnreachableSink;
+
+    #[async_trait]
+    impl ReminderEventSink for UnreachableSink {
+        async fn emit_reminder(&self, _event: &ReminderEvent) -> CoreResult<()> {
+            Err(CoreError::ReminderDeliveryFailed { failed: 1 })
+        }
+    }
+
+    fn service_failing_inserts_with(failures: Vec<CoreError>) -> HydrationService {
+        let repository: InMemoryLogRepository = InMemoryLogRepository {
+            insert_failures: Mutex::new(failures),
+            ..InMemoryLogRepository::default()
+        };
+        HydrationService::new(
+            Arc::new(repository),
+            Arc::new(TokioClock::starting_at_local("2026-01-05 10:00")),
+        )
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn busy_database_is_retried_with_backoff() -> CoreResult<()> {
+        let busy = || CoreError::Sqlite(sqlx::Error::PoolTimedOut);
+        let service: HydrationService = service_failing_inserts_with(vec![busy(), busy()]);
+        let sink: Arc<RecordingSink> = Arc::new(RecordingSink::new());
+        let mut scheduler: HydrationScheduler = HydrationScheduler::new();
+        let (_config_tx, config_rx): ConfigChannel = watch::channel(ReminderConfig::new(30));
+        scheduler.start(reminder_service(service, sink.clone()), ReminderConfig::new(30), config_rx)?;
+        let mut status: watch::Receiver<SchedulerStatus> = scheduler.subscribe_status();
+
+        advance_minutes(30).await;
+        let retrying: HealthState = status
+            .wait_for(|status: &SchedulerStatus| status.health != HealthState::Healthy)
+            .await
+            .expect("scheduler alive")
+            .health;
+        assert_eq!(retrying, HealthState::Retrying { attempt: 1 });
+        advance_minutes(5).await;
+        scheduler.stop().await?;
+
+        // Retried one and then two seconds later; the cadence continues from that reminder.
+        let fired: Vec<Duration> = sink
+            .fired
+            .lock()
+            .expect("sink lock")
+            .iter()
+            .map(|(elapsed, _): &(Duration, ReminderEvent)| *elapsed)
+            .collect();
+        assert_eq!(fired, vec![Duration::from_secs(30 * 60 + 3)]);
+        let status: SchedulerStatus = scheduler.status();
+        assert_eq!(status.health, HealthState::Healthy);
+        assert_eq!(status.last_error.map(|err: SchedulerError| err.kind), Some(FailureKind::Transient));
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn failed_progress_reads_are_retried_without_logging_twice() -> CoreResult<()> {
+        let intake: NoIntakeRepository = NoIntakeRepository {
+            total_failures: Mutex::new(vec![CoreError::Sqlite(sqlx::Error::PoolTimedOut)]),
+        };
+        let service: HydrationService = in_memory_service();
+        let sink: Arc<RecordingSink> = Arc::new(RecordingSink::new());
+        let mut scheduler: HydrationScheduler = HydrationScheduler::new();
+        let (_config_tx, config_rx): ConfigChannel = watch::channel(ReminderConfig::new(30));
+        scheduler.start(
+            reminder_service_with_intake(service.clone(), sink.clone(), intake),
+            ReminderConfig::new(30),
+            config_rx,
+        )?;
+
+        advance_minutes(31).await;
+        scheduler.stop().await?;
+
+        assert_eq!(service.reminder_count().await?, 1);
+        assert_eq!(sink.fired.lock().expect("sink lock").len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn sink_failures_do_not_stop_the_loop() -> CoreResult<()> {
+        let service: HydrationService = in_memory_service();
+        let mut scheduler: HydrationScheduler = HydrationScheduler::new();
+        let (_config_tx, config_rx): ConfigChannel = watch::channel(ReminderConfig::new(30));
+        scheduler.start(
+            reminder_service(service.clone(), Arc::new(UnreachableSink)),
+            ReminderConfig::new(30),
+            config_rx,
+        )?;
+
+        advance_minutes(65).await;
+        let status: SchedulerStatus = scheduler.status();
+        scheduler.stop().await?;
+
+        assert_eq!(service.reminder_count().await?, 2);
+        assert_eq!(status.health, HealthState::Degraded);
+        assert_eq!(status.fired_count, 2);
+        assert_eq!(status.last_error.map(|err: SchedulerError| err.kind), Some(FailureKind::Delivery));
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn fatal_errors_stop_the_loop() -> CoreResult<()> {
+        let corrupt: CoreError = CoreError::InvalidPersistedValue {
+            field: "triggered_at",
+            value: -1,
+        };
+        let service: HydrationService = service_failing_inserts_with(vec![corrupt]);
+        let sink: Arc<RecordingSink> = Arc::new(RecordingSink::new());
+        let mut scheduler: HydrationScheduler = HydrationScheduler::new();
+        let (_config_tx, config_rx): ConfigChannel = watch::channel(ReminderConfig::new(30));
+        scheduler.start(reminder_service(service, sink.clone()), ReminderConfig::new(30), config_rx)?;
+
+        advance_minutes(65).await;
+
+        let status: SchedulerStatus = scheduler.status();
+        assert_eq!((status.state, status.health), (SchedulerState::Stopped, HealthState::Failed));
+        assert!(matches!(
+            scheduler.stop().await,
+            Err(CoreError::InvalidPersistedValue { .. })
+        ));
+        assert!(sink.fired_minutes().is_empty());
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn panics_mark_the_loop_failed() -> CoreResult<()> {
+        let mut scheduler: HydrationScheduler = HydrationScheduler::new();
+        let mut status_rx: watch::Receiver<SchedulerStatus> = scheduler.subscribe_status();
+        let (_config_tx, config_rx): ConfigChannel = watch::channel(ReminderConfig::new(30));
+        scheduler.start(
+            reminder_service(in_memory_service(), Arc::new(PanickingSink)),
+            ReminderConfig::new(30),
+            config_rx,
+        )?;
+
+        advance_minutes(35).await;
+        let status: SchedulerStatus = status_rx
+            .wait_for(|status: &SchedulerStatus| status.state == SchedulerState::Stopped)
+            .await
+            .expect("scheduler alive")
+            .clone();
+
+        assert_eq!(status.health, HealthState::Failed);
+        assert_eq!(status.last_error.map(|err: SchedulerError| err.kind), Some(FailureKind::Panic));
+        assert!(matches!(scheduler.stop().await, Err(CoreError::TaskJoin(_))));
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn status_tracks_next_and_last_fire_times() -> CoreResult<()> {
+        let clock: Arc<TokioClock> = Arc::new(TokioClock::starting_at_local("2026-01-05 10:00"));
+        let repository: InMemoryLogRepository = InMemoryLogRepository {
+            previous_run_last_at: Some(local_instant("2026-01-05 09:50")),
+            ..InMemoryLogRepository::default()
+        };
+        let service: HydrationService = HydrationService::new(Arc::new(repository), clock);
+        let sink: Arc<RecordingSink> = Arc::new(RecordingSink::new());
+        let mut scheduler: HydrationScheduler = HydrationScheduler::new();
+        let (_config_tx, config_rx): ConfigChannel = watch::channel(ReminderConfig::new(30));
+        assert_eq!(scheduler.status(), SchedulerStatus::default());
+
+        scheduler.start(reminder_service(service, sink.clone()), ReminderConfig::new(30), config_rx)?;
+        let mut status: watch::Receiver<SchedulerStatus> = scheduler.subscribe_status();
+        let resumed: SchedulerStatus = status
+            .wait_for(|status: &SchedulerStatus| status.next_fire_at.is_some())
+            .await
+            .expect("scheduler alive")
+            .clone();
+        assert_eq!(resumed.state, SchedulerState::Running);
+        assert_eq!(resumed.last_fired_at, Some(local_instant("2026-01-05 09:50")));
+        assert_eq!(resumed.next_fire_at, Some(local_instant("2026-01-05 10:20")));
+
+        advance_minutes(25).await;
+        let fired: SchedulerStatus = scheduler.status();
+        assert_eq!(fired.fired_count, 1);
+        assert_eq!(fired.last_fired_at, Some(local_instant("2026-01-05 10:20")));
+        assert_eq!(fired.next_fire_at, Some(local_instant("2026-01-05 10:50")));
+
+        scheduler.stop().await?;
+        let stopped: SchedulerStatus = scheduler.status();
+        assert_eq!((stopped.state, stopped.next_fire_at), (SchedulerState::Stopped, None));
+        assert_eq!(stopped.fired_count, 1);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn timed_pause_holds_reminders_then_restarts_the_cadence() -> CoreResult<()> {
+        let service: HydrationService = in_memory_service();
+        let sink: Arc<RecordingSink> = Arc::new(RecordingSink::new());
+        let mut scheduler: HydrationScheduler = HydrationScheduler::new();
+        let (config_tx, config_rx): ConfigChannel = watch::channel(ReminderConfig::new(30));
+        scheduler.start(reminder_service(service.clone(), sink.clone()), ReminderConfig::new(30), config_rx)?;
+
+        advance_minutes(40).await;
+        let paused_until: DateTime<Utc> = local_instant("2026-01-05 11:40");
+        config_tx
+            .send(ReminderConfig::new(30).with_pause(Some(ReminderPause::Until(paused_until))))
+            .expect("scheduler subscribed");
+        advance_minutes(1).await;
+        let status: SchedulerStatus = scheduler.status();
+        assert_eq!((status.state, status.next_fire_at), (SchedulerState::Paused, Some(paused_until)));
+
+        // A snooze during the pause is held too.
+        service.respond_to_reminder(1, ReminderResponse::Snoozed { minutes: 5 }).await?;
+        advance_minutes(90).await;
+        scheduler.stop().await?;
+
+        // Nothing between 10:40 and 11:40; the next reminder is one interval after the pause.
+        assert_eq!(sink.fired_local_times(), vec!["Mon 10:30", "Mon 12:10"]);
+        assert_eq!(sink.fired_kinds(), vec![ReminderKind::Scheduled, ReminderKind::Scheduled]);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn indefinite_pause_lasts_until_resumed() -> CoreResult<()> {
+        let sink: Arc<RecordingSink> = Arc::new(RecordingSink::new());
+        let paused: ReminderConfig = ReminderConfig::new(30).with_pause(Some(ReminderPause::Indefinite));
+        let mut scheduler: HydrationScheduler = HydrationScheduler::new();
+        let (config_tx, config_rx): ConfigChannel = watch::channel(paused);
+        scheduler.start(reminder_service(in_memory_service(), sink.clone()), paused, config_rx)?;
+
+        advance_minutes(180).await;
+        let status: SchedulerStatus = scheduler.status();
+        assert_eq!((status.state, status.next_fire_at), (SchedulerState::Paused, None));
+        assert!(sink.fired_minutes().is_empty());
+
+        config_tx.send(ReminderConfig::new(30)).expect("scheduler subscribed");
+        advance_minutes(35).await;
+        scheduler.stop().await?;
+
+        assert_eq!(sink.fired_minutes(), vec![210]);
+        Ok(())
+    }
+}
@@ -0,0 +1,388 @@
+//! Tokio-backed scheduler for named background jobs with their own handlers.
+//!
+//! The hydration reminder does not run here: it needs snoozes, pauses, live
+//! reconfiguration, active windows, catch-up after sleep and retries with health
+//! reporting, while a job here is a plain handler that stops on its first error.
+//! `HydrationScheduler` keeps that loop; both share `MAX_TICK` for sleeping.
+use super::MAX_TICK;
+use crate::domain::schedule::JobSchedule;
+use crate::error::{CoreError, CoreResult};
+use crate::ports::clock::Clock;
+use async_trait::async_trait;
+use chrono::{DateTime, Local, Utc};
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+
+/// Work run by the job scheduler each time a job's schedule comes due.
+#[async_trait]
+pub trait JobHandler: Send + Sync {
+    /// Runs the job once; an error stops the job and is returned when it is removed.
+    async fn run(&self) -> CoreResult<()>;
+}
+
+/// Lifecycle state of a scheduled job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobStatus {
+    Scheduled,
+    Paused,
+    /// The schedule has no further runs, or the handler failed.
+    Finished,
+}
+
+/// Snapshot of one job as returned by `JobScheduler::list`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JobInfo {
+    pub name: String,
+    pub schedule: JobSchedule,
+    pub status: JobStatus,
+    pub next_run_at: Option<DateTime<Utc>>,
+    pub last_run_at: Option<DateTime<Utc>>,
+    pub run_count: u64,
+}
+
+/// Requested state, published to a job's task on a watch channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JobControl {
+    Running,
+    Paused,
+    Stopped,
+}
+
+/// Run bookkeeping shared between a job's task and `JobScheduler::list`.
+#[derive(Debug, Default)]
+struct JobRuns {
+    next_run_at: Option<DateTime<Utc>>,
+    last_run_at: Option<DateTime<Utc>>,
+    run_count: u64,
+}
+
+struct JobSlot {
+    schedule: JobSchedule,
+    control_tx: watch::Sender<JobControl>,
+    runs: Arc<Mutex<JobRuns>>,
+    join_handle: JoinHandle<CoreResult<()>>,
+}
+
+/// Runs many named jobs, each in its own Tokio task.
+pub struct JobScheduler {
+    clock: Arc<dyn Clock>,
+    jobs: BTreeMap<String, JobSlot>,
+}
+
+impl JobScheduler {
+    /// Creates an empty scheduler that reads wall-clock time from `clock`.
+    pub fn new(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            clock,
+            jobs: BTreeMap::new(),
+        }
+    }
+
+    /// Adds a job under a unique name and starts its task.
+    pub fn schedule(
+        &mut self,
+        name: &str,
+        schedule: JobSchedule,
+        handler: Arc<dyn JobHandler>,
+    ) -> CoreResult<()> {
+        if self.jobs.contains_key(name) {
+            return Err(CoreError::JobAlreadyExists(name.to_string()));
+        }
+        if schedule.next_after(self.clock.now(), &Local).is_none() {
+            return Err(CoreError::JobNeverRuns(name.to_string()));
+        }
+
+        let (control_tx, control_rx): (watch::Sender<JobControl>, watch::Receiver<JobControl>) =
+            watch::channel(JobControl::Running);
+        let runs: Arc<Mutex<JobRuns>> = Arc::new(Mutex::new(JobRuns::default()));
+        let join_handle: JoinHandle<CoreResult<()>> = tokio::spawn(run_job(
+            schedule.clone(),
+            handler,
+            self.clock.clone(),
+            runs.clone(),
+            control_rx,
+        ));
+
+        self.jobs.insert(
+            name.to_string(),
+            JobSlot {
+                schedule,
+                control_tx,
+                runs,
+                join_handle,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Lists every job, ordered by name.
+    pub fn list(&self) -> Vec<JobInfo> {
+        self.jobs
+            .iter()
+            .map(|(name, slot): (&String, &JobSlot)| {
+                let runs: MutexGuard<'_, JobRuns> = slot.runs.lock().unwrap_or_else(PoisonError::into_inner);
+                let status: JobStatus = if slot.join_handle.is_finished() {
+                    JobStatus::Finished
+                } else if *slot.control_tx.borrow() == JobControl::Paused {
+                    JobStatus::Paused
+                } else {
+                    JobStatus::Scheduled
+                };
+
+                JobInfo {
+                    name: name.clone(),
+                    schedule: slot.schedule.clone(),
+                    status,
+                    next_run_at: (status == JobStatus::Scheduled).then_some(runs.next_run_at).flatten(),
+                    last_run_at: runs.last_run_at,
+                    run_count: runs.run_count,
+                }
+            })
+            .collect()
+    }
+
+    /// Stops a job from running until it is resumed; runs due meanwhile are skipped.
+    pub fn pause(&self, name: &str) -> CoreResult<()> {
+        self.control(name, JobControl::Paused)
+    }
+
+    /// Resumes a paused job; interval jobs count their next period from now.
+    pub fn resume(&self, name: &str) -> CoreResult<()> {
+        self.control(name, JobControl::Running)
+    }
+
+    /// Stops a job, waits for its task and forgets it.
+    pub async fn remove(&mut self, name: &str) -> CoreResult<()> {
+        let slot: JobSlot = self
+            .jobs
+            .remove(name)
+            .ok_or_else(|| CoreError::JobNotFound(name.to_string()))?;
+        stop_slot(slot).await
+    }
+
+    /// Stops and forgets every job, returning the first failure after all have stopped.
+    pub async fn remove_all(&mut self) -> CoreResult<()> {
+        let mut result: CoreResult<()> = Ok(());
+        for (_, slot) in std::mem::take(&mut self.jobs) {
+            let stopped: CoreResult<()> = stop_slot(slot).await;
+            if result.is_ok() {
+                result = stopped;
+            }
+        }
+
+        result
+    }
+
+    fn control(&self, name: &str, control: JobControl) -> CoreResult<()> {
+        let slot: &JobSlot = self
+            .jobs
+            .get(name)
+            .ok_or_else(|| CoreError::JobNotFound(name.to_string()))?;
+        slot.control_tx.send_replace(control);
+        Ok(())
+    }
+}
+
+async fn stop_slot(slot: JobSlot) -> CoreResult<()> {
+    slot.control_tx.send_replace(JobControl::Stopped);
+    slot.join_handle.await?
+}
+
+/// Body of one job's task: sleeps until the next wall-clock run, honouring control changes.
+async fn run_job(
+    schedule: JobSchedule,
+    handler: Arc<dyn JobHandler>,
+    clock: Arc<dyn Clock>,
+    runs: Arc<Mutex<JobRuns>>,
+    mut control_rx: watch::Receiver<JobControl>,
+) -> CoreResult<()> {
+    // Runs are counted from the previous run, or from when the job was added or resumed.
+    let mut anchor: DateTime<Utc> = clock.now();
+
+    loop {
+        let control: JobControl = *control_rx.borrow_and_update();
+        match control {
+            JobControl::Stopped => return Ok(()),
+            JobControl::Paused => {
+                if control_rx.changed().await.is_err() {
+                    return Ok(());
+                }
+                anchor = clock.now();
+                continue;
+            }
+            JobControl::Running => {}
+        }
+
+        let Some(next_run_at) = schedule.next_after(anchor, &Local) else {
+            runs.lock().unwrap_or_else(PoisonError::into_inner).next_run_at = None;
+            return Ok(());
+        };
+        runs.lock().unwrap_or_else(PoisonError::into_inner).next_run_at = Some(next_run_at);
+
+        let now: DateTime<Utc> = clock.now();
+        let sleep_for: Duration = (next_run_at - now).to_std().unwrap_or(Duration::ZERO).min(MAX_TICK);
+
+        tokio::select! {
+            // Control changes win ties so a pause or removal never races with a run.
+            biased;
+
+            changed = control_rx.changed() => {
+                if changed.is_err() {
+                    return Ok(());
+                }
+            }
+            _ = tokio::time::sleep(sleep_for) => {
+                if clock.now() < next_run_at {
+                    continue;
+                }
+
+                handler.run().await?;
+                anchor = clock.now();
+                let mut runs: MutexGuard<'_, JobRuns> = runs.lock().unwrap_or_else(PoisonError::into_inner);
+                runs.last_run_at = Some(anchor);
+                runs.run_count += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::inbound::scheduler::test_clock::{local_instant, TokioClock};
+    use crate::domain::hydration::WeekdaySet;
+    use chrono::NaiveTime;
+
+    /// Records the local wall-clock time of each run.
+    struct RecordingJob {
+        clock: Arc<dyn Clock>,
+        runs: Mutex<Vec<String>>,
+    }
+
+    impl RecordingJob {
+        fn new(clock: Arc<dyn Clock>) -> Arc<Self> {
+            Arc::new(Self {
+                clock,
+                runs: Mutex::new(Vec::new()),
+            })
+        }
+
+        fn local_runs(&self) -> Vec<String> {
+            self.runs.lock().expect("runs lock").clone()
+        }
+    }
+
+    #[async_trait]
+    impl JobHandler for RecordingJob {
+        async fn run(&self) -> CoreResult<()> {
+            let local: String = self.clock.now().with_timezone(&Local).format("%a %H:%M").to_string();
+            self.runs.lock().expect("runs lock").push(local);
+            Ok(())
+        }
+    }
+
+    struct FailingJob;
+
+    #[async_trait]
+    impl JobHandler for FailingJob {
+        async fn run(&self) -> CoreResult<()> {
+            Err(CoreError::EmptyJournalEntry)
+        }
+    }
+
+    /// Scheduler whose clock starts on Monday 2026-01-05 at 10:00 local time.
+    fn scheduler() -> (JobScheduler, Arc<dyn Clock>) {
+        let clock: Arc<dyn Clock> = Arc::new(TokioClock::starting_at_local("2026-01-05 10:00"));
+        (JobScheduler::new(clock.clone()), clock)
+    }
+
+    fn time(raw: &str) -> NaiveTime {
+        NaiveTime::parse_from_str(raw, "%H:%M").expect("valid time")
+    }
+
+    async fn advance_minutes(minutes: u64) {
+        tokio::time::sleep(Duration::from_secs(minutes * 60)).await;
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn runs_interval_daily_and_one_shot_jobs_side_by_side() -> CoreResult<()> {
+        let (mut scheduler, clock): (JobScheduler, Arc<dyn Clock>) = scheduler();
+        let stretch: Arc<RecordingJob> = RecordingJob::new(clock.clone());
+        let standup: Arc<RecordingJob> = RecordingJob::new(clock.clone());
+        let demo: Arc<RecordingJob> = RecordingJob::new(clock.clone());
+        let daily: JobSchedule =
+            JobSchedule::daily_at(&[time("10:30"), time("11:15")], WeekdaySet::WEEKDAYS).expect("valid schedule");
+
+        scheduler.schedule("stretch", JobSchedule::every_minutes(45), stretch.clone())?;
+        scheduler.schedule("standup", daily, standup.clone())?;
+        scheduler.schedule("demo", JobSchedule::Once(local_instant("2026-01-05 11:00")), demo.clone())?;
+        advance_minutes(100).await;
+
+        let jobs: Vec<JobInfo> = scheduler.list();
+        scheduler.remove_all().await?;
+
+        assert_eq!(stretch.local_runs(), vec!["Mon 10:45", "Mon 11:30"]);
+        assert_eq!(standup.local_runs(), vec!["Mon 10:30", "Mon 11:15"]);
+        assert_eq!(demo.local_runs(), vec!["Mon 11:00"]);
+        let names: Vec<&str> = jobs.iter().map(|job: &JobInfo| job.name.as_str()).collect();
+        assert_eq!(names, vec!["demo", "standup", "stretch"]);
+        assert_eq!(jobs[0].status, JobStatus::Finished);
+        assert_eq!(jobs[0].next_run_at, None);
+        assert_eq!(jobs[1].next_run_at, Some(local_instant("2026-01-06 10:30")));
+        assert_eq!(jobs[2].run_count, 2);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn paused_jobs_skip_runs_until_resumed() -> CoreResult<()> {
+        let (mut scheduler, clock): (JobScheduler, Arc<dyn Clock>) = scheduler();
+        let job: Arc<RecordingJob> = RecordingJob::new(clock);
+        scheduler.schedule("stretch", JobSchedule::every_minutes(30), job.clone())?;
+
+        advance_minutes(35).await;
+        scheduler.pause("stretch")?;
+        assert_eq!(scheduler.list()[0].status, JobStatus::Paused);
+        advance_minutes(60).await;
+        scheduler.resume("stretch")?;
+        advance_minutes(35).await;
+        scheduler.remove("stretch").await?;
+
+        // Paused at 10:35, resumed at 11:35: the next run is one interval later.
+        assert_eq!(job.local_runs(), vec!["Mon 10:30", "Mon 12:05"]);
+        assert!(scheduler.list().is_empty());
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn job_names_are_unique_and_must_exist() -> CoreResult<()> {
+        let (mut scheduler, clock): (JobScheduler, Arc<dyn Clock>) = scheduler();
+        scheduler.schedule("stretch", JobSchedule::every_minutes(30), RecordingJob::new(clock.clone()))?;
+
+        assert!(matches!(
+            scheduler.schedule("stretch", JobSchedule::every_minutes(5), RecordingJob::new(clock.clone())),
+            Err(CoreError::JobAlreadyExists(name)) if name == "stretch"
+        ));
+        assert!(matches!(scheduler.pause("missing"), Err(CoreError::JobNotFound(_))));
+        assert!(matches!(
+            scheduler.schedule("past", JobSchedule::Once(local_instant("2026-01-05 09:00")), RecordingJob::new(clock)),
+            Err(CoreError::JobNeverRuns(_))
+        ));
+        scheduler.remove_all().await
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn handler_errors_finish_the_job_and_surface_on_remove() -> CoreResult<()> {
+        let (mut scheduler, _clock): (JobScheduler, Arc<dyn Clock>) = scheduler();
+        scheduler.schedule("broken", JobSchedule::every_minutes(1), Arc::new(FailingJob))?;
+
+        advance_minutes(2).await;
+
+        assert_eq!(scheduler.list()[0].status, JobStatus::Finished);
+        assert!(matches!(scheduler.remove("broken").await, Err(CoreError::EmptyJournalEntry)));
+        Ok(())
+    }
+}
@@ -1,4 +1,17 @@
 //! Inbound scheduler adapter modules for background reminder orchestration.
 mod hydration_scheduler;
+mod job_scheduler;
+mod status;
+mod supervision;
+#[cfg(test)]
+mod test_clock;
+
+use std::time::Duration;
 
 pub use hydration_scheduler::HydrationScheduler;
+pub use job_scheduler::{JobHandler, JobInfo, JobScheduler, JobStatus};
+pub use status::{SchedulerState, SchedulerStatus};
+pub use supervision::{FailureKind, HealthState, SchedulerError};
+
+/// Longest single sleep, so a wall-clock jump after suspend is noticed within a minute.
+const MAX_TICK: Duration = Duration::from_secs(60);
@@ -0,0 +1,51 @@
+//! Status snapshot published by the hydration scheduler for UIs and tray icons.
+use super::supervision::{HealthState, SchedulerError};
+use chrono::{DateTime, Utc};
+
+/// Whether the reminder loop is running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchedulerState {
+    Stopped,
+    Running,
+    /// Running, but reminders are held until the pause ends.
+    Paused,
+}
+
+impl SchedulerState {
+    /// Returns the stable lowercase name used by inbound adapters.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Stopped => "stopped",
+            Self::Running => "running",
+            Self::Paused => "paused",
+        }
+    }
+}
+
+/// Point-in-time view of the reminder loop, published on a `watch` channel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchedulerStatus {
+    pub state: SchedulerState,
+    pub health: HealthState,
+    /// When the next reminder is due; while paused, when a timed pause ends.
+    pub next_fire_at: Option<DateTime<Utc>>,
+    /// The latest reminder fired, including ones persisted by earlier runs.
+    pub last_fired_at: Option<DateTime<Utc>>,
+    /// Reminders fired since the loop was last started.
+    pub fired_count: u64,
+    /// The last error the loop handled; kept after it recovers.
+    pub last_error: Option<SchedulerError>,
+}
+
+impl Default for SchedulerStatus {
+    fn default() -> Self {
+        Self {
+            state: SchedulerState::Stopped,
+            health: HealthState::Healthy,
+            next_fire_at: None,
+            last_fired_at: None,
+            fired_count: 0,
+            last_error: None,
+        }
+    }
+}
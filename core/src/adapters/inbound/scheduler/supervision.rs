@@ -0,0 +1,225 @@
+//! Supervision policy that decides whether a scheduler error is retried, tolerated or fatal.
+use super::status::{SchedulerState, SchedulerStatus};
+use super::MAX_TICK;
+use crate::error::CoreError;
+use crate::ports::clock::Clock;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+
+/// First delay before retrying after a transient store error; doubles up to `MAX_TICK`.
+const INITIAL_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// How the scheduler reacted to an error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailureKind {
+    /// The database was busy or locked; the same work is retried after a backoff.
+    Transient,
+    /// The reminder was logged but no sink accepted it; the loop keeps its cadence.
+    Delivery,
+    /// Anything else; the loop stopped.
+    Fatal,
+    /// The loop's task panicked; the loop stopped.
+    Panic,
+}
+
+impl FailureKind {
+    /// Returns the stable lowercase name used by inbound adapters.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Transient => "transient",
+            Self::Delivery => "delivery",
+            Self::Fatal => "fatal",
+            Self::Panic => "panic",
+        }
+    }
+}
+
+/// The most recent error seen by the scheduler.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchedulerError {
+    pub kind: FailureKind,
+    pub message: String,
+    pub occurred_at: DateTime<Utc>,
+}
+
+/// Overall health of the reminder loop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthState {
+    Healthy,
+    /// The last reminder reached no sink; cleared by the next delivered reminder.
+    Degraded,
+    /// Waiting to retry after `attempt` consecutive transient errors.
+    Retrying { attempt: u32 },
+    /// Stopped on a fatal error or a panic.
+    Failed,
+}
+
+impl HealthState {
+    /// Returns the stable lowercase name used by inbound adapters.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Healthy => "healthy",
+            Self::Degraded => "degraded",
+            Self::Retrying { .. } => "retrying",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+/// Classifies loop errors, tracks retry backoff and publishes the loop's status.
+pub(super) struct Supervisor {
+    status_tx: watch::Sender<SchedulerStatus>,
+    clock: Arc<dyn Clock>,
+    attempt: u32,
+}
+
+impl Supervisor {
+    /// Publishes a fresh running status and returns the supervisor for a new run.
+    pub(super) fn start(status_tx: watch::Sender<SchedulerStatus>, clock: Arc<dyn Clock>) -> Self {
+        status_tx.send_modify(|status: &mut SchedulerStatus| {
+            *status = SchedulerStatus {
+                state: SchedulerState::Running,
+                last_fired_at: status.last_fired_at,
+                ..SchedulerStatus::default()
+            };
+        });
+        Self {
+            status_tx,
+            clock,
+            attempt: 0,
+        }
+    }
+
+    /// Records the last reminder persisted before this run.
+    pub(super) fn resumed(&self, last_fired_at: Option<DateTime<Utc>>) {
+        self.status_tx.send_modify(|status: &mut SchedulerStatus| {
+            status.last_fired_at = last_fired_at;
+        });
+    }
+
+    /// Publishes whether reminders are paused and when the loop next fires or resumes.
+    pub(super) fn scheduled(&self, state: SchedulerState, next_fire_at: Option<DateTime<Utc>>) {
+        self.status_tx.send_if_modified(|status: &mut SchedulerStatus| {
+            let changed: bool = status.state != state || status.next_fire_at != next_fire_at;
+            status.state = state;
+            status.next_fire_at = next_fire_at;
+            changed
+        });
+    }
+
+    /// Records a reminder that reached at least one sink.
+    pub(super) fn delivered(&mut self, fired_at: DateTime<Utc>) {
+        self.attempt = 0;
+        self.status_tx.send_modify(|status: &mut SchedulerStatus| {
+            status.health = HealthState::Healthy;
+            status.last_fired_at = Some(fired_at);
+            status.fired_count += 1;
+        });
+    }
+
+    /// Records a reminder that was logged but reached no sink.
+    pub(super) fn delivery_failed(&mut self, err: &CoreError) {
+        self.attempt = 0;
+        self.record(FailureKind::Delivery, err, HealthState::Degraded);
+        let fired_at: DateTime<Utc> = self.clock.now();
+        self.status_tx.send_modify(|status: &mut SchedulerStatus| {
+            status.last_fired_at = Some(fired_at);
+            status.fired_count += 1;
+        });
+    }
+
+    /// Records a pass that completed after earlier transient errors.
+    pub(super) fn recovered(&mut self) {
+        if self.attempt > 0 {
+            self.attempt = 0;
+            self.set_health(HealthState::Healthy);
+        }
+    }
+
+    /// Returns how long to wait before retrying, or hands back a fatal error.
+    pub(super) fn failed(&mut self, err: CoreError) -> Result<Duration, CoreError> {
+        if !err.is_transient() {
+            self.record(FailureKind::Fatal, &err, HealthState::Failed);
+            stop_status(&self.status_tx);
+            return Err(err);
+        }
+
+        self.attempt = self.attempt.saturating_add(1);
+        self.record(
+            FailureKind::Transient,
+            &err,
+            HealthState::Retrying {
+                attempt: self.attempt,
+            },
+        );
+        Ok(retry_delay(self.attempt))
+    }
+
+    fn record(&self, kind: FailureKind, err: &CoreError, health: HealthState) {
+        let error: SchedulerError = SchedulerError {
+            kind,
+            message: err.to_string(),
+            occurred_at: self.clock.now(),
+        };
+        self.status_tx.send_modify(|status: &mut SchedulerStatus| {
+            status.health = health;
+            status.last_error = Some(error);
+        });
+    }
+
+    fn set_health(&self, health: HealthState) {
+        self.status_tx.send_if_modified(|status: &mut SchedulerStatus| {
+            let changed: bool = status.health != health;
+            status.health = health;
+            changed
+        });
+    }
+}
+
+/// Marks the loop failed after its task panicked, which never reaches `Supervisor::failed`.
+pub(super) fn panic_status(status_tx: &watch::Sender<SchedulerStatus>, message: String, occurred_at: DateTime<Utc>) {
+    let error: SchedulerError = SchedulerError {
+        kind: FailureKind::Panic,
+        message,
+        occurred_at,
+    };
+    status_tx.send_modify(|status: &mut SchedulerStatus| {
+        status.health = HealthState::Failed;
+        status.last_error = Some(error);
+    });
+    stop_status(status_tx);
+}
+
+/// Marks the loop stopped and clears its pending fire time.
+pub(super) fn stop_status(status_tx: &watch::Sender<SchedulerStatus>) {
+    status_tx.send_if_modified(|status: &mut SchedulerStatus| {
+        let changed: bool = status.state != SchedulerState::Stopped || status.next_fire_at.is_some();
+        status.state = SchedulerState::Stopped;
+        status.next_fire_at = None;
+        changed
+    });
+}
+
+/// Exponential backoff for the given retry attempt, starting at one second.
+fn retry_delay(attempt: u32) -> Duration {
+    let doublings: u32 = attempt.saturating_sub(1).min(16);
+    INITIAL_RETRY_DELAY
+        .saturating_mul(1 << doublings)
+        .min(MAX_TICK)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_delay_doubles_up_to_the_tick_length() {
+        let delays: Vec<u64> = (1..=8)
+            .map(|attempt: u32| retry_delay(attempt).as_secs())
+            .collect();
+
+        assert_eq!(delays, vec![1, 2, 4, 8, 16, 32, 60, 60]);
+    }
+}
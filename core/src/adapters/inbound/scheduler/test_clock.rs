@@ -0,0 +1,43 @@
+//! Paused-time wall clock shared by scheduler tests.
+use crate::domain::hydration::resolve_local;
+use crate::ports::clock::Clock;
+use chrono::{DateTime, Local, NaiveDateTime, Utc};
+use std::sync::Mutex;
+use tokio::time::Instant;
+
+/// Wall clock that advances with Tokio's paused clock and can jump like a suspend.
+pub(super) struct TokioClock {
+    origin_wall: DateTime<Utc>,
+    origin: Instant,
+    jumped: Mutex<chrono::Duration>,
+}
+
+impl TokioClock {
+    pub(super) fn starting_at_local(raw: &str) -> Self {
+        Self {
+            origin_wall: local_instant(raw),
+            origin: Instant::now(),
+            jumped: Mutex::new(chrono::Duration::zero()),
+        }
+    }
+
+    /// Moves the wall clock forward without advancing Tokio time.
+    pub(super) fn jump_minutes(&self, minutes: i64) {
+        *self.jumped.lock().expect("clock lock") += chrono::Duration::minutes(minutes);
+    }
+}
+
+impl Clock for TokioClock {
+    fn now(&self) -> DateTime<Utc> {
+        let elapsed: chrono::Duration =
+            chrono::Duration::from_std(self.origin.elapsed()).expect("elapsed fits");
+        self.origin_wall + elapsed + *self.jumped.lock().expect("clock lock")
+    }
+}
+
+/// Resolves a `YYYY-MM-DD HH:MM` local wall-clock time to an instant.
+pub(super) fn local_instant(raw: &str) -> DateTime<Utc> {
+    let local: NaiveDateTime =
+        NaiveDateTime::parse_from_str(raw, "%Y-%m-%d %H:%M").expect("valid local time");
+    resolve_local(&Local, local)
+}
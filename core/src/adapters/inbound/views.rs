@@ -0,0 +1,516 @@
+//! Serializable views of core types, and request shapes, shared by the inbound JSON adapters.
+use crate::adapters::inbound::scheduler::{HealthState, SchedulerError, SchedulerStatus};
+use crate::domain::achievements::Achievement;
+use crate::domain::export::{ImportCounts, ImportStrategy, ImportSummary};
+use crate::domain::history::{HistoryCursor, HistoryPage, ReminderLogEntry, ReminderOutcome};
+use crate::domain::hydration::{
+    ActiveWindow, CronSchedule, HydrationGoal, HydrationProgress, IntakeSource, PauseLength, QuietHours,
+    ReminderAction, ReminderConfig, ReminderEvent, ReminderPause, WaterIntake,
+};
+use crate::domain::journal::{JournalEntry, JournalPrompt};
+use crate::domain::stats::{DailyIntake, HydrationStats};
+use chrono::{DateTime, Days, Local, NaiveDate, NaiveTime, Utc, Weekday};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Wire form of `PauseLength`: `{"minutes": 60}`, `"until_tomorrow"` or `"indefinitely"`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PauseLengthRequest {
+    Minutes(u32),
+    UntilTomorrow,
+    #[default]
+    Indefinitely,
+}
+
+impl From<PauseLengthRequest> for PauseLength {
+    fn from(request: PauseLengthRequest) -> Self {
+        match request {
+            PauseLengthRequest::Minutes(minutes) => Self::Minutes(minutes),
+            PauseLengthRequest::UntilTomorrow => Self::UntilTomorrow,
+            PauseLengthRequest::Indefinitely => Self::Indefinitely,
+        }
+    }
+}
+
+/// Deserializes an optional intake source name such as `"glass"`.
+pub(crate) fn intake_source<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<IntakeSource>, D::Error> {
+    let raw: Option<String> = Option::deserialize(deserializer)?;
+    raw.map(|raw: String| {
+        raw.parse::<IntakeSource>()
+            .map_err(|()| D::Error::custom(format!("unknown intake source {raw:?}")))
+    })
+    .transpose()
+}
+
+/// A fired reminder with the progress snapshot and the replies it offers.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReminderEventView {
+    pub id: i64,
+    pub kind: &'static str,
+    pub fired_at: DateTime<Utc>,
+    pub message: String,
+    pub progress: ProgressView,
+    pub actions: Vec<&'static str>,
+}
+
+impl From<&ReminderEvent> for ReminderEventView {
+    fn from(event: &ReminderEvent) -> Self {
+        Self {
+            id: event.id,
+            kind: event.kind.as_str(),
+            fired_at: event.fired_at,
+            message: event.message.clone(),
+            progress: ProgressView::from(event.progress),
+            actions: event
+                .actions
+                .iter()
+                .map(|action: &ReminderAction| action.as_str())
+                .collect(),
+        }
+    }
+}
+
+/// Intake consumed against the goal for the current hydration day.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct ProgressView {
+    pub consumed_ml: u64,
+    pub goal_ml: u32,
+    pub remaining_ml: u64,
+    pub percent: f64,
+}
+
+impl From<HydrationProgress> for ProgressView {
+    fn from(progress: HydrationProgress) -> Self {
+        Self {
+            consumed_ml: progress.consumed_ml,
+            goal_ml: progress.goal_ml,
+            remaining_ml: progress.remaining_ml,
+            percent: progress.percent,
+        }
+    }
+}
+
+/// Reminder loop status; `retry_attempt` is set while retrying a busy database.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SchedulerStatusView {
+    pub state: &'static str,
+    pub health: &'static str,
+    pub retry_attempt: Option<u32>,
+    pub next_fire_at: Option<DateTime<Utc>>,
+    pub last_fired_at: Option<DateTime<Utc>>,
+    pub fired_count: u64,
+    pub last_error: Option<SchedulerErrorView>,
+}
+
+impl From<&SchedulerStatus> for SchedulerStatusView {
+    fn from(status: &SchedulerStatus) -> Self {
+        Self {
+            state: status.state.as_str(),
+            health: status.health.as_str(),
+            retry_attempt: match status.health {
+                HealthState::Retrying { attempt } => Some(attempt),
+                HealthState::Healthy | HealthState::Degraded | HealthState::Failed => None,
+            },
+            next_fire_at: status.next_fire_at,
+            last_fired_at: status.last_fired_at,
+            fired_count: status.fired_count,
+            last_error: status.last_error.as_ref().map(SchedulerErrorView::from),
+        }
+    }
+}
+
+/// The last error the reminder loop handled.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SchedulerErrorView {
+    pub kind: &'static str,
+    pub message: String,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl From<&SchedulerError> for SchedulerErrorView {
+    fn from(error: &SchedulerError) -> Self {
+        Self {
+            kind: error.kind.as_str(),
+            message: error.message.clone(),
+            occurred_at: error.occurred_at,
+        }
+    }
+}
+
+/// A pause on reminders; `until` is `None` when it lasts until resumed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PauseView {
+    pub until: Option<DateTime<Utc>>,
+    pub indefinite: bool,
+}
+
+impl From<ReminderPause> for PauseView {
+    fn from(pause: ReminderPause) -> Self {
+        Self {
+            until: pause.ends_at(),
+            indefinite: pause == ReminderPause::Indefinite,
+        }
+    }
+}
+
+/// One logged drink.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct IntakeView {
+    pub id: i64,
+    pub amount_ml: u32,
+    pub consumed_at: DateTime<Utc>,
+    pub source: Option<&'static str>,
+}
+
+impl From<&WaterIntake> for IntakeView {
+    fn from(intake: &WaterIntake) -> Self {
+        Self {
+            id: intake.id,
+            amount_ml: intake.amount_ml,
+            consumed_at: intake.consumed_at,
+            source: intake.source.map(IntakeSource::as_str),
+        }
+    }
+}
+
+/// A logged reminder; `outcome` stays empty until the user answers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReminderLogView {
+    pub id: i64,
+    pub message: String,
+    pub triggered_at: DateTime<Utc>,
+    pub outcome: Option<&'static str>,
+    pub snooze_minutes: Option<u32>,
+    pub responded_at: Option<DateTime<Utc>>,
+}
+
+impl From<&ReminderLogEntry> for ReminderLogView {
+    fn from(entry: &ReminderLogEntry) -> Self {
+        Self {
+            id: entry.id,
+            message: entry.message.clone(),
+            triggered_at: entry.triggered_at,
+            outcome: entry.outcome.map(ReminderOutcome::as_str),
+            snooze_minutes: match entry.outcome {
+                Some(ReminderOutcome::Snoozed { minutes }) => Some(minutes),
+                Some(ReminderOutcome::Drank | ReminderOutcome::Dismissed) | None => None,
+            },
+            responded_at: entry.responded_at,
+        }
+    }
+}
+
+/// One page of history; pass `next_cursor` back to read the next page.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HistoryPageView<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+}
+
+impl<T> HistoryPageView<T> {
+    /// Converts every item of a page into its view.
+    pub fn new<'a, I>(page: &'a HistoryPage<I>) -> Self
+    where
+        T: From<&'a I>,
+    {
+        Self {
+            items: page.items.iter().map(T::from).collect(),
+            next_cursor: page.next_cursor.map(|cursor: HistoryCursor| cursor.token()),
+        }
+    }
+}
+
+/// Effective reminder settings; times are local `HH:MM`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReminderConfigView {
+    pub interval_minutes: u64,
+    pub cron: Option<String>,
+    pub active_window: Option<ActiveWindowView>,
+    pub quiet_hours: Option<QuietHoursView>,
+    pub catch_up: &'static str,
+    pub pause: Option<PauseView>,
+}
+
+impl From<ReminderConfig> for ReminderConfigView {
+    fn from(config: ReminderConfig) -> Self {
+        Self {
+            interval_minutes: config.interval_minutes,
+            cron: config.cron.as_ref().map(CronSchedule::to_string),
+            active_window: config.active_window.map(ActiveWindowView::from),
+            quiet_hours: config.quiet_hours.map(QuietHoursView::from),
+            catch_up: config.catch_up.as_str(),
+            pause: config.pause.map(PauseView::from),
+        }
+    }
+}
+
+/// Daily window in which reminders fire, with its weekdays (`"mon"` to `"sun"`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ActiveWindowView {
+    pub start: String,
+    pub end: String,
+    pub days: Vec<&'static str>,
+}
+
+impl From<ActiveWindow> for ActiveWindowView {
+    fn from(window: ActiveWindow) -> Self {
+        Self {
+            start: clock_time(window.start),
+            end: clock_time(window.end),
+            days: WEEKDAYS
+                .iter()
+                .filter(|(day, _name): &&(Weekday, &str)| window.days.contains(*day))
+                .map(|(_day, name): &(Weekday, &'static str)| *name)
+                .collect(),
+        }
+    }
+}
+
+/// Daily span during which reminders stay silent.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct QuietHoursView {
+    pub start: String,
+    pub end: String,
+}
+
+impl From<QuietHours> for QuietHoursView {
+    fn from(quiet_hours: QuietHours) -> Self {
+        Self {
+            start: clock_time(quiet_hours.start),
+            end: clock_time(quiet_hours.end),
+        }
+    }
+}
+
+/// Daily intake goal and the local hour a hydration day starts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct GoalView {
+    pub daily_goal_ml: u32,
+    pub day_start_hour: u32,
+}
+
+impl From<HydrationGoal> for GoalView {
+    fn from(goal: HydrationGoal) -> Self {
+        Self {
+            daily_goal_ml: goal.daily_goal_ml,
+            day_start_hour: goal.day_start_hour,
+        }
+    }
+}
+
+/// A journal prompt.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct JournalPromptView {
+    pub id: i64,
+    pub text: String,
+}
+
+impl From<JournalPrompt> for JournalPromptView {
+    fn from(prompt: JournalPrompt) -> Self {
+        Self {
+            id: prompt.id,
+            text: prompt.text,
+        }
+    }
+}
+
+/// A journal entry; `entry_date` is `YYYY-MM-DD`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct JournalEntryView {
+    pub id: i64,
+    pub entry_date: String,
+    pub prompt_id: Option<i64>,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<JournalEntry> for JournalEntryView {
+    fn from(entry: JournalEntry) -> Self {
+        Self {
+            id: entry.id,
+            entry_date: entry.entry_date.to_string(),
+            prompt_id: entry.prompt_id,
+            body: entry.body,
+            created_at: entry.created_at,
+            updated_at: entry.updated_at,
+        }
+    }
+}
+
+/// Statistics for a period; days are `YYYY-MM-DD` hydration days and percentages are `0-100`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StatsView {
+    pub period: &'static str,
+    pub first_day: String,
+    pub last_day: String,
+    pub goal_ml: u32,
+    pub total_ml: u64,
+    pub average_daily_ml: f64,
+    pub goal_hit_percent: f64,
+    pub current_streak_days: u32,
+    pub longest_streak_days: u32,
+    pub days: Vec<DailyIntakeView>,
+    /// Average intake per local hour of the day, starting at `00:00`.
+    pub average_ml_by_hour: Vec<f64>,
+    pub reminders: ReminderStatsView,
+}
+
+impl From<&HydrationStats> for StatsView {
+    fn from(stats: &HydrationStats) -> Self {
+        Self {
+            period: stats.period.as_str(),
+            first_day: stats.first_day.to_string(),
+            last_day: stats.last_day.to_string(),
+            goal_ml: stats.goal_ml,
+            total_ml: stats.total_ml,
+            average_daily_ml: stats.average_daily_ml,
+            goal_hit_percent: stats.goal_hit_percent,
+            current_streak_days: stats.streaks.current_days,
+            longest_streak_days: stats.streaks.longest_days,
+            days: stats
+                .days
+                .iter()
+                .map(|day: &DailyIntake| DailyIntakeView {
+                    day: day.day.to_string(),
+                    total_ml: day.total_ml,
+                    goal_met: day.goal_met(stats.goal_ml),
+                })
+                .collect(),
+            average_ml_by_hour: stats.average_ml_by_hour.to_vec(),
+            reminders: ReminderStatsView {
+                fired: stats.reminders.fired,
+                drank: stats.reminders.drank,
+                snoozed: stats.reminders.snoozed,
+                dismissed: stats.reminders.dismissed,
+                drink_response_percent: stats.reminders.drink_response_percent(),
+            },
+        }
+    }
+}
+
+/// Intake for one hydration day of a stats period.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DailyIntakeView {
+    pub day: String,
+    pub total_ml: u64,
+    pub goal_met: bool,
+}
+
+/// Reminders fired in a stats period and how they were answered.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReminderStatsView {
+    pub fired: u64,
+    pub drank: u64,
+    pub snoozed: u64,
+    pub dismissed: u64,
+    /// Share of fired reminders answered with a drink; `null` when none fired.
+    pub drink_response_percent: Option<f64>,
+}
+
+/// An achievement with progress towards its threshold; `unlocked_at` is `null` until unlocked.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AchievementView {
+    pub id: &'static str,
+    pub title: &'static str,
+    pub description: &'static str,
+    pub progress: u32,
+    pub threshold: u32,
+    pub unlocked_at: Option<DateTime<Utc>>,
+}
+
+impl From<&Achievement> for AchievementView {
+    fn from(achievement: &Achievement) -> Self {
+        Self {
+            id: achievement.rule.id,
+            title: achievement.rule.title,
+            description: achievement.rule.description,
+            progress: achievement.progress,
+            threshold: achievement.rule.threshold,
+            unlocked_at: achievement.unlocked_at,
+        }
+    }
+}
+
+/// Outcome of an import: the strategy used and counts for the settings and each table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ImportSummaryView {
+    pub strategy: &'static str,
+    pub settings: ImportCountsView,
+    pub reminder_logs: ImportCountsView,
+    pub water_intake: ImportCountsView,
+    pub journal_entries: ImportCountsView,
+}
+
+impl ImportSummaryView {
+    /// Builds the view of an import run with `strategy`.
+    pub fn new(strategy: ImportStrategy, summary: &ImportSummary) -> Self {
+        Self {
+            strategy: strategy.as_str(),
+            settings: summary.settings.into(),
+            reminder_logs: summary.reminder_logs.into(),
+            water_intake: summary.water_intake.into(),
+            journal_entries: summary.journal_entries.into(),
+        }
+    }
+}
+
+/// Records inserted, skipped as identical, and in conflict with a stored record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ImportCountsView {
+    pub inserted: u64,
+    pub skipped: u64,
+    pub conflicting: u64,
+}
+
+impl From<ImportCounts> for ImportCountsView {
+    fn from(counts: ImportCounts) -> Self {
+        Self {
+            inserted: counts.inserted,
+            skipped: counts.skipped,
+            conflicting: counts.conflicting,
+        }
+    }
+}
+
+/// Weekday names produced by the JSON adapters, Monday first.
+const WEEKDAYS: [(Weekday, &str); 7] = [
+    (Weekday::Mon, "mon"),
+    (Weekday::Tue, "tue"),
+    (Weekday::Wed, "wed"),
+    (Weekday::Thu, "thu"),
+    (Weekday::Fri, "fri"),
+    (Weekday::Sat, "sat"),
+    (Weekday::Sun, "sun"),
+];
+
+fn clock_time(time: NaiveTime) -> String {
+    time.format("%H:%M").to_string()
+}
+
+/// Parses a local `HH:MM` time as accepted by the inbound adapters.
+pub(crate) fn parse_clock_time(raw: &str) -> Option<NaiveTime> {
+    NaiveTime::parse_from_str(raw.trim(), "%H:%M").ok()
+}
+
+/// Parses a history range bound: an RFC 3339 instant, or a local `YYYY-MM-DD` date.
+///
+/// Dates mark the start of the day, or with `end_of_day` the start of the next day,
+/// so a date used as the exclusive end still includes that whole day.
+pub(crate) fn parse_history_bound(raw: &str, end_of_day: bool) -> Option<DateTime<Utc>> {
+    let raw: &str = raw.trim();
+    if let Ok(at) = DateTime::parse_from_rfc3339(raw) {
+        return Some(at.with_timezone(&Utc));
+    }
+
+    let date: NaiveDate = NaiveDate::parse_from_str(raw, "%Y-%m-%d").ok()?;
+    let date: NaiveDate = if end_of_day { date.checked_add_days(Days::new(1))? } else { date };
+    let start: DateTime<Local> = date.and_time(NaiveTime::MIN).and_local_timezone(Local).earliest()?;
+    Some(start.with_timezone(&Utc))
+}
+
+/// Parses a weekday name, abbreviated (`"mon"`) or full (`"Monday"`).
+pub(crate) fn parse_weekday(raw: &str) -> Option<Weekday> {
+    raw.trim().parse::<Weekday>().ok()
+}
@@ -0,0 +1,4 @@
+//! Outbound clock adapter modules.
+mod system_clock;
+
+pub use system_clock::SystemClock;
@@ -0,0 +1,12 @@
+//! Outbound clock that reads the operating system wall clock.
+use crate::ports::clock::Clock;
+use chrono::{DateTime, Utc};
+
+#[derive(Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
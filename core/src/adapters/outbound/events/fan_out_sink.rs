@@ -0,0 +1,287 @@
+//! Composite sink that broadcasts reminder events to several sinks concurrently.
+use crate::domain::achievements::Achievement;
+use crate::domain::hydration::ReminderEvent;
+use crate::error::{CoreError, CoreResult};
+use crate::ports::reminder_event_sink::ReminderEventSink;
+use async_trait::async_trait;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinSet;
+
+/// Time a single sink gets to accept an event before it counts as failed.
+pub const DEFAULT_SINK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// One named downstream sink and its delivery timeout.
+#[derive(Clone)]
+struct SinkRoute {
+    name: String,
+    sink: Arc<dyn ReminderEventSink>,
+    timeout: Duration,
+}
+
+/// Sink that emits every event to all routes and isolates failures per route.
+#[derive(Clone, Default)]
+pub struct FanOutReminderSink {
+    routes: Vec<SinkRoute>,
+}
+
+impl FanOutReminderSink {
+    /// Creates a fan-out sink with no downstream sinks.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a named downstream sink that must accept each event within `timeout`.
+    pub fn with_sink(
+        mut self,
+        name: impl Into<String>,
+        sink: Arc<dyn ReminderEventSink>,
+        timeout: Duration,
+    ) -> Self {
+        self.routes.push(SinkRoute {
+            name: name.into(),
+            sink,
+            timeout,
+        });
+        self
+    }
+
+    /// Returns the downstream sink names in registration order.
+    pub fn sink_names(&self) -> Vec<&str> {
+        self.routes
+            .iter()
+            .map(|route: &SinkRoute| route.name.as_str())
+            .collect()
+    }
+
+    /// Runs `call` on every route at once, each under its timeout, and returns the
+    /// names of the routes that failed with the reason.
+    async fn on_every_route<F>(&self, call: F) -> Vec<(String, String)>
+    where
+        F: Fn(Arc<dyn ReminderEventSink>) -> SinkCall,
+    {
+        let mut calls: JoinSet<(String, Result<(), String>)> = JoinSet::new();
+        for route in self.routes.iter().cloned() {
+            let pending: SinkCall = call(route.sink.clone());
+            calls.spawn(async move {
+                let outcome: Result<(), String> = match tokio::time::timeout(route.timeout, pending).await {
+                    Ok(Ok(())) => Ok(()),
+                    Ok(Err(err)) => Err(err.to_string()),
+                    Err(_) => Err(format!("timed out after {:?}", route.timeout)),
+                };
+                (route.name, outcome)
+            });
+        }
+
+        let mut failures: Vec<(String, String)> = Vec::new();
+        while let Some(joined) = calls.join_next().await {
+            let (name, outcome): (String, Result<(), String>) = match joined {
+                Ok(call) => call,
+                Err(err) => (String::from("<panicked>"), Err(err.to_string())),
+            };
+            if let Err(reason) = outcome {
+                failures.push((name, reason));
+            }
+        }
+        failures
+    }
+}
+
+/// One sink call, boxed so it can run on its own task.
+type SinkCall = Pin<Box<dyn Future<Output = CoreResult<()>> + Send>>;
+
+#[async_trait]
+impl ReminderEventSink for FanOutReminderSink {
+    /// Delivers to every sink at once; fails only when no sink accepted the event.
+    async fn emit_reminder(&self, event: &ReminderEvent) -> CoreResult<()> {
+        if self.routes.is_empty() {
+            return Ok(());
+        }
+
+        let event: Arc<ReminderEvent> = Arc::new(event.clone());
+        let failures: Vec<(String, String)> = self
+            .on_every_route(|sink: Arc<dyn ReminderEventSink>| {
+                let event: Arc<ReminderEvent> = event.clone();
+                Box::pin(async move { sink.emit_reminder(&event).await })
+            })
+            .await;
+        for (name, reason) in &failures {
+            tracing::warn!(sink = %name, reminder_id = event.id, "reminder sink failed: {reason}");
+        }
+
+        if failures.len() == self.routes.len() {
+            return Err(CoreError::ReminderDeliveryFailed { failed: failures.len() });
+        }
+        Ok(())
+    }
+
+    /// Delivers to every sink at once; fails only when no sink accepted the achievement.
+    async fn emit_achievement(&self, achievement: &Achievement) -> CoreResult<()> {
+        if self.routes.is_empty() {
+            return Ok(());
+        }
+
+        let achievement: Achievement = *achievement;
+        let failures: Vec<(String, String)> = self
+            .on_every_route(|sink: Arc<dyn ReminderEventSink>| {
+                Box::pin(async move { sink.emit_achievement(&achievement).await })
+            })
+            .await;
+        for (name, reason) in &failures {
+            tracing::warn!(sink = %name, achievement = achievement.rule.id, "achievement sink failed: {reason}");
+        }
+
+        if failures.len() == self.routes.len() {
+            return Err(CoreError::ReminderDeliveryFailed { failed: failures.len() });
+        }
+        Ok(())
+    }
+
+    /// Flushes every sink at once; a sink that fails or times out is logged and skipped.
+    async fn flush(&self) -> CoreResult<()> {
+        let failures: Vec<(String, String)> = self
+            .on_every_route(|sink: Arc<dyn ReminderEventSink>| Box::pin(async move { sink.flush().await }))
+            .await;
+        for (name, reason) in &failures {
+            tracing::warn!(sink = %name, "reminder sink failed to flush: {reason}");
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::hydration::{
+        HydrationProgress, HydrationReminder, ReminderKind, DEFAULT_REMINDER_MESSAGE,
+    };
+    use chrono::Utc;
+    use std::sync::Mutex;
+    use tokio::time::Instant;
+
+    /// Sink that optionally waits, then records the event ID or fails.
+    struct ScriptedSink {
+        delay: Duration,
+        fail: bool,
+        received: Mutex<Vec<i64>>,
+        flushes: Mutex<u32>,
+    }
+
+    impl ScriptedSink {
+        fn new(delay: Duration, fail: bool) -> Arc<Self> {
+            Arc::new(Self {
+                delay,
+                fail,
+                received: Mutex::new(Vec::new()),
+                flushes: Mutex::new(0),
+            })
+        }
+
+        fn received(&self) -> Vec<i64> {
+            self.received.lock().expect("sink lock").clone()
+        }
+
+        fn flushes(&self) -> u32 {
+            *self.flushes.lock().expect("sink lock")
+        }
+    }
+
+    #[async_trait]
+    impl ReminderEventSink for ScriptedSink {
+        async fn emit_reminder(&self, event: &ReminderEvent) -> CoreResult<()> {
+            tokio::time::sleep(self.delay).await;
+            if self.fail {
+                return Err(CoreError::ReminderNotFound(event.id));
+            }
+            self.received.lock().expect("sink lock").push(event.id);
+            Ok(())
+        }
+
+        async fn flush(&self) -> CoreResult<()> {
+            tokio::time::sleep(self.delay).await;
+            *self.flushes.lock().expect("sink lock") += 1;
+            Ok(())
+        }
+    }
+
+    fn event(id: i64) -> ReminderEvent {
+        let reminder: HydrationReminder = HydrationReminder {
+            id,
+            message: DEFAULT_REMINDER_MESSAGE.to_string(),
+            triggered_at: Utc::now(),
+        };
+        ReminderEvent::new(reminder, ReminderKind::Manual, HydrationProgress::new(0, 2_000))
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn delivers_concurrently_and_isolates_failures() -> CoreResult<()> {
+        let slow: Arc<ScriptedSink> = ScriptedSink::new(Duration::from_secs(3), false);
+        let also_slow: Arc<ScriptedSink> = ScriptedSink::new(Duration::from_secs(3), false);
+        let broken: Arc<ScriptedSink> = ScriptedSink::new(Duration::ZERO, true);
+        let sink: FanOutReminderSink = FanOutReminderSink::new()
+            .with_sink("slow", slow.clone(), DEFAULT_SINK_TIMEOUT)
+            .with_sink("broken", broken, DEFAULT_SINK_TIMEOUT)
+            .with_sink("also-slow", also_slow.clone(), DEFAULT_SINK_TIMEOUT);
+        let started: Instant = Instant::now();
+
+        sink.emit_reminder(&event(1)).await?;
+
+        assert_eq!(started.elapsed(), Duration::from_secs(3));
+        assert_eq!(slow.received(), vec![1]);
+        assert_eq!(also_slow.received(), vec![1]);
+        assert_eq!(sink.sink_names(), vec!["slow", "broken", "also-slow"]);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn slow_sinks_are_cut_off_at_their_timeout() -> CoreResult<()> {
+        let hung: Arc<ScriptedSink> = ScriptedSink::new(Duration::from_secs(3_600), false);
+        let fast: Arc<ScriptedSink> = ScriptedSink::new(Duration::ZERO, false);
+        let sink: FanOutReminderSink = FanOutReminderSink::new()
+            .with_sink("hung", hung.clone(), Duration::from_secs(2))
+            .with_sink("fast", fast.clone(), Duration::from_secs(2));
+        let started: Instant = Instant::now();
+
+        sink.emit_reminder(&event(7)).await?;
+
+        assert_eq!(started.elapsed(), Duration::from_secs(2));
+        assert!(hung.received().is_empty());
+        assert_eq!(fast.received(), vec![7]);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn fails_only_when_every_sink_fails() {
+        let sink: FanOutReminderSink = FanOutReminderSink::new()
+            .with_sink("broken", ScriptedSink::new(Duration::ZERO, true), DEFAULT_SINK_TIMEOUT)
+            .with_sink(
+                "hung",
+                ScriptedSink::new(Duration::from_secs(60), false),
+                Duration::from_secs(1),
+            );
+
+        assert!(matches!(
+            sink.emit_reminder(&event(3)).await,
+            Err(CoreError::ReminderDeliveryFailed { failed: 2 })
+        ));
+        assert!(FanOutReminderSink::new().emit_reminder(&event(4)).await.is_ok());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn flush_reaches_every_sink_and_skips_hung_ones() -> CoreResult<()> {
+        let slow: Arc<ScriptedSink> = ScriptedSink::new(Duration::from_secs(1), false);
+        let hung: Arc<ScriptedSink> = ScriptedSink::new(Duration::from_secs(3_600), false);
+        let sink: FanOutReminderSink = FanOutReminderSink::new()
+            .with_sink("slow", slow.clone(), DEFAULT_SINK_TIMEOUT)
+            .with_sink("hung", hung.clone(), Duration::from_secs(2));
+        let started: Instant = Instant::now();
+
+        sink.flush().await?;
+
+        assert_eq!(started.elapsed(), Duration::from_secs(2));
+        assert_eq!((slow.flushes(), hung.flushes()), (1, 0));
+        Ok(())
+    }
+}
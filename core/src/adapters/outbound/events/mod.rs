@@ -1,4 +1,6 @@
 //! Outbound event sink adapter modules.
+mod fan_out_sink;
 mod stdout_sink;
 
+pub use fan_out_sink::{FanOutReminderSink, DEFAULT_SINK_TIMEOUT};
 pub use stdout_sink::StdoutReminderSink;
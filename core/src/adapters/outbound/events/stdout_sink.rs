@@ -1,15 +1,84 @@
 //! Outbound sink that writes reminder events to standard output.
+use crate::domain::achievements::Achievement;
+use crate::domain::hydration::{ReminderAction, ReminderEvent, ReminderKind};
 use crate::error::CoreResult;
 use crate::ports::reminder_event_sink::ReminderEventSink;
 use async_trait::async_trait;
+use chrono::{Local, TimeZone};
+use std::io::Write;
 
 #[derive(Default)]
 pub struct StdoutReminderSink;
 
 #[async_trait]
 impl ReminderEventSink for StdoutReminderSink {
-    async fn emit_hydration_reminder(&self, message: &str) -> CoreResult<()> {
-        println!("{message}");
+    async fn emit_reminder(&self, event: &ReminderEvent) -> CoreResult<()> {
+        println!("{}", render(event, &Local));
         Ok(())
     }
+
+    async fn emit_achievement(&self, achievement: &Achievement) -> CoreResult<()> {
+        println!("Achievement unlocked: {} ({})", achievement.rule.title, achievement.rule.description);
+        Ok(())
+    }
+
+    async fn flush(&self) -> CoreResult<()> {
+        std::io::stdout().flush()?;
+        Ok(())
+    }
+}
+
+/// Formats an event as a headline, a progress line and the available replies.
+fn render<Tz: TimeZone>(event: &ReminderEvent, timezone: &Tz) -> String
+where
+    Tz::Offset: std::fmt::Display,
+{
+    let fired_at: String = event.fired_at.with_timezone(timezone).format("%H:%M").to_string();
+    let kind: &str = match event.kind {
+        ReminderKind::Scheduled => "",
+        ReminderKind::Snoozed => " [snoozed]",
+        ReminderKind::CatchUp => " [missed while away]",
+        ReminderKind::Manual => " [manual]",
+    };
+    let actions: Vec<&str> = event
+        .actions
+        .iter()
+        .map(|action: &ReminderAction| action.as_str())
+        .collect();
+
+    format!(
+        "[{fired_at}] {} (reminder #{}){kind}\n  Today: {} / {} ml ({:.0}%)\n  Reply: {}",
+        event.message,
+        event.id,
+        event.progress.consumed_ml,
+        event.progress.goal_ml,
+        event.progress.percent,
+        actions.join(", ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::hydration::{HydrationProgress, HydrationReminder, DEFAULT_REMINDER_MESSAGE};
+    use chrono::{DateTime, NaiveDateTime, Utc};
+
+    #[test]
+    fn renders_message_progress_and_actions() {
+        let fired_at: DateTime<Utc> = NaiveDateTime::parse_from_str("2026-01-05 10:30", "%Y-%m-%d %H:%M")
+            .expect("valid time")
+            .and_utc();
+        let reminder: HydrationReminder = HydrationReminder {
+            id: 7,
+            message: DEFAULT_REMINDER_MESSAGE.to_string(),
+            triggered_at: fired_at,
+        };
+        let event: ReminderEvent =
+            ReminderEvent::new(reminder, ReminderKind::Snoozed, HydrationProgress::new(750, 2_000));
+
+        assert_eq!(
+            render(&event, &Utc),
+            "[10:30] Reminder: drink water! (reminder #7) [snoozed]\n  Today: 750 / 2000 ml (38%)\n  Reply: drink, snooze, dismiss"
+        );
+    }
 }
@@ -0,0 +1,146 @@
+//! Export writer producing one CSV file per table.
+use super::records::{JournalEntryRecord, ReminderLogRecord, WaterIntakeRecord};
+use crate::domain::export::{ExportSettings, ExportTable};
+use crate::domain::history::ReminderLogEntry;
+use crate::domain::hydration::WaterIntake;
+use crate::domain::journal::JournalEntry;
+use crate::error::{CoreError, CoreResult};
+use crate::ports::export_writer::ExportWriter;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+/// Writes each table to `<directory>/<table>.csv` with a header row.
+///
+/// Settings have no rows of their own, so only the JSON document carries them.
+pub struct CsvExportWriter {
+    directory: PathBuf,
+    table: Option<csv::Writer<File>>,
+    files: Vec<PathBuf>,
+}
+
+impl CsvExportWriter {
+    /// Creates a writer that replaces table files in `directory`, creating it if needed.
+    pub fn new(directory: impl Into<PathBuf>) -> CoreResult<Self> {
+        let directory: PathBuf = directory.into();
+        std::fs::create_dir_all(&directory)?;
+        Ok(Self {
+            directory,
+            table: None,
+            files: Vec::new(),
+        })
+    }
+
+    /// Returns the files written so far, in table order.
+    pub fn files(&self) -> &[PathBuf] {
+        &self.files
+    }
+
+    fn row<T: Serialize>(&mut self, record: &T) -> CoreResult<()> {
+        let Some(table) = self.table.as_mut() else {
+            return Ok(());
+        };
+        table.serialize(record).map_err(csv_error)
+    }
+
+    fn close_table(&mut self) -> CoreResult<()> {
+        if let Some(mut table) = self.table.take() {
+            table.flush()?;
+        }
+        Ok(())
+    }
+}
+
+impl ExportWriter for CsvExportWriter {
+    fn begin(&mut self, _exported_at: DateTime<Utc>, _settings: &ExportSettings) -> CoreResult<()> {
+        Ok(())
+    }
+
+    fn begin_table(&mut self, table: ExportTable) -> CoreResult<()> {
+        self.close_table()?;
+        let path: PathBuf = csv_table_path(&self.directory, table);
+        let mut writer: csv::Writer<File> = csv::WriterBuilder::new()
+            .has_headers(false)
+            .from_path(&path)
+            .map_err(csv_error)?;
+        // Written up front so empty tables still name their columns.
+        writer.write_record(headers(table)).map_err(csv_error)?;
+        self.table = Some(writer);
+        self.files.push(path);
+        Ok(())
+    }
+
+    fn reminder_log(&mut self, entry: &ReminderLogEntry) -> CoreResult<()> {
+        self.row(&ReminderLogRecord::from(entry))
+    }
+
+    fn water_intake(&mut self, intake: &WaterIntake) -> CoreResult<()> {
+        self.row(&WaterIntakeRecord::from(intake))
+    }
+
+    fn journal_entry(&mut self, entry: &JournalEntry) -> CoreResult<()> {
+        self.row(&JournalEntryRecord::from(entry))
+    }
+
+    fn finish(&mut self) -> CoreResult<()> {
+        self.close_table()
+    }
+}
+
+/// Column names, matching the field order of each table's record.
+fn headers(table: ExportTable) -> &'static [&'static str] {
+    match table {
+        ExportTable::ReminderLogs => &["id", "message", "triggered_at", "outcome", "snooze_minutes", "responded_at"],
+        ExportTable::WaterIntake => &["id", "amount_ml", "consumed_at", "source"],
+        ExportTable::JournalEntries => &["id", "entry_date", "prompt_id", "body", "created_at", "updated_at"],
+    }
+}
+
+fn csv_error(err: csv::Error) -> CoreError {
+    CoreError::Io(err.into())
+}
+
+/// Returns the file a table is written to inside `directory`.
+fn csv_table_path(directory: &Path, table: ExportTable) -> PathBuf {
+    directory.join(format!("{}.csv", table.as_str()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::history::ReminderOutcome;
+
+    #[test]
+    fn writes_one_file_per_table_with_headers() {
+        let dir: tempfile::TempDir = tempfile::tempdir().expect("temp dir");
+        let mut writer: CsvExportWriter = CsvExportWriter::new(dir.path().join("export")).expect("writer");
+
+        writer.begin_table(ExportTable::ReminderLogs).expect("table");
+        writer
+            .reminder_log(&ReminderLogEntry {
+                id: 3,
+                message: "Time to drink, \"really\"".to_string(),
+                triggered_at: DateTime::UNIX_EPOCH,
+                outcome: Some(ReminderOutcome::Snoozed { minutes: 10 }),
+                responded_at: None,
+            })
+            .expect("row");
+        writer.begin_table(ExportTable::WaterIntake).expect("table");
+        writer.finish().expect("finish");
+
+        let reminders: String =
+            std::fs::read_to_string(csv_table_path(&dir.path().join("export"), ExportTable::ReminderLogs))
+                .expect("reminder file");
+        assert_eq!(
+            reminders,
+            "id,message,triggered_at,outcome,snooze_minutes,responded_at\n\
+             3,\"Time to drink, \"\"really\"\"\",1970-01-01T00:00:00Z,snoozed,10,\n"
+        );
+        let intake: String =
+            std::fs::read_to_string(csv_table_path(&dir.path().join("export"), ExportTable::WaterIntake))
+                .expect("intake file");
+        assert_eq!(intake, "id,amount_ml,consumed_at,source\n");
+        assert_eq!(writer.files().len(), 2);
+    }
+}
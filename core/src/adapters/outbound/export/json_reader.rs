@@ -0,0 +1,217 @@
+//! Reads a versioned JSON export back for importing.
+use super::records::{JournalEntryRecord, ReminderLogRecord, SettingsRecord, WaterIntakeRecord};
+use crate::domain::export::{
+    ExportSettings, ExportTable, ImportDocument, ImportRecords, EXPORT_FORMAT, EXPORT_VERSION,
+};
+use crate::domain::history::ReminderLogEntry;
+use crate::domain::hydration::WaterIntake;
+use crate::domain::journal::JournalEntry;
+use crate::error::{CoreError, CoreResult};
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value;
+use std::io::Read;
+
+/// Top level of an export; records stay raw so errors can name the one that failed.
+#[derive(Debug, Deserialize)]
+struct DocumentRecord {
+    version: u32,
+    exported_at: DateTime<Utc>,
+    settings: Value,
+    #[serde(default)]
+    reminder_logs: Vec<Value>,
+    #[serde(default)]
+    water_intake: Vec<Value>,
+    #[serde(default)]
+    journal_entries: Vec<Value>,
+}
+
+/// Parses and validates a document written by `JsonExportWriter`.
+///
+/// The format name and version are checked before anything else, so a newer export
+/// fails with `CoreError::UnsupportedExport` rather than a field error. Tables left
+/// out of the export read as empty. The whole document is held in memory.
+pub fn read_json_export(reader: impl Read) -> CoreResult<ImportDocument> {
+    let value: Value = serde_json::from_reader(reader).map_err(|err: serde_json::Error| invalid("document", err))?;
+    let format: &str = value.get("format").and_then(Value::as_str).unwrap_or_default();
+    let version: u64 = value.get("version").and_then(Value::as_u64).unwrap_or(0);
+    if format != EXPORT_FORMAT || !(1..=u64::from(EXPORT_VERSION)).contains(&version) {
+        return Err(CoreError::UnsupportedExport {
+            format: format.to_string(),
+            version,
+        });
+    }
+
+    let document: DocumentRecord =
+        serde_json::from_value(value).map_err(|err: serde_json::Error| invalid("document", err))?;
+    let settings: ExportSettings = convert::<SettingsRecord, ExportSettings>("settings".to_string(), document.settings)?;
+
+    Ok(ImportDocument {
+        version: document.version,
+        exported_at: document.exported_at,
+        settings,
+        records: ImportRecords {
+            reminder_logs: convert_table::<ReminderLogRecord, ReminderLogEntry>(
+                ExportTable::ReminderLogs,
+                document.reminder_logs,
+            )?,
+            water_intake: convert_table::<WaterIntakeRecord, WaterIntake>(ExportTable::WaterIntake, document.water_intake)?,
+            journal_entries: convert_table::<JournalEntryRecord, JournalEntry>(
+                ExportTable::JournalEntries,
+                document.journal_entries,
+            )?,
+        },
+    })
+}
+
+fn convert_table<R, T>(table: ExportTable, values: Vec<Value>) -> CoreResult<Vec<T>>
+where
+    R: DeserializeOwned,
+    T: TryFrom<R, Error = String>,
+{
+    values
+        .into_iter()
+        .enumerate()
+        .map(|(index, value): (usize, Value)| convert::<R, T>(format!("{}[{index}]", table.as_str()), value))
+        .collect()
+}
+
+fn convert<R, T>(record: String, value: Value) -> CoreResult<T>
+where
+    R: DeserializeOwned,
+    T: TryFrom<R, Error = String>,
+{
+    let parsed: R = match serde_json::from_value::<R>(value) {
+        Ok(parsed) => parsed,
+        Err(err) => return Err(invalid(&record, err)),
+    };
+    T::try_from(parsed).map_err(|reason: String| CoreError::InvalidImport { record, reason })
+}
+
+fn invalid(record: &str, reason: impl ToString) -> CoreError {
+    CoreError::InvalidImport {
+        record: record.to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::outbound::export::JsonExportWriter;
+    use crate::domain::hydration::{ActiveWindow, HydrationGoal, IntakeSource, QuietHours, ReminderConfig, WeekdaySet};
+    use crate::domain::history::ReminderOutcome;
+    use crate::domain::journal::EntryDate;
+    use crate::ports::export_writer::ExportWriter;
+    use chrono::{NaiveDate, NaiveTime};
+
+    fn time(hour: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, 0, 0).expect("valid time")
+    }
+
+    #[test]
+    fn exports_read_back_into_the_same_records() {
+        let settings: ExportSettings = ExportSettings {
+            reminder_config: ReminderConfig {
+                cron: "*/30 9-17 * * mon-fri".parse().ok(),
+                active_window: ActiveWindow::new(time(9), time(17), WeekdaySet::WEEKDAYS),
+                quiet_hours: QuietHours::new(time(22), time(7)),
+                ..ReminderConfig::new(45)
+            },
+            goal: HydrationGoal::new(2_500, 4).expect("valid goal"),
+        };
+        let reminder: ReminderLogEntry = ReminderLogEntry {
+            id: 1,
+            message: "Drink".to_string(),
+            triggered_at: DateTime::from_timestamp_nanos(1_767_225_600_123_456_789),
+            outcome: Some(ReminderOutcome::Snoozed { minutes: 10 }),
+            responded_at: Some(DateTime::from_timestamp_nanos(1_767_225_660_000_000_000)),
+        };
+        let intake: WaterIntake = WaterIntake {
+            id: 2,
+            amount_ml: 330,
+            consumed_at: DateTime::from_timestamp_nanos(1_767_225_700_000_001_000),
+            source: Some(IntakeSource::Bottle),
+        };
+        let entry: JournalEntry = JournalEntry {
+            id: 3,
+            entry_date: EntryDate::new(NaiveDate::from_ymd_opt(2026, 1, 1).expect("valid date")),
+            prompt_id: Some(4),
+            body: "Fresh start".to_string(),
+            created_at: DateTime::UNIX_EPOCH,
+            updated_at: DateTime::UNIX_EPOCH,
+        };
+
+        let mut writer: JsonExportWriter<Vec<u8>> = JsonExportWriter::new(Vec::new());
+        writer.begin(DateTime::UNIX_EPOCH, &settings).expect("begin");
+        writer.begin_table(ExportTable::ReminderLogs).expect("table");
+        writer.reminder_log(&reminder).expect("reminder");
+        writer.begin_table(ExportTable::WaterIntake).expect("table");
+        writer.water_intake(&intake).expect("intake");
+        writer.begin_table(ExportTable::JournalEntries).expect("table");
+        writer.journal_entry(&entry).expect("entry");
+        writer.finish().expect("finish");
+
+        let document: ImportDocument = read_json_export(writer.into_inner().as_slice()).expect("valid export");
+        assert_eq!(document.version, EXPORT_VERSION);
+        assert_eq!(document.settings, settings);
+        assert_eq!(
+            document.records,
+            ImportRecords {
+                reminder_logs: vec![reminder],
+                water_intake: vec![intake],
+                journal_entries: vec![entry],
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_other_formats_newer_versions_and_bad_records() {
+        let read = |json: &str| read_json_export(json.as_bytes());
+        let settings: &str = r#"{"interval_minutes":30,"cron":null,"active_window":null,"quiet_hours":null,
+            "catch_up":"skip","daily_goal_ml":2000,"day_start_hour":0}"#;
+
+        assert!(matches!(
+            read(r#"{"format":"desk-buddy-export","version":2}"#),
+            Err(CoreError::UnsupportedExport { version: 2, .. })
+        ));
+        assert!(matches!(
+            read(r#"{"reminder_config":{}}"#),
+            Err(CoreError::UnsupportedExport { version: 0, .. })
+        ));
+        assert!(matches!(read("{"), Err(CoreError::InvalidImport { .. })));
+
+        let bad_amount: String = format!(
+            r#"{{"format":"desk-buddy-export","version":1,"exported_at":"2026-01-01T00:00:00Z","settings":{settings},
+                "water_intake":[{{"id":1,"amount_ml":250,"consumed_at":"2026-01-01T08:00:00Z","source":null}},
+                                {{"id":2,"amount_ml":0,"consumed_at":"2026-01-01T09:00:00Z","source":null}}]}}"#
+        );
+        match read(&bad_amount) {
+            Err(CoreError::InvalidImport { record, reason }) => {
+                assert_eq!(record, "water_intake[1]");
+                assert!(reason.starts_with("amount_ml"), "{reason}");
+            }
+            other => panic!("expected an invalid record, got {other:?}"),
+        }
+
+        let huge_interval: String = format!(
+            r#"{{"format":"desk-buddy-export","version":1,"exported_at":"2026-01-01T00:00:00Z","settings":{}}}"#,
+            settings.replace(r#""interval_minutes":30"#, r#""interval_minutes":1000000000000"#)
+        );
+        match read(&huge_interval) {
+            Err(CoreError::InvalidImport { record, reason }) => {
+                assert_eq!(record, "settings");
+                assert_eq!(reason, "interval_minutes: 1000000000000 is not 1-10080");
+            }
+            other => panic!("expected invalid settings, got {other:?}"),
+        }
+
+        let only_settings: String = format!(
+            r#"{{"format":"desk-buddy-export","version":1,"exported_at":"2026-01-01T00:00:00Z","settings":{settings}}}"#
+        );
+        let document: ImportDocument = read(&only_settings).expect("tables are optional");
+        assert_eq!(document.records, ImportRecords::default());
+        assert_eq!(document.settings.reminder_config.interval_minutes, 30);
+    }
+}
@@ -0,0 +1,145 @@
+//! Export writer producing one versioned JSON document.
+use super::records::{JournalEntryRecord, ReminderLogRecord, SettingsRecord, WaterIntakeRecord};
+use crate::domain::export::{ExportSettings, ExportTable, EXPORT_FORMAT, EXPORT_VERSION};
+use crate::domain::history::ReminderLogEntry;
+use crate::domain::hydration::WaterIntake;
+use crate::domain::journal::JournalEntry;
+use crate::error::{CoreError, CoreResult};
+use crate::ports::export_writer::ExportWriter;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::io::Write;
+
+/// Writes `{"format", "version", "exported_at", "settings", <table>: [...]}`, one
+/// record per line, as records arrive.
+pub struct JsonExportWriter<W> {
+    out: W,
+    /// Records written to the open table, or `None` before the first table.
+    table_rows: Option<u64>,
+}
+
+impl<W: Write + Send> JsonExportWriter<W> {
+    /// Creates a writer over `out`; wrap files in a `BufWriter`.
+    pub fn new(out: W) -> Self {
+        Self { out, table_rows: None }
+    }
+
+    /// Returns the underlying output.
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+
+    fn value<T: Serialize + ?Sized>(&mut self, value: &T) -> CoreResult<()> {
+        serde_json::to_writer(&mut self.out, value).map_err(|err: serde_json::Error| CoreError::Io(err.into()))
+    }
+
+    fn record<T: Serialize>(&mut self, record: &T) -> CoreResult<()> {
+        let rows: &mut u64 = self.table_rows.get_or_insert(0);
+        let separator: &[u8] = if *rows == 0 { b"\n" } else { b",\n" };
+        *rows += 1;
+        self.out.write_all(separator)?;
+        self.value(record)
+    }
+
+    fn close_table(&mut self) -> CoreResult<()> {
+        match self.table_rows.take() {
+            Some(0) => self.out.write_all(b"]")?,
+            Some(_) => self.out.write_all(b"\n]")?,
+            None => {}
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write + Send> ExportWriter for JsonExportWriter<W> {
+    fn begin(&mut self, exported_at: DateTime<Utc>, settings: &ExportSettings) -> CoreResult<()> {
+        self.out.write_all(b"{\"format\":")?;
+        self.value(EXPORT_FORMAT)?;
+        write!(self.out, ",\"version\":{EXPORT_VERSION},\"exported_at\":")?;
+        self.value(&exported_at)?;
+        self.out.write_all(b",\n\"settings\":")?;
+        self.value(&SettingsRecord::from(settings))
+    }
+
+    fn begin_table(&mut self, table: ExportTable) -> CoreResult<()> {
+        self.close_table()?;
+        write!(self.out, ",\n\"{}\":[", table.as_str())?;
+        self.table_rows = Some(0);
+        Ok(())
+    }
+
+    fn reminder_log(&mut self, entry: &ReminderLogEntry) -> CoreResult<()> {
+        self.record(&ReminderLogRecord::from(entry))
+    }
+
+    fn water_intake(&mut self, intake: &WaterIntake) -> CoreResult<()> {
+        self.record(&WaterIntakeRecord::from(intake))
+    }
+
+    fn journal_entry(&mut self, entry: &JournalEntry) -> CoreResult<()> {
+        self.record(&JournalEntryRecord::from(entry))
+    }
+
+    fn finish(&mut self) -> CoreResult<()> {
+        self.close_table()?;
+        self.out.write_all(b"\n}\n")?;
+        self.out.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::hydration::{HydrationGoal, IntakeSource, ReminderConfig};
+    use crate::domain::journal::EntryDate;
+    use chrono::NaiveDate;
+    use serde_json::Value;
+
+    #[test]
+    fn writes_a_versioned_document_table_by_table() {
+        let settings: ExportSettings = ExportSettings {
+            reminder_config: ReminderConfig::default(),
+            goal: HydrationGoal::default(),
+        };
+        let mut writer: JsonExportWriter<Vec<u8>> = JsonExportWriter::new(Vec::new());
+
+        writer.begin(DateTime::UNIX_EPOCH, &settings).expect("begin");
+        writer.begin_table(ExportTable::ReminderLogs).expect("table");
+        writer.begin_table(ExportTable::WaterIntake).expect("table");
+        for id in 1..=2 {
+            writer
+                .water_intake(&WaterIntake {
+                    id,
+                    amount_ml: 250,
+                    consumed_at: DateTime::from_timestamp_nanos(1_767_225_600_123_456_789),
+                    source: Some(IntakeSource::Cup),
+                })
+                .expect("intake");
+        }
+        writer.begin_table(ExportTable::JournalEntries).expect("table");
+        writer
+            .journal_entry(&JournalEntry {
+                id: 7,
+                entry_date: EntryDate::new(NaiveDate::from_ymd_opt(2026, 1, 1).expect("valid date")),
+                prompt_id: None,
+                body: "New \"year\"".to_string(),
+                created_at: DateTime::UNIX_EPOCH,
+                updated_at: DateTime::UNIX_EPOCH,
+            })
+            .expect("entry");
+        writer.finish().expect("finish");
+
+        let document: Value = serde_json::from_slice(&writer.into_inner()).expect("valid JSON");
+        assert_eq!(document["format"], EXPORT_FORMAT);
+        assert_eq!(document["version"], EXPORT_VERSION);
+        assert_eq!(document["exported_at"], "1970-01-01T00:00:00Z");
+        assert_eq!(document["settings"]["catch_up"], "fire_once");
+        assert_eq!(document["water_intake"][1]["id"], 2);
+        assert_eq!(document["water_intake"][1]["consumed_at"], "2026-01-01T00:00:00.123456789Z");
+        assert_eq!(document["water_intake"][1]["source"], "cup");
+        assert_eq!(document["reminder_logs"], serde_json::json!([]));
+        assert_eq!(document["journal_entries"][0]["entry_date"], "2026-01-01");
+        assert_eq!(document["journal_entries"][0]["body"], "New \"year\"");
+    }
+}
@@ -0,0 +1,10 @@
+//! Outbound export writers for the versioned JSON document and per-table CSV files,
+//! and the reader that turns a JSON export back into records for importing.
+mod csv_writer;
+mod json_reader;
+mod json_writer;
+mod records;
+
+pub use csv_writer::CsvExportWriter;
+pub use json_reader::read_json_export;
+pub use json_writer::JsonExportWriter;
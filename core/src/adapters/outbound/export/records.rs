@@ -0,0 +1,290 @@
+//! Serialized shapes of export records, shared by every export format, and their
+//! validated conversions back into domain records for imports.
+//!
+//! These are part of the versioned export format rather than the API views, so a
+//! field only changes together with `EXPORT_VERSION`.
+use crate::domain::export::ExportSettings;
+use crate::domain::history::{ReminderLogEntry, ReminderOutcome};
+use crate::domain::hydration::{
+    is_valid_intake_amount, ActiveWindow, CatchUpPolicy, CronError, CronSchedule, HydrationGoal, IntakeSource,
+    QuietHours, ReminderConfig, WaterIntake, WeekdaySet, MAX_INTAKE_ML, MAX_INTERVAL_MINUTES,
+};
+use crate::domain::journal::{normalize_entry_body, EntryDate, JournalEntry};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+
+/// Weekdays in export order, Monday first.
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+/// Reminder settings and goal; times are local `HH:MM`. Pauses are transient and left out.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SettingsRecord {
+    pub interval_minutes: u64,
+    pub cron: Option<String>,
+    pub active_window: Option<ActiveWindowRecord>,
+    pub quiet_hours: Option<QuietHoursRecord>,
+    pub catch_up: String,
+    pub daily_goal_ml: u32,
+    pub day_start_hour: u32,
+}
+
+impl From<&ExportSettings> for SettingsRecord {
+    fn from(settings: &ExportSettings) -> Self {
+        let config: &ReminderConfig = &settings.reminder_config;
+        Self {
+            interval_minutes: config.interval_minutes,
+            cron: config.cron.as_ref().map(CronSchedule::to_string),
+            active_window: config.active_window.map(ActiveWindowRecord::from),
+            quiet_hours: config.quiet_hours.map(QuietHoursRecord::from),
+            catch_up: config.catch_up.as_str().to_string(),
+            daily_goal_ml: settings.goal.daily_goal_ml,
+            day_start_hour: settings.goal.day_start_hour,
+        }
+    }
+}
+
+impl TryFrom<SettingsRecord> for ExportSettings {
+    type Error = String;
+
+    fn try_from(record: SettingsRecord) -> Result<Self, Self::Error> {
+        if !(1..=MAX_INTERVAL_MINUTES).contains(&record.interval_minutes) {
+            return Err(format!(
+                "interval_minutes: {} is not 1-{MAX_INTERVAL_MINUTES}",
+                record.interval_minutes
+            ));
+        }
+        let cron: Option<CronSchedule> = record
+            .cron
+            .map(|raw: String| raw.parse::<CronSchedule>())
+            .transpose()
+            .map_err(|err: CronError| format!("cron: {err}"))?;
+        let catch_up: CatchUpPolicy = record
+            .catch_up
+            .parse::<CatchUpPolicy>()
+            .map_err(|()| format!("catch_up: unknown policy {:?}", record.catch_up))?;
+        let goal: HydrationGoal = HydrationGoal::new(record.daily_goal_ml.max(1), record.day_start_hour)
+            .ok_or_else(|| format!("day_start_hour: {} is not 0-23", record.day_start_hour))?;
+
+        Ok(Self {
+            reminder_config: ReminderConfig {
+                cron,
+                active_window: record.active_window.map(ActiveWindow::try_from).transpose()?,
+                quiet_hours: record.quiet_hours.map(QuietHours::try_from).transpose()?,
+                catch_up,
+                ..ReminderConfig::new(record.interval_minutes)
+            },
+            goal,
+        })
+    }
+}
+
+/// Daily window in which reminders fire, with its weekdays (`"mon"` to `"sun"`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActiveWindowRecord {
+    pub start: String,
+    pub end: String,
+    pub days: Vec<String>,
+}
+
+impl From<ActiveWindow> for ActiveWindowRecord {
+    fn from(window: ActiveWindow) -> Self {
+        Self {
+            start: clock_time(window.start),
+            end: clock_time(window.end),
+            days: WEEKDAYS
+                .iter()
+                .filter(|day: &&Weekday| window.days.contains(**day))
+                .map(|day: &Weekday| day.to_string().to_ascii_lowercase())
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<ActiveWindowRecord> for ActiveWindow {
+    type Error = String;
+
+    fn try_from(record: ActiveWindowRecord) -> Result<Self, Self::Error> {
+        let days: Vec<Weekday> = record
+            .days
+            .iter()
+            .map(|raw: &String| raw.parse::<Weekday>().map_err(|_| format!("active_window: unknown day {raw:?}")))
+            .collect::<Result<Vec<Weekday>, String>>()?;
+        ActiveWindow::new(
+            parse_clock_time("active_window.start", &record.start)?,
+            parse_clock_time("active_window.end", &record.end)?,
+            WeekdaySet::from_days(&days),
+        )
+        .ok_or_else(|| "active_window: must start before it ends and name a day".to_string())
+    }
+}
+
+/// Daily span during which reminders stay silent.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuietHoursRecord {
+    pub start: String,
+    pub end: String,
+}
+
+impl From<QuietHours> for QuietHoursRecord {
+    fn from(quiet_hours: QuietHours) -> Self {
+        Self {
+            start: clock_time(quiet_hours.start),
+            end: clock_time(quiet_hours.end),
+        }
+    }
+}
+
+impl TryFrom<QuietHoursRecord> for QuietHours {
+    type Error = String;
+
+    fn try_from(record: QuietHoursRecord) -> Result<Self, Self::Error> {
+        QuietHours::new(
+            parse_clock_time("quiet_hours.start", &record.start)?,
+            parse_clock_time("quiet_hours.end", &record.end)?,
+        )
+        .ok_or_else(|| "quiet_hours: must not start and end at the same time".to_string())
+    }
+}
+
+/// A logged reminder; `outcome` is `drank`, `snoozed` or `dismissed` once answered.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReminderLogRecord {
+    pub id: i64,
+    pub message: String,
+    pub triggered_at: DateTime<Utc>,
+    pub outcome: Option<String>,
+    pub snooze_minutes: Option<u32>,
+    pub responded_at: Option<DateTime<Utc>>,
+}
+
+impl From<&ReminderLogEntry> for ReminderLogRecord {
+    fn from(entry: &ReminderLogEntry) -> Self {
+        Self {
+            id: entry.id,
+            message: entry.message.clone(),
+            triggered_at: entry.triggered_at,
+            outcome: entry.outcome.map(|outcome: ReminderOutcome| outcome.as_str().to_string()),
+            snooze_minutes: match entry.outcome {
+                Some(ReminderOutcome::Snoozed { minutes }) => Some(minutes),
+                _ => None,
+            },
+            responded_at: entry.responded_at,
+        }
+    }
+}
+
+impl TryFrom<ReminderLogRecord> for ReminderLogEntry {
+    type Error = String;
+
+    fn try_from(record: ReminderLogRecord) -> Result<Self, Self::Error> {
+        let outcome: Option<ReminderOutcome> = record
+            .outcome
+            .map(|raw: String| {
+                ReminderOutcome::from_stored(&raw, record.snooze_minutes)
+                    .ok_or_else(|| format!("outcome: unknown outcome {raw:?}"))
+            })
+            .transpose()?;
+        Ok(Self {
+            id: record.id,
+            message: record.message,
+            triggered_at: record.triggered_at,
+            outcome,
+            responded_at: record.responded_at,
+        })
+    }
+}
+
+/// A logged drink.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WaterIntakeRecord {
+    pub id: i64,
+    pub amount_ml: u32,
+    pub consumed_at: DateTime<Utc>,
+    pub source: Option<String>,
+}
+
+impl From<&WaterIntake> for WaterIntakeRecord {
+    fn from(intake: &WaterIntake) -> Self {
+        Self {
+            id: intake.id,
+            amount_ml: intake.amount_ml,
+            consumed_at: intake.consumed_at,
+            source: intake.source.map(|source: IntakeSource| source.as_str().to_string()),
+        }
+    }
+}
+
+impl TryFrom<WaterIntakeRecord> for WaterIntake {
+    type Error = String;
+
+    fn try_from(record: WaterIntakeRecord) -> Result<Self, Self::Error> {
+        if !is_valid_intake_amount(record.amount_ml) {
+            return Err(format!("amount_ml: {} is not 1-{MAX_INTAKE_ML}", record.amount_ml));
+        }
+        let source: Option<IntakeSource> = record
+            .source
+            .map(|raw: String| raw.parse::<IntakeSource>().map_err(|()| format!("source: unknown source {raw:?}")))
+            .transpose()?;
+        Ok(Self {
+            id: record.id,
+            amount_ml: record.amount_ml,
+            consumed_at: record.consumed_at,
+            source,
+        })
+    }
+}
+
+/// A journal entry, one per date.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JournalEntryRecord {
+    pub id: i64,
+    pub entry_date: NaiveDate,
+    pub prompt_id: Option<i64>,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&JournalEntry> for JournalEntryRecord {
+    fn from(entry: &JournalEntry) -> Self {
+        Self {
+            id: entry.id,
+            entry_date: entry.entry_date.date(),
+            prompt_id: entry.prompt_id,
+            body: entry.body.clone(),
+            created_at: entry.created_at,
+            updated_at: entry.updated_at,
+        }
+    }
+}
+
+impl TryFrom<JournalEntryRecord> for JournalEntry {
+    type Error = String;
+
+    fn try_from(record: JournalEntryRecord) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: record.id,
+            entry_date: EntryDate::new(record.entry_date),
+            prompt_id: record.prompt_id,
+            body: normalize_entry_body(&record.body).ok_or_else(|| "body: must not be empty".to_string())?,
+            created_at: record.created_at,
+            updated_at: record.updated_at,
+        })
+    }
+}
+
+fn clock_time(time: NaiveTime) -> String {
+    time.format("%H:%M").to_string()
+}
+
+fn parse_clock_time(field: &str, raw: &str) -> Result<NaiveTime, String> {
+    NaiveTime::parse_from_str(raw.trim(), "%H:%M").map_err(|_| format!("{field}: expected HH:MM, got {raw:?}"))
+}
@@ -1,3 +1,5 @@
 //! Outbound adapters that implement external dependency ports.
+pub mod clock;
 pub mod events;
+pub mod export;
 pub mod sqlite;
@@ -0,0 +1,53 @@
+//! SQLite implementation of the achievement repository port.
+use crate::domain::achievements::AchievementUnlock;
+use crate::error::CoreResult;
+use crate::ports::achievement_repository::AchievementRepository;
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use sqlx::SqlitePool;
+
+pub struct SqliteAchievementRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteAchievementRepository {
+    /// Creates a SQLite-backed achievement repository.
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AchievementRepository for SqliteAchievementRepository {
+    async fn insert_achievement_unlock(&self, id: &str, unlocked_at: DateTime<Utc>) -> CoreResult<bool> {
+        let inserted: u64 = sqlx::query(
+            r#"
+            INSERT INTO achievement_unlocks (id, unlocked_at)
+            VALUES (?1, ?2)
+            ON CONFLICT (id) DO NOTHING
+            "#,
+        )
+        .bind(id)
+        .bind(unlocked_at.naive_utc())
+        .execute(&self.pool)
+        .await?
+        .rows_affected();
+
+        Ok(inserted == 1)
+    }
+
+    async fn achievement_unlocks(&self) -> CoreResult<Vec<AchievementUnlock>> {
+        let rows: Vec<(String, NaiveDateTime)> =
+            sqlx::query_as("SELECT id, unlocked_at FROM achievement_unlocks ORDER BY unlocked_at, id")
+                .fetch_all(&self.pool)
+                .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, unlocked_at): (String, NaiveDateTime)| AchievementUnlock {
+                id,
+                unlocked_at: unlocked_at.and_utc(),
+            })
+            .collect())
+    }
+}
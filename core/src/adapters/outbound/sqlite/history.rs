@@ -0,0 +1,47 @@
+//! Keyset pagination shared by the history listings.
+use crate::domain::history::{HistoryCursor, HistoryQuery, SortOrder};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use sqlx::query::QueryAs;
+use sqlx::sqlite::SqliteArguments;
+use sqlx::Sqlite;
+
+/// Builds a page query over `table`, ordered by `(time_column, id)`.
+///
+/// The placeholders are bound by `bind_page`: range start and end, cursor time and
+/// ID, then the row limit.
+pub(super) fn page_sql(columns: &str, table: &str, time_column: &str, order: SortOrder) -> String {
+    let (after, direction): (&str, &str) = match order {
+        SortOrder::NewestFirst => ("<", "DESC"),
+        SortOrder::OldestFirst => (">", "ASC"),
+    };
+    format!(
+        r#"
+        SELECT {columns}
+        FROM {table}
+        WHERE (?1 IS NULL OR {time_column} >= ?1)
+          AND (?2 IS NULL OR {time_column} < ?2)
+          AND (?3 IS NULL OR ({time_column}, id) {after} (?3, ?4))
+        ORDER BY {time_column} {direction}, id {direction}
+        LIMIT ?5
+        "#
+    )
+}
+
+/// Binds a query built by `page_sql`, asking for one row past the page to detect more.
+pub(super) fn bind_page<'q, O>(
+    query: QueryAs<'q, Sqlite, O, SqliteArguments<'q>>,
+    history: &HistoryQuery,
+) -> QueryAs<'q, Sqlite, O, SqliteArguments<'q>> {
+    let naive = |at: DateTime<Utc>| at.naive_utc();
+    query
+        .bind(history.from.map(naive))
+        .bind(history.until.map(naive))
+        .bind(history.after.map(|cursor: HistoryCursor| cursor.at.naive_utc()))
+        .bind(history.after.map(|cursor: HistoryCursor| cursor.id))
+        .bind(i64::from(history.page_size()) + 1)
+}
+
+/// Reads an optional stored timestamp as UTC.
+pub(super) fn utc(at: Option<NaiveDateTime>) -> Option<DateTime<Utc>> {
+    at.map(|at: NaiveDateTime| at.and_utc())
+}
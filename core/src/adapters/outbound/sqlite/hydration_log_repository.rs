@@ -1,8 +1,16 @@
 //! SQLite implementation of the hydration log repository port.
+use super::history::{bind_page, page_sql, utc};
+use super::water_intake_repository::insert_intake;
+use crate::domain::history::{HistoryPage, HistoryQuery, ReminderLogEntry, ReminderOutcome};
+use crate::domain::hydration::{HydrationReminder, ReminderResponse, WaterIntake};
+use crate::domain::stats::ReminderResponseCounts;
 use crate::error::CoreResult;
 use crate::ports::hydration_log_repository::HydrationLogRepository;
 use async_trait::async_trait;
-use sqlx::SqlitePool;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use sqlx::{Sqlite, SqlitePool, Transaction};
+
+pub(super) type ReminderLogRow = (i64, String, NaiveDateTime, Option<String>, Option<i64>, Option<NaiveDateTime>);
 
 pub struct SqliteHydrationLogRepository {
     pool: SqlitePool,
@@ -15,14 +23,42 @@ impl SqliteHydrationLogRepository {
     }
 }
 
+pub(super) fn log_entry_from_row(row: ReminderLogRow) -> ReminderLogEntry {
+    let (id, message, triggered_at, outcome, snooze_minutes, responded_at): ReminderLogRow = row;
+    let snooze_minutes: Option<u32> = snooze_minutes.and_then(|minutes: i64| u32::try_from(minutes).ok());
+    ReminderLogEntry {
+        id,
+        message,
+        triggered_at: triggered_at.and_utc(),
+        outcome: outcome.and_then(|raw: String| ReminderOutcome::from_stored(&raw, snooze_minutes)),
+        responded_at: utc(responded_at),
+    }
+}
+
 #[async_trait]
 impl HydrationLogRepository for SqliteHydrationLogRepository {
-    async fn insert_hydration_log(&self, message: &str) -> CoreResult<()> {
-        sqlx::query("INSERT INTO hydration_reminder_logs (message) VALUES (?1)")
-            .bind(message)
-            .execute(&self.pool)
-            .await?;
-        Ok(())
+    async fn insert_hydration_log(
+        &self,
+        message: &str,
+        triggered_at: DateTime<Utc>,
+    ) -> CoreResult<HydrationReminder> {
+        let (id, message, triggered_at): (i64, String, NaiveDateTime) = sqlx::query_as(
+            r#"
+            INSERT INTO hydration_reminder_logs (message, triggered_at)
+            VALUES (?1, ?2)
+            RETURNING id, message, triggered_at
+            "#,
+        )
+        .bind(message)
+        .bind(triggered_at.naive_utc())
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(HydrationReminder {
+            id,
+            message,
+            triggered_at: triggered_at.and_utc(),
+        })
     }
 
     async fn hydration_log_count(&self) -> CoreResult<i64> {
@@ -32,4 +68,102 @@ impl HydrationLogRepository for SqliteHydrationLogRepository {
 
         Ok(count)
     }
+
+    async fn latest_reminder_at(&self) -> CoreResult<Option<DateTime<Utc>>> {
+        let latest: Option<NaiveDateTime> =
+            sqlx::query_scalar("SELECT MAX(triggered_at) FROM hydration_reminder_logs")
+                .fetch_one(&self.pool)
+                .await?;
+
+        Ok(latest.map(|triggered_at: NaiveDateTime| triggered_at.and_utc()))
+    }
+
+    async fn record_reminder_response(
+        &self,
+        reminder_id: i64,
+        response: ReminderResponse,
+        responded_at: DateTime<Utc>,
+    ) -> CoreResult<bool> {
+        let snooze_minutes: Option<i64> = match response {
+            ReminderResponse::Snoozed { minutes } => Some(i64::from(minutes)),
+            ReminderResponse::Drank { .. } | ReminderResponse::Dismissed => None,
+        };
+
+        let mut tx: Transaction<'static, Sqlite> = self.pool.begin().await?;
+        let updated: u64 = sqlx::query(
+            r#"
+            UPDATE hydration_reminder_logs
+            SET outcome = ?2, snooze_minutes = ?3, responded_at = ?4
+            WHERE id = ?1
+            "#,
+        )
+        .bind(reminder_id)
+        .bind(response.outcome())
+        .bind(snooze_minutes)
+        .bind(responded_at.naive_utc())
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+        if updated != 1 {
+            return Ok(false);
+        }
+
+        if let ReminderResponse::Drank {
+            amount_ml: Some(amount_ml),
+        } = response
+        {
+            let _: WaterIntake = insert_intake(&mut tx, amount_ml, responded_at, None).await?;
+        }
+        tx.commit().await?;
+
+        Ok(true)
+    }
+
+    async fn list_hydration_logs(&self, query: &HistoryQuery) -> CoreResult<HistoryPage<ReminderLogEntry>> {
+        let sql: String = page_sql(
+            "id, message, triggered_at, outcome, snooze_minutes, responded_at",
+            "hydration_reminder_logs",
+            "triggered_at",
+            query.order,
+        );
+        let rows: Vec<ReminderLogRow> = bind_page(sqlx::query_as(&sql), query)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(HistoryPage::from_rows(
+            rows.into_iter().map(log_entry_from_row).collect(),
+            query.page_size(),
+            ReminderLogEntry::cursor,
+        ))
+    }
+
+    async fn reminder_response_counts(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> CoreResult<ReminderResponseCounts> {
+        let (fired, drank, snoozed, dismissed): (i64, i64, i64, i64) = sqlx::query_as(
+            r#"
+            SELECT
+                COUNT(*),
+                COALESCE(SUM(outcome = 'drank'), 0),
+                COALESCE(SUM(outcome = 'snoozed'), 0),
+                COALESCE(SUM(outcome = 'dismissed'), 0)
+            FROM hydration_reminder_logs
+            WHERE triggered_at >= ?1 AND triggered_at < ?2
+            "#,
+        )
+        .bind(start.naive_utc())
+        .bind(end.naive_utc())
+        .fetch_one(&self.pool)
+        .await?;
+
+        let count = |value: i64| -> u64 { u64::try_from(value).unwrap_or(0) };
+        Ok(ReminderResponseCounts {
+            fired: count(fired),
+            drank: count(drank),
+            snoozed: count(snoozed),
+            dismissed: count(dismissed),
+        })
+    }
 }
@@ -0,0 +1,271 @@
+//! SQLite implementation of the import repository port.
+use super::hydration_log_repository::{log_entry_from_row, ReminderLogRow};
+use super::journal_entry_repository::{entry_from_row, JournalEntryRow};
+use super::settings_repository::minute_of_day;
+use super::water_intake_repository::{intake_from_row, WaterIntakeRow};
+use crate::domain::export::{
+    ExportSettings, ImportAction, ImportRecords, ImportStrategy, ImportSummary, RecordMatch,
+};
+use crate::domain::history::{ReminderLogEntry, ReminderOutcome};
+use crate::domain::hydration::{ActiveWindow, CronSchedule, IntakeSource, QuietHours, ReminderConfig, WaterIntake};
+use crate::domain::journal::JournalEntry;
+use crate::error::CoreResult;
+use crate::ports::import_repository::ImportRepository;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{SqliteConnection, SqlitePool};
+
+pub struct SqliteImportRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteImportRepository {
+    /// Creates a SQLite-backed import repository.
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ImportRepository for SqliteImportRepository {
+    async fn import_records(
+        &self,
+        records: &ImportRecords,
+        settings: Option<&ExportSettings>,
+        strategy: ImportStrategy,
+    ) -> CoreResult<ImportSummary> {
+        let mut tx: sqlx::Transaction<'static, sqlx::Sqlite> = self.pool.begin().await?;
+        let mut summary: ImportSummary = ImportSummary::default();
+
+        if let Some(settings) = settings {
+            import_settings(&mut tx, settings).await?;
+        }
+        for entry in &records.reminder_logs {
+            let action: ImportAction = import_reminder_log(&mut tx, entry, strategy).await?;
+            summary.reminder_logs.record(action);
+        }
+        for intake in &records.water_intake {
+            let action: ImportAction = import_water_intake(&mut tx, intake, strategy).await?;
+            summary.water_intake.record(action);
+        }
+        for entry in &records.journal_entries {
+            let action: ImportAction = import_journal_entry(&mut tx, entry, strategy).await?;
+            summary.journal_entries.record(action);
+        }
+
+        tx.commit().await?;
+        Ok(summary)
+    }
+}
+
+/// Saves imported reminder settings and goal over the stored ones; the pause is kept.
+///
+/// Missing quiet hours are saved as turned off, since the export holds effective settings.
+async fn import_settings(conn: &mut SqliteConnection, settings: &ExportSettings) -> CoreResult<()> {
+    let config: &ReminderConfig = &settings.reminder_config;
+    sqlx::query(
+        r#"
+        INSERT INTO hydration_settings (
+            id, interval_minutes, cron_expression, active_start_minute, active_end_minute, active_days,
+            quiet_start_minute, quiet_end_minute, quiet_hours_disabled, catch_up_policy, daily_goal_ml,
+            day_start_hour
+        )
+        VALUES (1, ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
+        ON CONFLICT(id)
+        DO UPDATE SET
+            interval_minutes = excluded.interval_minutes,
+            cron_expression = excluded.cron_expression,
+            active_start_minute = excluded.active_start_minute,
+            active_end_minute = excluded.active_end_minute,
+            active_days = excluded.active_days,
+            quiet_start_minute = excluded.quiet_start_minute,
+            quiet_end_minute = excluded.quiet_end_minute,
+            quiet_hours_disabled = excluded.quiet_hours_disabled,
+            catch_up_policy = excluded.catch_up_policy,
+            daily_goal_ml = excluded.daily_goal_ml,
+            day_start_hour = excluded.day_start_hour
+        "#,
+    )
+    .bind(i64::try_from(config.interval_minutes).unwrap_or(i64::MAX))
+    .bind(config.cron.map(|cron: CronSchedule| cron.to_string()))
+    .bind(config.active_window.map(|window: ActiveWindow| minute_of_day(window.start)))
+    .bind(config.active_window.map(|window: ActiveWindow| minute_of_day(window.end)))
+    .bind(config.active_window.map(|window: ActiveWindow| i64::from(window.days.bits())))
+    .bind(config.quiet_hours.map(|quiet: QuietHours| minute_of_day(quiet.start)))
+    .bind(config.quiet_hours.map(|quiet: QuietHours| minute_of_day(quiet.end)))
+    .bind(config.quiet_hours.is_none().then_some(1_i64))
+    .bind(config.catch_up.as_str())
+    .bind(i64::from(settings.goal.daily_goal_ml))
+    .bind(i64::from(settings.goal.day_start_hour))
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+/// Snooze minutes stored alongside a reminder outcome.
+fn snooze_minutes(outcome: Option<ReminderOutcome>) -> Option<i64> {
+    match outcome {
+        Some(ReminderOutcome::Snoozed { minutes }) => Some(i64::from(minutes)),
+        _ => None,
+    }
+}
+
+async fn import_reminder_log(
+    conn: &mut SqliteConnection,
+    entry: &ReminderLogEntry,
+    strategy: ImportStrategy,
+) -> CoreResult<ImportAction> {
+    let existing: Vec<ReminderLogEntry> = sqlx::query_as::<_, ReminderLogRow>(
+        r#"
+        SELECT id, message, triggered_at, outcome, snooze_minutes, responded_at
+        FROM hydration_reminder_logs
+        WHERE triggered_at = ?1
+        ORDER BY id
+        "#,
+    )
+    .bind(entry.triggered_at.naive_utc())
+    .fetch_all(&mut *conn)
+    .await?
+    .into_iter()
+    .map(log_entry_from_row)
+    .collect();
+
+    let action: ImportAction = strategy.resolve(RecordMatch::among(&existing, entry), false);
+    match (action, existing.first()) {
+        (ImportAction::Insert, _) => {
+            sqlx::query(
+                r#"
+                INSERT INTO hydration_reminder_logs (message, triggered_at, outcome, snooze_minutes, responded_at)
+                VALUES (?1, ?2, ?3, ?4, ?5)
+                "#,
+            )
+            .bind(&entry.message)
+            .bind(entry.triggered_at.naive_utc())
+            .bind(entry.outcome.map(ReminderOutcome::as_str))
+            .bind(snooze_minutes(entry.outcome))
+            .bind(entry.responded_at.map(|at: DateTime<Utc>| at.naive_utc()))
+            .execute(&mut *conn)
+            .await?;
+        }
+        (ImportAction::Replace, Some(stored)) => {
+            sqlx::query(
+                r#"
+                UPDATE hydration_reminder_logs
+                SET message = ?2, outcome = ?3, snooze_minutes = ?4, responded_at = ?5
+                WHERE id = ?1
+                "#,
+            )
+            .bind(stored.id)
+            .bind(&entry.message)
+            .bind(entry.outcome.map(ReminderOutcome::as_str))
+            .bind(snooze_minutes(entry.outcome))
+            .bind(entry.responded_at.map(|at: DateTime<Utc>| at.naive_utc()))
+            .execute(&mut *conn)
+            .await?;
+        }
+        _ => {}
+    }
+    Ok(action)
+}
+
+async fn import_water_intake(
+    conn: &mut SqliteConnection,
+    intake: &WaterIntake,
+    strategy: ImportStrategy,
+) -> CoreResult<ImportAction> {
+    let existing: Vec<WaterIntake> = sqlx::query_as::<_, WaterIntakeRow>(
+        r#"
+        SELECT id, amount_ml, consumed_at, source
+        FROM water_intake_logs
+        WHERE consumed_at = ?1
+        ORDER BY id
+        "#,
+    )
+    .bind(intake.consumed_at.naive_utc())
+    .fetch_all(&mut *conn)
+    .await?
+    .into_iter()
+    .map(intake_from_row)
+    .collect();
+
+    let action: ImportAction = strategy.resolve(RecordMatch::among(&existing, intake), false);
+    match (action, existing.first()) {
+        (ImportAction::Insert, _) => {
+            sqlx::query("INSERT INTO water_intake_logs (amount_ml, consumed_at, source) VALUES (?1, ?2, ?3)")
+                .bind(i64::from(intake.amount_ml))
+                .bind(intake.consumed_at.naive_utc())
+                .bind(intake.source.map(IntakeSource::as_str))
+                .execute(&mut *conn)
+                .await?;
+        }
+        (ImportAction::Replace, Some(stored)) => {
+            sqlx::query("UPDATE water_intake_logs SET amount_ml = ?2, source = ?3 WHERE id = ?1")
+                .bind(stored.id)
+                .bind(i64::from(intake.amount_ml))
+                .bind(intake.source.map(IntakeSource::as_str))
+                .execute(&mut *conn)
+                .await?;
+        }
+        _ => {}
+    }
+    Ok(action)
+}
+
+async fn import_journal_entry(
+    conn: &mut SqliteConnection,
+    entry: &JournalEntry,
+    strategy: ImportStrategy,
+) -> CoreResult<ImportAction> {
+    let existing: Vec<JournalEntry> = sqlx::query_as::<_, JournalEntryRow>(
+        r#"
+        SELECT id, entry_date, prompt_id, body, created_at, updated_at
+        FROM journal_entries
+        WHERE entry_date = ?1
+        "#,
+    )
+    .bind(entry.entry_date.date())
+    .fetch_all(&mut *conn)
+    .await?
+    .into_iter()
+    .map(entry_from_row)
+    .collect();
+
+    let action: ImportAction = strategy.resolve(RecordMatch::among(&existing, entry), true);
+    match (action, existing.first()) {
+        (ImportAction::Insert, _) => {
+            // Prompt IDs come from the exporting database; unknown ones are dropped.
+            sqlx::query(
+                r#"
+                INSERT INTO journal_entries (entry_date, prompt_id, body, created_at, updated_at)
+                VALUES (?1, (SELECT id FROM journal_prompts WHERE id = ?2), ?3, ?4, ?5)
+                "#,
+            )
+            .bind(entry.entry_date.date())
+            .bind(entry.prompt_id)
+            .bind(&entry.body)
+            .bind(entry.created_at.naive_utc())
+            .bind(entry.updated_at.naive_utc())
+            .execute(&mut *conn)
+            .await?;
+        }
+        (ImportAction::Replace, Some(stored)) => {
+            sqlx::query("UPDATE journal_entries SET body = ?2, updated_at = ?3 WHERE id = ?1")
+                .bind(stored.id)
+                .bind(&entry.body)
+                .bind(entry.updated_at.naive_utc())
+                .execute(&mut *conn)
+                .await?;
+        }
+        (ImportAction::Merge, Some(stored)) => {
+            sqlx::query("UPDATE journal_entries SET body = ?2, updated_at = ?3 WHERE id = ?1")
+                .bind(stored.id)
+                .bind(format!("{}\n\n{}", stored.body, entry.body))
+                .bind(stored.updated_at.max(entry.updated_at).naive_utc())
+                .execute(&mut *conn)
+                .await?;
+        }
+        _ => {}
+    }
+    Ok(action)
+}
@@ -0,0 +1,116 @@
+//! SQLite implementation of the journal entry repository port.
+use crate::domain::journal::{EntryDate, JournalEntry};
+use crate::error::CoreResult;
+use crate::ports::journal_entry_repository::JournalEntryRepository;
+use async_trait::async_trait;
+use chrono::{NaiveDate, NaiveDateTime};
+use sqlx::SqlitePool;
+
+pub(super) type JournalEntryRow = (i64, NaiveDate, Option<i64>, String, NaiveDateTime, NaiveDateTime);
+
+pub struct SqliteJournalEntryRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteJournalEntryRepository {
+    /// Creates a SQLite-backed journal entry repository.
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+pub(super) fn entry_from_row(row: JournalEntryRow) -> JournalEntry {
+    let (id, entry_date, prompt_id, body, created_at, updated_at): JournalEntryRow = row;
+    JournalEntry {
+        id,
+        entry_date: EntryDate::new(entry_date),
+        prompt_id,
+        body,
+        created_at: created_at.and_utc(),
+        updated_at: updated_at.and_utc(),
+    }
+}
+
+#[async_trait]
+impl JournalEntryRepository for SqliteJournalEntryRepository {
+    async fn upsert_journal_entry(
+        &self,
+        entry_date: EntryDate,
+        prompt_id: Option<i64>,
+        body: &str,
+    ) -> CoreResult<JournalEntry> {
+        let row: JournalEntryRow = sqlx::query_as(
+            r#"
+            INSERT INTO journal_entries (entry_date, prompt_id, body)
+            VALUES (?1, ?2, ?3)
+            ON CONFLICT(entry_date)
+            DO UPDATE SET prompt_id = excluded.prompt_id, body = excluded.body, updated_at = CURRENT_TIMESTAMP
+            RETURNING id, entry_date, prompt_id, body, created_at, updated_at
+            "#,
+        )
+        .bind(entry_date.date())
+        .bind(prompt_id)
+        .bind(body)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(entry_from_row(row))
+    }
+
+    async fn journal_entry_for_date(&self, entry_date: EntryDate) -> CoreResult<Option<JournalEntry>> {
+        let maybe_row: Option<JournalEntryRow> = sqlx::query_as(
+            r#"
+            SELECT id, entry_date, prompt_id, body, created_at, updated_at
+            FROM journal_entries
+            WHERE entry_date = ?1
+            "#,
+        )
+        .bind(entry_date.date())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(maybe_row.map(entry_from_row))
+    }
+
+    async fn list_journal_entries(&self, limit: u32) -> CoreResult<Vec<JournalEntry>> {
+        let rows: Vec<JournalEntryRow> = sqlx::query_as(
+            r#"
+            SELECT id, entry_date, prompt_id, body, created_at, updated_at
+            FROM journal_entries
+            ORDER BY entry_date DESC
+            LIMIT ?1
+            "#,
+        )
+        .bind(i64::from(limit))
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(entry_from_row).collect())
+    }
+
+    async fn list_journal_entries_after(&self, after: Option<EntryDate>, limit: u32) -> CoreResult<Vec<JournalEntry>> {
+        let rows: Vec<JournalEntryRow> = sqlx::query_as(
+            r#"
+            SELECT id, entry_date, prompt_id, body, created_at, updated_at
+            FROM journal_entries
+            WHERE ?1 IS NULL OR entry_date > ?1
+            ORDER BY entry_date ASC
+            LIMIT ?2
+            "#,
+        )
+        .bind(after.map(EntryDate::date))
+        .bind(i64::from(limit))
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(entry_from_row).collect())
+    }
+
+    async fn journal_entry_count(&self) -> CoreResult<u64> {
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM journal_entries")
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(u64::try_from(count).unwrap_or(0))
+    }
+}
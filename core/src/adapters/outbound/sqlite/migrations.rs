@@ -1,26 +1,197 @@
-//! SQLite schema bootstrap for required core tables.
-use crate::error::CoreResult;
-use sqlx::SqlitePool;
+//! Versioned SQLite schema migrations applied in order at startup.
+use crate::error::{CoreError, CoreResult};
+use sqlx::{Sqlite, SqlitePool, Transaction};
 
-/// Creates required SQLite tables for the current core schema.
+/// One ordered schema step; statements run together in a single transaction.
+struct Migration {
+    version: i64,
+    description: &'static str,
+    statements: &'static [&'static str],
+}
+
+// Versions 1 and 2 predate the schema_version table, so they tolerate databases
+// that already contain their tables (for example the original desk-buddy.db).
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "hydration reminder logs and settings",
+        statements: &[
+            r#"
+            CREATE TABLE IF NOT EXISTS hydration_reminder_logs (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                message TEXT NOT NULL,
+                triggered_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+            )
+            "#,
+            r#"
+            CREATE TABLE IF NOT EXISTS hydration_settings (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                interval_minutes INTEGER NOT NULL
+            )
+            "#,
+        ],
+    },
+    Migration {
+        version: 2,
+        description: "journal prompts and entries",
+        statements: &[
+            r#"
+            CREATE TABLE IF NOT EXISTS journal_prompts (
+                id INTEGER PRIMARY KEY,
+                text TEXT NOT NULL
+            )
+            "#,
+            r#"
+            INSERT OR IGNORE INTO journal_prompts (id, text) VALUES
+                (1, 'What is one thing you want to finish today?'),
+                (2, 'What gave you energy yesterday?'),
+                (3, 'Which task are you avoiding, and why?'),
+                (4, 'What would make today feel like a good day?'),
+                (5, 'Who helped you recently, and how?'),
+                (6, 'What did you learn this week?'),
+                (7, 'How are you feeling right now, honestly?')
+            "#,
+            r#"
+            CREATE TABLE IF NOT EXISTS journal_entries (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                entry_date TEXT NOT NULL UNIQUE,
+                prompt_id INTEGER REFERENCES journal_prompts (id),
+                body TEXT NOT NULL,
+                created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+            )
+            "#,
+        ],
+    },
+    Migration {
+        version: 3,
+        description: "water intake logs",
+        statements: &[
+            r#"
+            CREATE TABLE water_intake_logs (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                amount_ml INTEGER NOT NULL CHECK (amount_ml > 0),
+                source TEXT,
+                consumed_at TEXT NOT NULL
+            )
+            "#,
+        ],
+    },
+    Migration {
+        version: 4,
+        description: "nullable hydration settings with daily goal and day start hour",
+        // SQLite cannot relax NOT NULL in place, so the settings table is rebuilt.
+        statements: &[
+            r#"
+            CREATE TABLE hydration_settings_v4 (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                interval_minutes INTEGER,
+                daily_goal_ml INTEGER,
+                day_start_hour INTEGER
+            )
+            "#,
+            r#"
+            INSERT INTO hydration_settings_v4 (id, interval_minutes)
+            SELECT id, interval_minutes FROM hydration_settings
+            "#,
+            "DROP TABLE hydration_settings",
+            "ALTER TABLE hydration_settings_v4 RENAME TO hydration_settings",
+        ],
+    },
+    Migration {
+        version: 5,
+        description: "reminder response outcomes",
+        statements: &[
+            "ALTER TABLE hydration_reminder_logs ADD COLUMN outcome TEXT",
+            "ALTER TABLE hydration_reminder_logs ADD COLUMN snooze_minutes INTEGER",
+            "ALTER TABLE hydration_reminder_logs ADD COLUMN responded_at TEXT",
+        ],
+    },
+    Migration {
+        version: 6,
+        description: "reminder active window and quiet hours",
+        statements: &[
+            "ALTER TABLE hydration_settings ADD COLUMN active_start_minute INTEGER",
+            "ALTER TABLE hydration_settings ADD COLUMN active_end_minute INTEGER",
+            "ALTER TABLE hydration_settings ADD COLUMN active_days INTEGER",
+            "ALTER TABLE hydration_settings ADD COLUMN quiet_start_minute INTEGER",
+            "ALTER TABLE hydration_settings ADD COLUMN quiet_end_minute INTEGER",
+        ],
+    },
+    Migration {
+        version: 7,
+        description: "missed reminder catch-up policy",
+        statements: &["ALTER TABLE hydration_settings ADD COLUMN catch_up_policy TEXT"],
+    },
+    Migration {
+        version: 8,
+        description: "cron reminder schedule",
+        statements: &["ALTER TABLE hydration_settings ADD COLUMN cron_expression TEXT"],
+    },
+    Migration {
+        version: 9,
+        description: "reminder pause",
+        statements: &[
+            "ALTER TABLE hydration_settings ADD COLUMN paused_until TEXT",
+            "ALTER TABLE hydration_settings ADD COLUMN paused_indefinitely INTEGER",
+        ],
+    },
+    Migration {
+        version: 10,
+        description: "history listing indexes",
+        // The ID breaks ties between equal timestamps, matching history cursors.
+        statements: &[
+            "CREATE INDEX hydration_reminder_logs_triggered_at ON hydration_reminder_logs (triggered_at, id)",
+            "CREATE INDEX water_intake_logs_consumed_at ON water_intake_logs (consumed_at, id)",
+        ],
+    },
+    Migration {
+        version: 11,
+        description: "achievement unlocks",
+        statements: &[
+            r#"
+            CREATE TABLE achievement_unlocks (
+                id TEXT PRIMARY KEY,
+                unlocked_at TEXT NOT NULL
+            )
+            "#,
+        ],
+    },
+    Migration {
+        version: 12,
+        description: "quiet hours turned off",
+        // Distinguishes "no quiet hours" from "nothing saved, use the default".
+        statements: &["ALTER TABLE hydration_settings ADD COLUMN quiet_hours_disabled INTEGER"],
+    },
+];
+
+/// Highest schema version this binary knows how to read and write.
+pub const LATEST_SCHEMA_VERSION: i64 = MIGRATIONS[MIGRATIONS.len() - 1].version;
+
+/// Brings the database schema up to `LATEST_SCHEMA_VERSION`.
 pub async fn run_migrations(pool: &SqlitePool) -> CoreResult<()> {
-    sqlx::query(
-        r#"
-        CREATE TABLE IF NOT EXISTS hydration_reminder_logs (
-            id INTEGER PRIMARY KEY AUTOINCREMENT,
-            message TEXT NOT NULL,
-            triggered_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
-        )
-        "#,
-    )
-    .execute(pool)
-    .await?;
+    apply_migrations(pool, MIGRATIONS).await
+}
 
+/// Returns the schema version recorded in the database (`0` when unversioned).
+pub async fn schema_version(pool: &SqlitePool) -> CoreResult<i64> {
+    ensure_schema_version_table(pool).await?;
+
+    let version: i64 =
+        sqlx::query_scalar("SELECT COALESCE(MAX(version), 0) FROM schema_version")
+            .fetch_one(pool)
+            .await?;
+
+    Ok(version)
+}
+
+async fn ensure_schema_version_table(pool: &SqlitePool) -> CoreResult<()> {
     sqlx::query(
         r#"
-        CREATE TABLE IF NOT EXISTS hydration_settings (
-            id INTEGER PRIMARY KEY CHECK (id = 1),
-            interval_minutes INTEGER NOT NULL
+        CREATE TABLE IF NOT EXISTS schema_version (
+            version INTEGER PRIMARY KEY,
+            description TEXT NOT NULL,
+            applied_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
         )
         "#,
     )
@@ -29,3 +200,159 @@ pub async fn run_migrations(pool: &SqlitePool) -> CoreResult<()> {
 
     Ok(())
 }
+
+async fn apply_migrations(pool: &SqlitePool, migrations: &[Migration]) -> CoreResult<()> {
+    let current_version: i64 = schema_version(pool).await?;
+    let supported_version: i64 = migrations
+        .last()
+        .map(|migration: &Migration| migration.version)
+        .unwrap_or(0);
+
+    // Refuse to touch a schema written by a newer binary rather than guess at it.
+    if current_version > supported_version {
+        return Err(CoreError::SchemaTooNew {
+            database_version: current_version,
+            supported_version,
+        });
+    }
+
+    for migration in migrations
+        .iter()
+        .filter(|migration: &&Migration| migration.version > current_version)
+    {
+        let mut transaction: Transaction<'_, Sqlite> = pool.begin().await?;
+
+        for statement in migration.statements {
+            sqlx::query(statement).execute(&mut *transaction).await?;
+        }
+
+        sqlx::query("INSERT INTO schema_version (version, description) VALUES (?1, ?2)")
+            .bind(migration.version)
+            .bind(migration.description)
+            .execute(&mut *transaction)
+            .await?;
+
+        transaction.commit().await?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::outbound::sqlite::connect_sqlite;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    const LEGACY_FIXTURE: &str = "tests/fixtures/desk-buddy-v0.db";
+
+    async fn legacy_database_copy(dir: &TempDir) -> CoreResult<SqlitePool> {
+        let fixture: PathBuf = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(LEGACY_FIXTURE);
+        let copy: PathBuf = dir.path().join("desk-buddy.db");
+        std::fs::copy(&fixture, &copy).expect("copy legacy fixture");
+
+        connect_sqlite(&format!("sqlite://{}", copy.display())).await
+    }
+
+    async fn table_exists(pool: &SqlitePool, table: &str) -> CoreResult<bool> {
+        let count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = ?1",
+        )
+        .bind(table)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(count == 1)
+    }
+
+    #[test]
+    fn migration_versions_are_strictly_increasing() {
+        let versions: Vec<i64> = MIGRATIONS
+            .iter()
+            .map(|migration: &Migration| migration.version)
+            .collect();
+
+        assert_eq!(versions.first(), Some(&1));
+        assert!(versions.windows(2).all(|pair: &[i64]| pair[0] + 1 == pair[1]));
+    }
+
+    #[tokio::test]
+    async fn fresh_database_migrates_to_latest_and_reruns_cleanly() -> CoreResult<()> {
+        let pool: SqlitePool = connect_sqlite("sqlite::memory:").await?;
+
+        run_migrations(&pool).await?;
+        run_migrations(&pool).await?;
+
+        assert_eq!(schema_version(&pool).await?, LATEST_SCHEMA_VERSION);
+        let applied: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM schema_version")
+            .fetch_one(&pool)
+            .await?;
+        assert_eq!(applied, LATEST_SCHEMA_VERSION);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn legacy_fixture_upgrades_and_keeps_existing_rows() -> CoreResult<()> {
+        let dir: TempDir = TempDir::new().expect("temp dir");
+        let pool: SqlitePool = legacy_database_copy(&dir).await?;
+        assert_eq!(schema_version(&pool).await?, 0);
+
+        run_migrations(&pool).await?;
+
+        assert_eq!(schema_version(&pool).await?, LATEST_SCHEMA_VERSION);
+        assert!(table_exists(&pool, "journal_entries").await?);
+        let logs: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM hydration_reminder_logs")
+            .fetch_one(&pool)
+            .await?;
+        assert_eq!(logs, 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn database_newer_than_binary_is_refused() -> CoreResult<()> {
+        let pool: SqlitePool = connect_sqlite("sqlite::memory:").await?;
+        run_migrations(&pool).await?;
+        sqlx::query("INSERT INTO schema_version (version, description) VALUES (?1, 'future')")
+            .bind(LATEST_SCHEMA_VERSION + 1)
+            .execute(&pool)
+            .await?;
+
+        let result: CoreResult<()> = run_migrations(&pool).await;
+
+        assert!(matches!(
+            result,
+            Err(CoreError::SchemaTooNew { database_version, supported_version })
+                if database_version == LATEST_SCHEMA_VERSION + 1
+                    && supported_version == LATEST_SCHEMA_VERSION
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn failing_step_rolls_back_its_transaction() -> CoreResult<()> {
+        const BROKEN: &[Migration] = &[
+            Migration {
+                version: 1,
+                description: "good step",
+                statements: &["CREATE TABLE first_table (id INTEGER PRIMARY KEY)"],
+            },
+            Migration {
+                version: 2,
+                description: "half-broken step",
+                statements: &[
+                    "CREATE TABLE second_table (id INTEGER PRIMARY KEY)",
+                    "INSERT INTO missing_table (id) VALUES (1)",
+                ],
+            },
+        ];
+        let pool: SqlitePool = connect_sqlite("sqlite::memory:").await?;
+
+        assert!(apply_migrations(&pool, BROKEN).await.is_err());
+
+        assert_eq!(schema_version(&pool).await?, 1);
+        assert!(table_exists(&pool, "first_table").await?);
+        assert!(!table_exists(&pool, "second_table").await?);
+        Ok(())
+    }
+}
@@ -1,10 +1,21 @@
 //! Outbound SQLite adapter modules for persistence and schema setup.
+mod achievement_repository;
 mod connection;
+mod history;
 mod hydration_log_repository;
+mod import_repository;
+mod journal_entry_repository;
 mod migrations;
+mod prompt_repository;
 mod settings_repository;
+mod water_intake_repository;
 
+pub use achievement_repository::SqliteAchievementRepository;
 pub use connection::connect_sqlite;
 pub use hydration_log_repository::SqliteHydrationLogRepository;
-pub use migrations::run_migrations;
+pub use import_repository::SqliteImportRepository;
+pub use journal_entry_repository::SqliteJournalEntryRepository;
+pub use migrations::{run_migrations, schema_version, LATEST_SCHEMA_VERSION};
+pub use prompt_repository::SqlitePromptRepository;
 pub use settings_repository::SqliteSettingsRepository;
+pub use water_intake_repository::SqliteWaterIntakeRepository;
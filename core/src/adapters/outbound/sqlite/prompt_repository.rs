@@ -0,0 +1,32 @@
+//! SQLite implementation of the journal prompt repository port.
+use crate::domain::journal::JournalPrompt;
+use crate::error::CoreResult;
+use crate::ports::prompt_repository::PromptRepository;
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+
+pub struct SqlitePromptRepository {
+    pool: SqlitePool,
+}
+
+impl SqlitePromptRepository {
+    /// Creates a SQLite-backed prompt repository.
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PromptRepository for SqlitePromptRepository {
+    async fn list_prompts(&self) -> CoreResult<Vec<JournalPrompt>> {
+        let rows: Vec<(i64, String)> =
+            sqlx::query_as("SELECT id, text FROM journal_prompts ORDER BY id")
+                .fetch_all(&self.pool)
+                .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, text): (i64, String)| JournalPrompt { id, text })
+            .collect())
+    }
+}
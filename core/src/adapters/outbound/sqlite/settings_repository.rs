@@ -1,8 +1,14 @@
 //! SQLite implementation of the settings repository port.
+use crate::domain::hydration::{
+    ActiveWindow, CatchUpPolicy, CronSchedule, QuietHours, ReminderPause, SavedReminderSettings, WeekdaySet,
+};
 use crate::error::CoreResult;
 use crate::ports::settings_repository::SettingsRepository;
 use async_trait::async_trait;
-use sqlx::SqlitePool;
+use chrono::{DateTime, NaiveDateTime, NaiveTime, Timelike, Utc};
+use sqlx::query::Query;
+use sqlx::sqlite::SqliteArguments;
+use sqlx::{Sqlite, SqlitePool};
 
 pub struct SqliteSettingsRepository {
     pool: SqlitePool,
@@ -13,38 +19,269 @@ impl SqliteSettingsRepository {
     pub fn new(pool: SqlitePool) -> Self {
         Self { pool }
     }
+
+    /// Upserts columns of the single `hydration_settings` row; `None` stores NULL.
+    async fn set_integers(&self, values: &[(&'static str, Option<i64>)]) -> CoreResult<()> {
+        let columns: Vec<&str> = values
+            .iter()
+            .map(|(column, _): &(&'static str, Option<i64>)| *column)
+            .collect();
+        let placeholders: Vec<String> = (1..=columns.len())
+            .map(|index: usize| format!("?{index}"))
+            .collect();
+        let updates: Vec<String> = columns
+            .iter()
+            .map(|column: &&str| format!("{column} = excluded.{column}"))
+            .collect();
+        let statement: String = format!(
+            r#"
+            INSERT INTO hydration_settings (id, {})
+            VALUES (1, {})
+            ON CONFLICT(id)
+            DO UPDATE SET {}
+            "#,
+            columns.join(", "),
+            placeholders.join(", "),
+            updates.join(", ")
+        );
+
+        let mut query: Query<'_, Sqlite, SqliteArguments<'_>> = sqlx::query(&statement);
+        for (_, value) in values {
+            query = query.bind(*value);
+        }
+        query.execute(&self.pool).await?;
+
+        Ok(())
+    }
+
+    /// Upserts one column of the single `hydration_settings` row.
+    async fn set_integer(&self, column: &'static str, value: i64) -> CoreResult<()> {
+        self.set_integers(&[(column, Some(value))]).await
+    }
+
+    /// Reads one column of the settings row; missing rows and NULLs are both absent.
+    async fn integer(&self, column: &'static str) -> CoreResult<Option<i64>> {
+        let statement: String = format!("SELECT {column} FROM hydration_settings WHERE id = 1");
+        let maybe_value: Option<Option<i64>> = sqlx::query_scalar::<_, Option<i64>>(&statement)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(maybe_value.flatten())
+    }
+}
+
+type ReminderSettingsRow = (
+    Option<i64>,
+    Option<String>,
+    Option<i64>,
+    Option<i64>,
+    Option<i64>,
+    Option<i64>,
+    Option<i64>,
+    Option<i64>,
+    Option<String>,
+    Option<NaiveDateTime>,
+    Option<i64>,
+);
+
+pub(super) fn minute_of_day(time: NaiveTime) -> i64 {
+    i64::from(time.hour() * 60 + time.minute())
+}
+
+/// Reads a persisted minute-of-day value as a wall-clock time.
+fn time_of_day(minute: Option<i64>) -> Option<NaiveTime> {
+    minute
+        .and_then(|minute: i64| u32::try_from(minute).ok())
+        .and_then(|minute: u32| NaiveTime::from_hms_opt(minute / 60, minute % 60, 0))
+}
+
+/// Parses the reminder columns, treating invalid or partial values as never saved.
+fn reminder_settings_from_row(row: ReminderSettingsRow) -> SavedReminderSettings {
+    let (
+        interval_minutes,
+        cron_expression,
+        active_start,
+        active_end,
+        active_days,
+        quiet_start,
+        quiet_end,
+        quiet_hours_disabled,
+        catch_up_policy,
+        paused_until,
+        paused_indefinitely,
+    ): ReminderSettingsRow = row;
+    let active_days: Option<WeekdaySet> = active_days
+        .and_then(|bits: i64| u8::try_from(bits).ok())
+        .map(WeekdaySet::from_bits);
+    let quiet_hours: Option<Option<QuietHours>> = if quiet_hours_disabled == Some(1) {
+        Some(None)
+    } else {
+        match (time_of_day(quiet_start), time_of_day(quiet_end)) {
+            (Some(start), Some(end)) => QuietHours::new(start, end).map(Some),
+            _ => None,
+        }
+    };
+
+    SavedReminderSettings {
+        interval_minutes: interval_minutes
+            .and_then(|raw: i64| u64::try_from(raw).ok())
+            .map(|value: u64| value.max(1)),
+        cron: cron_expression.and_then(|raw: String| raw.parse::<CronSchedule>().ok()),
+        active_window: match (time_of_day(active_start), time_of_day(active_end), active_days) {
+            (Some(start), Some(end), Some(days)) => ActiveWindow::new(start, end, days),
+            _ => None,
+        },
+        quiet_hours,
+        catch_up: catch_up_policy.and_then(|raw: String| raw.parse::<CatchUpPolicy>().ok()),
+        pause: match (paused_until, paused_indefinitely) {
+            (_, Some(1)) => Some(ReminderPause::Indefinite),
+            (Some(until), _) => Some(ReminderPause::Until(until.and_utc())),
+            _ => None,
+        },
+    }
 }
 
 #[async_trait]
 impl SettingsRepository for SqliteSettingsRepository {
     async fn set_hydration_interval_minutes(&self, interval_minutes: u64) -> CoreResult<()> {
+        self.set_integer(
+            "interval_minutes",
+            i64::try_from(interval_minutes).unwrap_or(i64::MAX),
+        )
+        .await
+    }
+
+    async fn set_daily_goal_ml(&self, daily_goal_ml: u32) -> CoreResult<()> {
+        self.set_integer("daily_goal_ml", i64::from(daily_goal_ml)).await
+    }
+
+    async fn daily_goal_ml(&self) -> CoreResult<Option<u32>> {
+        let maybe_goal: Option<i64> = self.integer("daily_goal_ml").await?;
+
+        Ok(maybe_goal
+            .and_then(|raw: i64| u32::try_from(raw).ok())
+            .filter(|value: &u32| *value > 0))
+    }
+
+    async fn set_day_start_hour(&self, day_start_hour: u32) -> CoreResult<()> {
+        self.set_integer("day_start_hour", i64::from(day_start_hour)).await
+    }
+
+    async fn day_start_hour(&self) -> CoreResult<Option<u32>> {
+        let maybe_hour: Option<i64> = self.integer("day_start_hour").await?;
+
+        Ok(maybe_hour
+            .and_then(|raw: i64| u32::try_from(raw).ok())
+            .filter(|value: &u32| *value <= 23))
+    }
+
+    async fn set_cron_schedule(&self, cron: Option<CronSchedule>) -> CoreResult<()> {
         sqlx::query(
             r#"
-            INSERT INTO hydration_settings (id, interval_minutes)
+            INSERT INTO hydration_settings (id, cron_expression)
             VALUES (1, ?1)
             ON CONFLICT(id)
-            DO UPDATE SET interval_minutes = excluded.interval_minutes
+            DO UPDATE SET cron_expression = excluded.cron_expression
             "#,
         )
-        .bind(i64::try_from(interval_minutes).unwrap_or(i64::MAX))
+        .bind(cron.map(|cron: CronSchedule| cron.to_string()))
         .execute(&self.pool)
         .await?;
 
         Ok(())
     }
 
-    async fn hydration_interval_minutes(&self) -> CoreResult<Option<u64>> {
-        let maybe_interval: Option<i64> = sqlx::query_scalar::<_, i64>(
-            "SELECT interval_minutes FROM hydration_settings WHERE id = 1",
+    async fn set_active_window(&self, active_window: Option<ActiveWindow>) -> CoreResult<()> {
+        let start: Option<i64> = active_window.map(|window: ActiveWindow| minute_of_day(window.start));
+        let end: Option<i64> = active_window.map(|window: ActiveWindow| minute_of_day(window.end));
+        let days: Option<i64> =
+            active_window.map(|window: ActiveWindow| i64::from(window.days.bits()));
+
+        self.set_integers(&[
+            ("active_start_minute", start),
+            ("active_end_minute", end),
+            ("active_days", days),
+        ])
+        .await
+    }
+
+    async fn set_quiet_hours(&self, quiet_hours: Option<QuietHours>) -> CoreResult<()> {
+        let start: Option<i64> = quiet_hours.map(|quiet: QuietHours| minute_of_day(quiet.start));
+        let end: Option<i64> = quiet_hours.map(|quiet: QuietHours| minute_of_day(quiet.end));
+        let disabled: Option<i64> = quiet_hours.is_none().then_some(1);
+
+        self.set_integers(&[
+            ("quiet_start_minute", start),
+            ("quiet_end_minute", end),
+            ("quiet_hours_disabled", disabled),
+        ])
+        .await
+    }
+
+    async fn clear_quiet_hours(&self) -> CoreResult<()> {
+        self.set_integers(&[
+            ("quiet_start_minute", None),
+            ("quiet_end_minute", None),
+            ("quiet_hours_disabled", None),
+        ])
+        .await
+    }
+
+    async fn set_catch_up_policy(&self, policy: CatchUpPolicy) -> CoreResult<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO hydration_settings (id, catch_up_policy)
+            VALUES (1, ?1)
+            ON CONFLICT(id)
+            DO UPDATE SET catch_up_policy = excluded.catch_up_policy
+            "#,
         )
-        .fetch_optional(&self.pool)
+        .bind(policy.as_str())
+        .execute(&self.pool)
         .await?;
 
-        // Treat invalid persisted values as absent so callers fall back to defaults.
-        let interval: Option<u64> = maybe_interval
-            .and_then(|raw: i64| u64::try_from(raw).ok())
-            .map(|value: u64| value.max(1));
+        Ok(())
+    }
+
+    async fn set_reminder_pause(&self, pause: Option<ReminderPause>) -> CoreResult<()> {
+        let until: Option<NaiveDateTime> = pause
+            .and_then(ReminderPause::ends_at)
+            .map(|until: DateTime<Utc>| until.naive_utc());
+        let indefinitely: Option<i64> = pause.map(|pause: ReminderPause| i64::from(pause == ReminderPause::Indefinite));
+
+        sqlx::query(
+            r#"
+            INSERT INTO hydration_settings (id, paused_until, paused_indefinitely)
+            VALUES (1, ?1, ?2)
+            ON CONFLICT(id)
+            DO UPDATE SET
+                paused_until = excluded.paused_until,
+                paused_indefinitely = excluded.paused_indefinitely
+            "#,
+        )
+        .bind(until)
+        .bind(indefinitely)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn reminder_settings(&self) -> CoreResult<SavedReminderSettings> {
+        let maybe_row: Option<ReminderSettingsRow> = sqlx::query_as(
+            r#"
+            SELECT
+                interval_minutes, cron_expression,
+                active_start_minute, active_end_minute, active_days,
+                quiet_start_minute, quiet_end_minute, quiet_hours_disabled,
+                catch_up_policy, paused_until, paused_indefinitely
+            FROM hydration_settings
+            WHERE id = 1
+            "#,
+        )
+        .fetch_optional(&self.pool)
+        .await?;
 
-        Ok(interval)
+        Ok(maybe_row.map(reminder_settings_from_row).unwrap_or_default())
     }
 }
@@ -0,0 +1,207 @@
+//! SQLite implementation of the water intake repository port.
+use super::history::{bind_page, page_sql};
+use crate::domain::history::{HistoryPage, HistoryQuery};
+use crate::domain::hydration::{IntakeSource, WaterIntake};
+use crate::domain::stats::{DailyIntake, HourlyIntake};
+use crate::error::CoreResult;
+use crate::ports::water_intake_repository::WaterIntakeRepository;
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use sqlx::pool::PoolConnection;
+use sqlx::{Sqlite, SqliteConnection, SqlitePool};
+
+pub(super) type WaterIntakeRow = (i64, i64, NaiveDateTime, Option<String>);
+
+pub struct SqliteWaterIntakeRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteWaterIntakeRepository {
+    /// Creates a SQLite-backed water intake repository.
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+pub(super) fn intake_from_row(row: WaterIntakeRow) -> WaterIntake {
+    let (id, amount_ml, consumed_at, source): WaterIntakeRow = row;
+    WaterIntake {
+        id,
+        amount_ml: u32::try_from(amount_ml).unwrap_or(0),
+        consumed_at: consumed_at.and_utc(),
+        // Unknown names written by newer builds degrade to `Other` instead of failing reads.
+        source: source.map(|raw: String| raw.parse::<IntakeSource>().unwrap_or(IntakeSource::Other)),
+    }
+}
+
+/// Inserts one drink on `conn`, so callers can log it inside their own transaction.
+pub(super) async fn insert_intake(
+    conn: &mut SqliteConnection,
+    amount_ml: u32,
+    consumed_at: DateTime<Utc>,
+    source: Option<IntakeSource>,
+) -> CoreResult<WaterIntake> {
+    let row: WaterIntakeRow = sqlx::query_as(
+        r#"
+        INSERT INTO water_intake_logs (amount_ml, consumed_at, source)
+        VALUES (?1, ?2, ?3)
+        RETURNING id, amount_ml, consumed_at, source
+        "#,
+    )
+    .bind(i64::from(amount_ml))
+    .bind(consumed_at.naive_utc())
+    .bind(source.map(IntakeSource::as_str))
+    .fetch_one(conn)
+    .await?;
+
+    Ok(intake_from_row(row))
+}
+
+/// SQLite date modifier that shifts local time so each hydration day starts at midnight.
+///
+/// Together with `'localtime'` this matches `domain::hydration::hydration_day`.
+fn day_start_modifier(day_start_hour: u32) -> String {
+    format!("-{} hours", day_start_hour.min(23))
+}
+
+#[async_trait]
+impl WaterIntakeRepository for SqliteWaterIntakeRepository {
+    async fn insert_water_intake(
+        &self,
+        amount_ml: u32,
+        consumed_at: DateTime<Utc>,
+        source: Option<IntakeSource>,
+    ) -> CoreResult<WaterIntake> {
+        let mut conn: PoolConnection<Sqlite> = self.pool.acquire().await?;
+        insert_intake(&mut conn, amount_ml, consumed_at, source).await
+    }
+
+    async fn delete_latest_water_intake(&self) -> CoreResult<Option<WaterIntake>> {
+        let maybe_row: Option<WaterIntakeRow> = sqlx::query_as(
+            r#"
+            DELETE FROM water_intake_logs
+            WHERE id = (SELECT MAX(id) FROM water_intake_logs)
+            RETURNING id, amount_ml, consumed_at, source
+            "#,
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(maybe_row.map(intake_from_row))
+    }
+
+    async fn total_intake_ml_between(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> CoreResult<u64> {
+        let total: i64 = sqlx::query_scalar(
+            r#"
+            SELECT COALESCE(SUM(amount_ml), 0)
+            FROM water_intake_logs
+            WHERE consumed_at >= ?1 AND consumed_at < ?2
+            "#,
+        )
+        .bind(start.naive_utc())
+        .bind(end.naive_utc())
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(u64::try_from(total).unwrap_or(0))
+    }
+
+    async fn list_water_intake(&self, query: &HistoryQuery) -> CoreResult<HistoryPage<WaterIntake>> {
+        let sql: String = page_sql(
+            "id, amount_ml, consumed_at, source",
+            "water_intake_logs",
+            "consumed_at",
+            query.order,
+        );
+        let rows: Vec<WaterIntakeRow> = bind_page(sqlx::query_as(&sql), query)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(HistoryPage::from_rows(
+            rows.into_iter().map(intake_from_row).collect(),
+            query.page_size(),
+            WaterIntake::cursor,
+        ))
+    }
+
+    async fn daily_intake_totals(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        day_start_hour: u32,
+    ) -> CoreResult<Vec<DailyIntake>> {
+        let rows: Vec<(NaiveDate, i64)> = sqlx::query_as(
+            r#"
+            SELECT date(consumed_at, 'localtime', ?3) AS day, SUM(amount_ml)
+            FROM water_intake_logs
+            WHERE consumed_at >= ?1 AND consumed_at < ?2
+            GROUP BY day
+            ORDER BY day
+            "#,
+        )
+        .bind(start.naive_utc())
+        .bind(end.naive_utc())
+        .bind(day_start_modifier(day_start_hour))
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(day, total_ml): (NaiveDate, i64)| DailyIntake {
+                day,
+                total_ml: u64::try_from(total_ml).unwrap_or(0),
+            })
+            .collect())
+    }
+
+    async fn goal_met_days(
+        &self,
+        goal_ml: u32,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        day_start_hour: u32,
+    ) -> CoreResult<Vec<NaiveDate>> {
+        let days: Vec<NaiveDate> = sqlx::query_scalar(
+            r#"
+            SELECT date(consumed_at, 'localtime', ?4) AS day
+            FROM water_intake_logs
+            WHERE consumed_at >= ?2 AND consumed_at < ?3
+            GROUP BY day
+            HAVING SUM(amount_ml) >= ?1
+            ORDER BY day
+            "#,
+        )
+        .bind(i64::from(goal_ml))
+        .bind(start.naive_utc())
+        .bind(end.naive_utc())
+        .bind(day_start_modifier(day_start_hour))
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(days)
+    }
+
+    async fn intake_by_hour(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> CoreResult<Vec<HourlyIntake>> {
+        let rows: Vec<(i64, i64)> = sqlx::query_as(
+            r#"
+            SELECT CAST(strftime('%H', consumed_at, 'localtime') AS INTEGER) AS hour, SUM(amount_ml)
+            FROM water_intake_logs
+            WHERE consumed_at >= ?1 AND consumed_at < ?2
+            GROUP BY hour
+            ORDER BY hour
+            "#,
+        )
+        .bind(start.naive_utc())
+        .bind(end.naive_utc())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(hour, total_ml): (i64, i64)| HourlyIntake {
+                hour: u32::try_from(hour).unwrap_or(0),
+                total_ml: u64::try_from(total_ml).unwrap_or(0),
+            })
+            .collect())
+    }
+}
@@ -1,36 +1,89 @@
 //! Pure core API that delegates to application services and ports.
+use crate::application::achievement_service::AchievementService;
+use crate::application::export_service::ExportService;
 use crate::application::hydration_service::HydrationService;
+use crate::application::import_service::ImportService;
+use crate::application::intake_service::IntakeService;
+use crate::application::journal_service::JournalService;
+use crate::application::reminder_service::ReminderService;
 use crate::application::settings_service::SettingsService;
-use crate::domain::hydration::ReminderConfig;
-use crate::error::CoreResult;
-use crate::ports::reminder_event_sink::ReminderEventSink;
-use std::sync::Arc;
+use crate::application::stats_service::StatsService;
+use crate::domain::achievements::Achievement;
+use crate::domain::export::{ExportSettings, ExportSummary, ExportTable, ImportDocument, ImportStrategy, ImportSummary};
+use crate::domain::history::{HistoryPage, HistoryQuery, ReminderLogEntry};
+use crate::domain::hydration::{
+    is_valid_pause_minutes, ActiveWindow, CatchUpPolicy, CronSchedule, HydrationGoal, HydrationProgress,
+    IntakeSource, PauseLength, QuietHours, ReminderConfig, ReminderEvent, ReminderKind, ReminderPause,
+    ReminderResponse, WaterIntake,
+};
+use crate::domain::journal::{EntryDate, JournalEntry, JournalPrompt};
+use crate::domain::stats::{HydrationStats, StatsPeriod};
+use crate::error::{CoreError, CoreResult};
+use crate::ports::export_writer::ExportWriter;
+use chrono::{DateTime, Local, Utc};
 
 pub struct CoreApi {
     hydration_service: HydrationService,
     settings_service: SettingsService,
-    reminder_sink: Arc<dyn ReminderEventSink>,
+    intake_service: IntakeService,
+    journal_service: JournalService,
+    reminder_service: ReminderService,
+    stats_service: StatsService,
+    achievement_service: AchievementService,
+    export_service: ExportService,
+    import_service: ImportService,
 }
 
 impl CoreApi {
     /// Creates a core API from already-wired services and output ports.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         hydration_service: HydrationService,
         settings_service: SettingsService,
-        reminder_sink: Arc<dyn ReminderEventSink>,
+        intake_service: IntakeService,
+        journal_service: JournalService,
+        reminder_service: ReminderService,
+        stats_service: StatsService,
+        achievement_service: AchievementService,
+        export_service: ExportService,
+        import_service: ImportService,
     ) -> Self {
         Self {
             hydration_service,
             settings_service,
-            reminder_sink,
+            intake_service,
+            journal_service,
+            reminder_service,
+            stats_service,
+            achievement_service,
+            export_service,
+            import_service,
         }
     }
 
     /// Triggers one hydration reminder and emits it through the event sink.
-    pub async fn trigger_hydration_reminder_once(&self) -> CoreResult<String> {
-        let message: String = self.hydration_service.trigger_reminder().await?;
-        self.reminder_sink.emit_hydration_reminder(&message).await?;
-        Ok(message)
+    pub async fn trigger_hydration_reminder_once(&self) -> CoreResult<ReminderEvent> {
+        self.reminder_service.remind(ReminderKind::Manual).await
+    }
+
+    /// Records the user's response to a reminder.
+    ///
+    /// `Drank` with an amount also logs intake, in the same transaction as the response,
+    /// and checks achievements; `Snoozed` reschedules the next reminder of a running scheduler.
+    pub async fn respond_to_hydration_reminder(
+        &self,
+        reminder_id: i64,
+        response: ReminderResponse,
+    ) -> CoreResult<()> {
+        self.hydration_service
+            .respond_to_reminder(reminder_id, response)
+            .await?;
+
+        if let ReminderResponse::Drank { amount_ml: Some(_) } = response {
+            self.check_achievements().await;
+        }
+
+        Ok(())
     }
 
     /// Returns the number of hydration reminder log entries.
@@ -38,6 +91,13 @@ impl CoreApi {
         self.hydration_service.reminder_count().await
     }
 
+    /// Lists logged reminders with their answers, one page at a time.
+    ///
+    /// Pass the returned `next_cursor` as `query.after` to fetch the following page.
+    pub async fn hydration_reminder_history(&self, query: HistoryQuery) -> CoreResult<HistoryPage<ReminderLogEntry>> {
+        self.hydration_service.reminder_history(query).await
+    }
+
     /// Persists hydration interval minutes and returns the normalized value.
     pub async fn set_hydration_interval_minutes(&self, interval_minutes: u64) -> CoreResult<u64> {
         let config: ReminderConfig = self
@@ -47,16 +107,211 @@ impl CoreApi {
         Ok(config.interval_minutes)
     }
 
+    /// Restricts reminders to a daily active window, or lifts the restriction with `None`.
+    pub async fn set_hydration_active_window(
+        &self,
+        active_window: Option<ActiveWindow>,
+    ) -> CoreResult<ReminderConfig> {
+        self.settings_service.set_active_window(active_window).await
+    }
+
+    /// Schedules reminders with a five-field cron expression (for example
+    /// `"*/45 9-16 * * mon-fri"`), or returns to the fixed interval with `None`.
+    pub async fn set_hydration_cron_schedule(&self, expression: Option<&str>) -> CoreResult<ReminderConfig> {
+        let cron: Option<CronSchedule> = expression
+            .map(|raw: &str| raw.parse::<CronSchedule>())
+            .transpose()?;
+        self.settings_service.set_cron_schedule(cron).await
+    }
+
+    /// Silences reminders during daily quiet hours, or turns them off with `None`.
+    pub async fn set_hydration_quiet_hours(
+        &self,
+        quiet_hours: Option<QuietHours>,
+    ) -> CoreResult<ReminderConfig> {
+        self.settings_service.set_quiet_hours(quiet_hours).await
+    }
+
+    /// Forgets saved quiet hours so the configured default quiet hours apply again.
+    pub async fn reset_hydration_quiet_hours(&self) -> CoreResult<ReminderConfig> {
+        self.settings_service.reset_quiet_hours().await
+    }
+
+    /// Chooses what happens to reminders missed while the machine slept.
+    pub async fn set_hydration_catch_up_policy(&self, policy: CatchUpPolicy) -> CoreResult<ReminderConfig> {
+        self.settings_service.set_catch_up_policy(policy).await
+    }
+
+    /// Pauses reminders for a number of minutes, until the next hydration day, or
+    /// until resumed; the pause is saved so it survives restarts.
+    pub async fn pause_hydration_reminders(&self, length: PauseLength) -> CoreResult<ReminderPause> {
+        let goal: HydrationGoal = self.settings_service.hydration_goal().await?;
+        let now: DateTime<Utc> = self.hydration_service.clock().now();
+        if let PauseLength::Minutes(minutes) = length
+            && !is_valid_pause_minutes(minutes)
+        {
+            return Err(CoreError::InvalidPauseMinutes(minutes));
+        }
+        let pause: ReminderPause = ReminderPause::starting_at(length, now, &Local, goal.day_start_hour);
+        let _: ReminderConfig = self.settings_service.set_reminder_pause(Some(pause)).await?;
+        Ok(pause)
+    }
+
+    /// Lifts any pause so reminders resume.
+    pub async fn resume_hydration_reminders(&self) -> CoreResult<ReminderConfig> {
+        self.settings_service.set_reminder_pause(None).await
+    }
+
+    /// Returns the pause in effect now, if any; expired pauses read as `None`.
+    pub async fn hydration_reminder_pause(&self) -> CoreResult<Option<ReminderPause>> {
+        let config: ReminderConfig = self.settings_service.hydration_reminder_config().await?;
+        Ok(config.active_pause(self.hydration_service.clock().now()))
+    }
+
     /// Reads the effective hydration reminder configuration.
     pub async fn hydration_reminder_config(&self) -> CoreResult<ReminderConfig> {
         self.settings_service.hydration_reminder_config().await
     }
 
+    /// Records water the user drank now, in millilitres, then checks achievements.
+    pub async fn log_water_intake(&self, amount_ml: u32, source: Option<IntakeSource>) -> CoreResult<WaterIntake> {
+        let intake: WaterIntake = self.intake_service.log_intake(amount_ml, source).await?;
+        self.check_achievements().await;
+        Ok(intake)
+    }
+
+    /// Removes the most recently logged drink, returning it when one existed.
+    pub async fn undo_last_water_intake(&self) -> CoreResult<Option<WaterIntake>> {
+        self.intake_service.undo_last_intake().await
+    }
+
+    /// Lists logged drinks, one page at a time; see `hydration_reminder_history`.
+    pub async fn water_intake_history(&self, query: HistoryQuery) -> CoreResult<HistoryPage<WaterIntake>> {
+        self.intake_service.intake_history(query).await
+    }
+
+    /// Returns total millilitres logged during the current local hydration day.
+    pub async fn water_intake_today_ml(&self) -> CoreResult<u64> {
+        let goal: HydrationGoal = self.settings_service.hydration_goal().await?;
+        self.intake_service.total_today_ml(goal.day_start_hour).await
+    }
+
+    /// Persists the daily intake goal and returns the normalized value.
+    pub async fn set_daily_hydration_goal_ml(&self, daily_goal_ml: u32) -> CoreResult<u32> {
+        let goal: HydrationGoal = self.settings_service.set_daily_goal_ml(daily_goal_ml).await?;
+        Ok(goal.daily_goal_ml)
+    }
+
+    /// Persists the local hour (0-23) at which a new hydration day begins.
+    pub async fn set_hydration_day_start_hour(&self, day_start_hour: u32) -> CoreResult<u32> {
+        let goal: HydrationGoal = self.settings_service.set_day_start_hour(day_start_hour).await?;
+        Ok(goal.day_start_hour)
+    }
+
+    /// Reads the effective daily goal and day start hour.
+    pub async fn hydration_goal(&self) -> CoreResult<HydrationGoal> {
+        self.settings_service.hydration_goal().await
+    }
+
+    /// Returns consumed intake versus goal for the current local hydration day.
+    pub async fn hydration_progress_today(&self) -> CoreResult<HydrationProgress> {
+        let goal: HydrationGoal = self.settings_service.hydration_goal().await?;
+        self.intake_service.progress_today(goal).await
+    }
+
+    /// Lists every achievement with progress towards it and when it unlocked.
+    pub async fn achievements(&self) -> CoreResult<Vec<Achievement>> {
+        let goal: HydrationGoal = self.settings_service.hydration_goal().await?;
+        self.achievement_service.achievements(goal).await
+    }
+
+    /// Unlocks achievements whose rules are now met, announcing each through the event
+    /// sink, and returns them. Logging drinks and saving journal entries call this.
+    pub async fn unlock_new_achievements(&self) -> CoreResult<Vec<Achievement>> {
+        let goal: HydrationGoal = self.settings_service.hydration_goal().await?;
+        self.achievement_service.unlock_new(goal).await
+    }
+
+    /// Unlocks achievements after a write, logging failures: the write itself succeeded
+    /// and the next write checks again.
+    async fn check_achievements(&self) {
+        if let Err(err) = self.unlock_new_achievements().await {
+            tracing::warn!("failed to check achievements: {err}");
+        }
+    }
+
+    /// Summarizes intake, goal streaks and reminder responses for the hydration days
+    /// of `period` ending today, judged against the current goal.
+    pub async fn hydration_stats(&self, period: StatsPeriod) -> CoreResult<HydrationStats> {
+        let goal: HydrationGoal = self.settings_service.hydration_goal().await?;
+        self.stats_service.stats(period, goal).await
+    }
+
+    /// Returns the journal prompt assigned to today's local date.
+    pub async fn todays_journal_prompt(&self) -> CoreResult<Option<JournalPrompt>> {
+        self.journal_service.prompt_for_date(self.today()).await
+    }
+
+    /// Saves or updates today's journal entry, then checks achievements.
+    pub async fn save_todays_journal_entry(&self, body: &str) -> CoreResult<JournalEntry> {
+        self.save_journal_entry(self.today(), body).await
+    }
+
+    /// Saves or updates the journal entry for a specific date, then checks achievements.
+    pub async fn save_journal_entry(&self, entry_date: EntryDate, body: &str) -> CoreResult<JournalEntry> {
+        let entry: JournalEntry = self.journal_service.save_entry(entry_date, body).await?;
+        self.check_achievements().await;
+        Ok(entry)
+    }
+
+    /// Loads the journal entry written for a date, if any.
+    pub async fn journal_entry(&self, entry_date: EntryDate) -> CoreResult<Option<JournalEntry>> {
+        self.journal_service.entry_for_date(entry_date).await
+    }
+
+    /// Lists past journal entries, newest first.
+    pub async fn journal_entries(&self, limit: u32) -> CoreResult<Vec<JournalEntry>> {
+        self.journal_service.recent_entries(limit).await
+    }
+
+    /// Streams current settings and every record of `tables` to `writer`, oldest first,
+    /// and returns how many records each table had.
+    ///
+    /// Pass `ExportTable::ALL` for a full export; `writer` picks the format, for example
+    /// `JsonExportWriter` or `CsvExportWriter` from `adapters::outbound::export`.
+    pub async fn export(&self, tables: &[ExportTable], writer: &mut dyn ExportWriter) -> CoreResult<ExportSummary> {
+        let settings: ExportSettings = ExportSettings {
+            reminder_config: self.settings_service.hydration_reminder_config().await?,
+            goal: self.settings_service.hydration_goal().await?,
+        };
+        self.export_service.export(&settings, tables, writer).await
+    }
+
+    /// Merges an export read back with `adapters::outbound::export::read_json_export`,
+    /// then checks achievements; returns inserted, skipped and conflicting counts.
+    ///
+    /// A record matches a stored one by reminder time, drink time or journal date;
+    /// `strategy` decides what happens when their content differs.
+    pub async fn import(&self, document: &ImportDocument, strategy: ImportStrategy) -> CoreResult<ImportSummary> {
+        let summary: ImportSummary = self.import_service.import(document, strategy).await?;
+        self.check_achievements().await;
+        Ok(summary)
+    }
+
     pub(crate) fn hydration_service(&self) -> HydrationService {
         self.hydration_service.clone()
     }
 
-    pub(crate) fn reminder_sink(&self) -> Arc<dyn ReminderEventSink> {
-        self.reminder_sink.clone()
+    /// Returns the local calendar date at the injected clock's current time.
+    fn today(&self) -> EntryDate {
+        EntryDate::new(self.hydration_service.clock().now().with_timezone(&Local).date_naive())
+    }
+
+    pub(crate) fn settings_service(&self) -> SettingsService {
+        self.settings_service.clone()
+    }
+
+    pub(crate) fn reminder_service(&self) -> ReminderService {
+        self.reminder_service.clone()
     }
 }
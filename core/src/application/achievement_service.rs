@@ -0,0 +1,102 @@
+//! Achievement use cases: measuring progress, recording unlocks and announcing them.
+use crate::application::stats_service::goal_days_since;
+use crate::domain::achievements::{goal_lookback_days, Achievement, AchievementTotals, AchievementUnlock};
+use crate::domain::hydration::{day_bounds, hydration_day, HydrationGoal};
+use crate::domain::stats::GoalStreaks;
+use crate::error::CoreResult;
+use crate::ports::achievement_repository::AchievementRepository;
+use crate::ports::clock::Clock;
+use crate::ports::journal_entry_repository::JournalEntryRepository;
+use crate::ports::reminder_event_sink::ReminderEventSink;
+use crate::ports::water_intake_repository::WaterIntakeRepository;
+use chrono::{DateTime, Days, Local, NaiveDate, Utc};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct AchievementService {
+    unlocks: Arc<dyn AchievementRepository>,
+    intake: Arc<dyn WaterIntakeRepository>,
+    journal: Arc<dyn JournalEntryRepository>,
+    sink: Arc<dyn ReminderEventSink>,
+    clock: Arc<dyn Clock>,
+}
+
+impl AchievementService {
+    /// Builds the achievement service over the data it measures and the sink it announces to.
+    pub fn new(
+        unlocks: Arc<dyn AchievementRepository>,
+        intake: Arc<dyn WaterIntakeRepository>,
+        journal: Arc<dyn JournalEntryRepository>,
+        sink: Arc<dyn ReminderEventSink>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            unlocks,
+            intake,
+            journal,
+            sink,
+            clock,
+        }
+    }
+
+    /// Returns every achievement with the user's progress and unlock time.
+    pub async fn achievements(&self, goal: HydrationGoal) -> CoreResult<Vec<Achievement>> {
+        let totals: AchievementTotals = self.totals(goal).await?;
+        let unlocks: Vec<AchievementUnlock> = self.unlocks.achievement_unlocks().await?;
+        Ok(Achievement::all(&totals, &unlocks))
+    }
+
+    /// Unlocks every achievement whose rule is now met and announces each one through
+    /// the sink; returns the newly unlocked achievements.
+    ///
+    /// Unlocks are stored before they are announced, so a failing sink is logged
+    /// instead of failing the caller.
+    pub async fn unlock_new(&self, goal: HydrationGoal) -> CoreResult<Vec<Achievement>> {
+        let totals: AchievementTotals = self.totals(goal).await?;
+        let unlocks: Vec<AchievementUnlock> = self.unlocks.achievement_unlocks().await?;
+        let now: DateTime<Utc> = self.clock.now();
+
+        let mut unlocked: Vec<Achievement> = Vec::new();
+        for achievement in Achievement::all(&totals, &unlocks) {
+            if achievement.is_unlocked() || !achievement.rule.is_met(&totals) {
+                continue;
+            }
+            if !self.unlocks.insert_achievement_unlock(achievement.rule.id, now).await? {
+                continue;
+            }
+
+            let achievement: Achievement = Achievement {
+                unlocked_at: Some(now),
+                ..achievement
+            };
+            if let Err(err) = self.sink.emit_achievement(&achievement).await {
+                tracing::warn!(achievement = achievement.rule.id, "failed to announce achievement: {err}");
+            }
+            unlocked.push(achievement);
+        }
+        Ok(unlocked)
+    }
+
+    /// Measures every metric the rules count, with hydration days judged against `goal`.
+    ///
+    /// Only the last `goal_lookback_days()` days and any streak running into them are read;
+    /// older goal days were measured when they were logged.
+    async fn totals(&self, goal: HydrationGoal) -> CoreResult<AchievementTotals> {
+        let now: DateTime<Utc> = self.clock.now();
+        let today: NaiveDate = hydration_day(now, &Local, goal.day_start_hour);
+        let (_today_start, end): (DateTime<Utc>, DateTime<Utc>) = day_bounds(now, &Local, goal.day_start_hour);
+        let first_day: NaiveDate = today
+            .checked_sub_days(Days::new(u64::from(goal_lookback_days().saturating_sub(1))))
+            .unwrap_or(NaiveDate::MIN);
+        let goal_days: Vec<NaiveDate> = goal_days_since(self.intake.as_ref(), goal, first_day, end).await?;
+        let streaks: GoalStreaks = GoalStreaks::from_goal_days(&goal_days, today);
+        let journal_entries: u64 = self.journal.journal_entry_count().await?;
+
+        Ok(AchievementTotals {
+            longest_goal_streak_days: streaks.longest_days,
+            goal_days: u32::try_from(goal_days.iter().filter(|day: &&NaiveDate| **day <= today).count())
+                .unwrap_or(u32::MAX),
+            journal_entries: u32::try_from(journal_entries).unwrap_or(u32::MAX),
+        })
+    }
+}
@@ -0,0 +1,124 @@
+//! Export use case that streams settings and recorded history page by page.
+use crate::domain::export::{ExportSettings, ExportSummary, ExportTable};
+use crate::domain::history::{HistoryCursor, HistoryPage, HistoryQuery, ReminderLogEntry, SortOrder, MAX_HISTORY_PAGE_SIZE};
+use crate::domain::hydration::WaterIntake;
+use crate::domain::journal::{EntryDate, JournalEntry};
+use crate::error::CoreResult;
+use crate::ports::clock::Clock;
+use crate::ports::export_writer::ExportWriter;
+use crate::ports::hydration_log_repository::HydrationLogRepository;
+use crate::ports::journal_entry_repository::JournalEntryRepository;
+use crate::ports::water_intake_repository::WaterIntakeRepository;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct ExportService {
+    log_repository: Arc<dyn HydrationLogRepository>,
+    intake_repository: Arc<dyn WaterIntakeRepository>,
+    journal_repository: Arc<dyn JournalEntryRepository>,
+    clock: Arc<dyn Clock>,
+}
+
+impl ExportService {
+    /// Builds the export service over the repositories whose records it writes.
+    pub fn new(
+        log_repository: Arc<dyn HydrationLogRepository>,
+        intake_repository: Arc<dyn WaterIntakeRepository>,
+        journal_repository: Arc<dyn JournalEntryRepository>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            log_repository,
+            intake_repository,
+            journal_repository,
+            clock,
+        }
+    }
+
+    /// Writes `settings` and then every record of `tables`, oldest first, to `writer`.
+    ///
+    /// Records are read `MAX_HISTORY_PAGE_SIZE` at a time, so only one page is held
+    /// in memory however long the history is.
+    pub async fn export(
+        &self,
+        settings: &ExportSettings,
+        tables: &[ExportTable],
+        writer: &mut dyn ExportWriter,
+    ) -> CoreResult<ExportSummary> {
+        writer.begin(self.clock.now(), settings)?;
+
+        let mut summary: ExportSummary = ExportSummary::default();
+        for &table in ExportTable::ALL.iter().filter(|table: &&ExportTable| tables.contains(table)) {
+            writer.begin_table(table)?;
+            *summary.count_mut(table) = match table {
+                ExportTable::ReminderLogs => self.export_reminder_logs(writer).await?,
+                ExportTable::WaterIntake => self.export_water_intake(writer).await?,
+                ExportTable::JournalEntries => self.export_journal_entries(writer).await?,
+            };
+        }
+
+        writer.finish()?;
+        Ok(summary)
+    }
+
+    async fn export_reminder_logs(&self, writer: &mut dyn ExportWriter) -> CoreResult<u64> {
+        let mut count: u64 = 0;
+        let mut after: Option<HistoryCursor> = None;
+        loop {
+            let page: HistoryPage<ReminderLogEntry> = self.log_repository.list_hydration_logs(&page_query(after)).await?;
+            for entry in &page.items {
+                writer.reminder_log(entry)?;
+                count += 1;
+            }
+            if page.next_cursor.is_none() {
+                return Ok(count);
+            }
+            after = page.next_cursor;
+        }
+    }
+
+    async fn export_water_intake(&self, writer: &mut dyn ExportWriter) -> CoreResult<u64> {
+        let mut count: u64 = 0;
+        let mut after: Option<HistoryCursor> = None;
+        loop {
+            let page: HistoryPage<WaterIntake> = self.intake_repository.list_water_intake(&page_query(after)).await?;
+            for intake in &page.items {
+                writer.water_intake(intake)?;
+                count += 1;
+            }
+            if page.next_cursor.is_none() {
+                return Ok(count);
+            }
+            after = page.next_cursor;
+        }
+    }
+
+    async fn export_journal_entries(&self, writer: &mut dyn ExportWriter) -> CoreResult<u64> {
+        let mut count: u64 = 0;
+        let mut after: Option<EntryDate> = None;
+        loop {
+            let entries: Vec<JournalEntry> = self
+                .journal_repository
+                .list_journal_entries_after(after, MAX_HISTORY_PAGE_SIZE)
+                .await?;
+            for entry in &entries {
+                writer.journal_entry(entry)?;
+                count += 1;
+            }
+            if entries.len() < MAX_HISTORY_PAGE_SIZE as usize {
+                return Ok(count);
+            }
+            after = entries.last().map(|entry: &JournalEntry| entry.entry_date);
+        }
+    }
+}
+
+/// Query for the full history, oldest first, continuing after `after`.
+fn page_query(after: Option<HistoryCursor>) -> HistoryQuery {
+    HistoryQuery {
+        order: SortOrder::OldestFirst,
+        limit: MAX_HISTORY_PAGE_SIZE,
+        after,
+        ..HistoryQuery::default()
+    }
+}
@@ -1,30 +1,109 @@
 //! Hydration reminder use cases built on repository port abstractions.
-use crate::domain::hydration::DEFAULT_REMINDER_MESSAGE;
-use crate::error::CoreResult;
+use crate::domain::history::{HistoryPage, HistoryQuery, ReminderLogEntry};
+use crate::domain::hydration::{
+    is_valid_intake_amount, HydrationReminder, ReminderResponse, SnoozeRequest,
+    DEFAULT_REMINDER_MESSAGE, MAX_SNOOZE_MINUTES,
+};
+use crate::error::{CoreError, CoreResult};
+use crate::ports::clock::Clock;
 use crate::ports::hydration_log_repository::HydrationLogRepository;
+use chrono::{DateTime, Utc};
 use std::sync::Arc;
+use tokio::sync::watch;
 
 #[derive(Clone)]
 pub struct HydrationService {
     repository: Arc<dyn HydrationLogRepository>,
+    clock: Arc<dyn Clock>,
+    snooze_tx: Arc<watch::Sender<Option<SnoozeRequest>>>,
 }
 
 impl HydrationService {
     /// Builds the hydration use-case service with a log repository implementation.
-    pub fn new(repository: Arc<dyn HydrationLogRepository>) -> Self {
-        Self { repository }
+    pub fn new(repository: Arc<dyn HydrationLogRepository>, clock: Arc<dyn Clock>) -> Self {
+        let (snooze_tx, _snooze_rx): (
+            watch::Sender<Option<SnoozeRequest>>,
+            watch::Receiver<Option<SnoozeRequest>>,
+        ) = watch::channel(None);
+
+        Self {
+            repository,
+            clock,
+            snooze_tx: Arc::new(snooze_tx),
+        }
     }
 
-    /// Records one hydration reminder event and returns the emitted message.
-    pub async fn trigger_reminder(&self) -> CoreResult<String> {
+    /// Records one hydration reminder event and returns it with its ID.
+    pub async fn trigger_reminder(&self) -> CoreResult<HydrationReminder> {
         self.repository
-            .insert_hydration_log(DEFAULT_REMINDER_MESSAGE)
-            .await?;
-        Ok(DEFAULT_REMINDER_MESSAGE.to_string())
+            .insert_hydration_log(DEFAULT_REMINDER_MESSAGE, self.clock.now())
+            .await
     }
 
     /// Returns how many hydration reminders have been logged.
     pub async fn reminder_count(&self) -> CoreResult<i64> {
         self.repository.hydration_log_count().await
     }
+
+    /// Returns when the most recent reminder fired, if any has.
+    pub async fn last_reminder_at(&self) -> CoreResult<Option<DateTime<Utc>>> {
+        self.repository.latest_reminder_at().await
+    }
+
+    /// Lists logged reminders and their answers for one page of `query`.
+    pub async fn reminder_history(&self, query: HistoryQuery) -> CoreResult<HistoryPage<ReminderLogEntry>> {
+        if let (Some(from), Some(until)) = (query.from, query.until)
+            && !query.has_valid_range()
+        {
+            return Err(CoreError::InvalidHistoryRange { from, until });
+        }
+        self.repository.list_hydration_logs(&query).await
+    }
+
+    /// Persists the user's response to a reminder and publishes snoozes to schedulers.
+    ///
+    /// Snooze lengths and drink amounts are validated before anything is saved.
+    pub async fn respond_to_reminder(
+        &self,
+        reminder_id: i64,
+        response: ReminderResponse,
+    ) -> CoreResult<()> {
+        match response {
+            ReminderResponse::Snoozed { minutes } if minutes == 0 || minutes > MAX_SNOOZE_MINUTES => {
+                return Err(CoreError::InvalidSnoozeMinutes(minutes));
+            }
+            ReminderResponse::Drank {
+                amount_ml: Some(amount_ml),
+            } if !is_valid_intake_amount(amount_ml) => {
+                return Err(CoreError::InvalidIntakeAmount(amount_ml));
+            }
+            _ => {}
+        }
+
+        let recorded: bool = self
+            .repository
+            .record_reminder_response(reminder_id, response, self.clock.now())
+            .await?;
+        if !recorded {
+            return Err(CoreError::ReminderNotFound(reminder_id));
+        }
+
+        if let Some(delay) = response.snooze_duration() {
+            let _previous: Option<SnoozeRequest> = self
+                .snooze_tx
+                .send_replace(Some(SnoozeRequest { reminder_id, delay }));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the wall clock used to timestamp reminders.
+    pub fn clock(&self) -> Arc<dyn Clock> {
+        self.clock.clone()
+    }
+
+    /// Subscribes to snooze requests; only requests published after this call are seen.
+    pub fn subscribe_snoozes(&self) -> watch::Receiver<Option<SnoozeRequest>> {
+        self.snooze_tx.subscribe()
+    }
 }
@@ -0,0 +1,54 @@
+//! Import use case that merges an exported document into the current database.
+use crate::application::settings_service::SettingsService;
+use crate::domain::export::{
+    ExportSettings, ImportAction, ImportCounts, ImportDocument, ImportStrategy, ImportSummary, RecordMatch,
+};
+use crate::domain::hydration::ReminderConfig;
+use crate::error::CoreResult;
+use crate::ports::import_repository::ImportRepository;
+use std::sync::Arc;
+use tokio::sync::MutexGuard;
+
+#[derive(Clone)]
+pub struct ImportService {
+    repository: Arc<dyn ImportRepository>,
+    settings_service: SettingsService,
+}
+
+impl ImportService {
+    /// Builds the import service over the record store and the settings it may replace.
+    pub fn new(repository: Arc<dyn ImportRepository>, settings_service: SettingsService) -> Self {
+        Self {
+            repository,
+            settings_service,
+        }
+    }
+
+    /// Merges every record of `document` and its settings with `strategy`.
+    ///
+    /// Records and settings are saved in one transaction, so a failure leaves the
+    /// database as it was. Settings count as one record that always exists; only
+    /// `Overwrite` replaces them when they differ, and the new reminder config is
+    /// published once the transaction commits.
+    pub async fn import(&self, document: &ImportDocument, strategy: ImportStrategy) -> CoreResult<ImportSummary> {
+        let _write: MutexGuard<'_, ()> = self.settings_service.lock_writes().await;
+        let current: ExportSettings = ExportSettings {
+            reminder_config: self.settings_service.hydration_reminder_config().await?,
+            goal: self.settings_service.hydration_goal().await?,
+        };
+        let action: ImportAction = strategy.resolve(RecordMatch::among(&[current], &document.settings), true);
+        let replacement: Option<&ExportSettings> = (action == ImportAction::Replace).then_some(&document.settings);
+
+        let mut summary: ImportSummary = self
+            .repository
+            .import_records(&document.records, replacement, strategy)
+            .await?;
+        if replacement.is_some() {
+            let _: ReminderConfig = self.settings_service.publish_stored_reminder_config().await?;
+        }
+        let mut counts: ImportCounts = ImportCounts::default();
+        counts.record(action);
+        summary.settings = counts;
+        Ok(summary)
+    }
+}
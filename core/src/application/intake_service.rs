@@ -0,0 +1,62 @@
+//! Water intake use cases for logging drinks and totalling the current day.
+use crate::domain::history::{HistoryPage, HistoryQuery};
+use crate::domain::hydration::{
+    day_bounds, is_valid_intake_amount, HydrationGoal, HydrationProgress, IntakeSource, WaterIntake,
+};
+use crate::error::{CoreError, CoreResult};
+use crate::ports::clock::Clock;
+use crate::ports::water_intake_repository::WaterIntakeRepository;
+use chrono::{DateTime, Local, Utc};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct IntakeService {
+    repository: Arc<dyn WaterIntakeRepository>,
+    clock: Arc<dyn Clock>,
+}
+
+impl IntakeService {
+    /// Builds the intake use-case service with a repository and clock.
+    pub fn new(repository: Arc<dyn WaterIntakeRepository>, clock: Arc<dyn Clock>) -> Self {
+        Self { repository, clock }
+    }
+
+    /// Records a drink consumed now and returns the stored entry.
+    pub async fn log_intake(&self, amount_ml: u32, source: Option<IntakeSource>) -> CoreResult<WaterIntake> {
+        if !is_valid_intake_amount(amount_ml) {
+            return Err(CoreError::InvalidIntakeAmount(amount_ml));
+        }
+
+        self.repository
+            .insert_water_intake(amount_ml, self.clock.now(), source)
+            .await
+    }
+
+    /// Removes the most recently logged drink, returning it when one existed.
+    pub async fn undo_last_intake(&self) -> CoreResult<Option<WaterIntake>> {
+        self.repository.delete_latest_water_intake().await
+    }
+
+    /// Lists logged drinks for one page of `query`.
+    pub async fn intake_history(&self, query: HistoryQuery) -> CoreResult<HistoryPage<WaterIntake>> {
+        if let (Some(from), Some(until)) = (query.from, query.until)
+            && !query.has_valid_range()
+        {
+            return Err(CoreError::InvalidHistoryRange { from, until });
+        }
+        self.repository.list_water_intake(&query).await
+    }
+
+    /// Sums intake for the local hydration day containing the current time.
+    pub async fn total_today_ml(&self, day_start_hour: u32) -> CoreResult<u64> {
+        let (start, end): (DateTime<Utc>, DateTime<Utc>) =
+            day_bounds(self.clock.now(), &Local, day_start_hour);
+        self.repository.total_intake_ml_between(start, end).await
+    }
+
+    /// Compares today's intake with the configured goal.
+    pub async fn progress_today(&self, goal: HydrationGoal) -> CoreResult<HydrationProgress> {
+        let consumed_ml: u64 = self.total_today_ml(goal.day_start_hour).await?;
+        Ok(HydrationProgress::new(consumed_ml, goal.daily_goal_ml))
+    }
+}
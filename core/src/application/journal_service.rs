@@ -0,0 +1,52 @@
+//! Daily prompt and journal entry use cases built on repository ports.
+use crate::domain::journal::{normalize_entry_body, EntryDate, JournalEntry, JournalPrompt};
+use crate::error::{CoreError, CoreResult};
+use crate::ports::journal_entry_repository::JournalEntryRepository;
+use crate::ports::prompt_repository::PromptRepository;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct JournalService {
+    prompts: Arc<dyn PromptRepository>,
+    entries: Arc<dyn JournalEntryRepository>,
+}
+
+impl JournalService {
+    /// Builds the journal use-case service with prompt and entry repositories.
+    pub fn new(prompts: Arc<dyn PromptRepository>, entries: Arc<dyn JournalEntryRepository>) -> Self {
+        Self { prompts, entries }
+    }
+
+    /// Returns the prompt assigned to a date, if any prompts exist.
+    pub async fn prompt_for_date(&self, entry_date: EntryDate) -> CoreResult<Option<JournalPrompt>> {
+        let mut prompts: Vec<JournalPrompt> = self.prompts.list_prompts().await?;
+        let prompt: Option<JournalPrompt> = entry_date
+            .prompt_index(prompts.len())
+            .map(|index: usize| prompts.swap_remove(index));
+
+        Ok(prompt)
+    }
+
+    /// Saves or updates the entry for a date and links it to that day's prompt.
+    pub async fn save_entry(&self, entry_date: EntryDate, body: &str) -> CoreResult<JournalEntry> {
+        let body: String = normalize_entry_body(body).ok_or(CoreError::EmptyJournalEntry)?;
+        let prompt_id: Option<i64> = self
+            .prompt_for_date(entry_date)
+            .await?
+            .map(|prompt: JournalPrompt| prompt.id);
+
+        self.entries
+            .upsert_journal_entry(entry_date, prompt_id, &body)
+            .await
+    }
+
+    /// Loads the entry written for a date, if any.
+    pub async fn entry_for_date(&self, entry_date: EntryDate) -> CoreResult<Option<JournalEntry>> {
+        self.entries.journal_entry_for_date(entry_date).await
+    }
+
+    /// Lists the most recent entries, newest first.
+    pub async fn recent_entries(&self, limit: u32) -> CoreResult<Vec<JournalEntry>> {
+        self.entries.list_journal_entries(limit).await
+    }
+}
@@ -1,3 +1,10 @@
 //! Application layer services that orchestrate domain logic through ports.
+pub mod achievement_service;
+pub mod export_service;
 pub mod hydration_service;
+pub mod import_service;
+pub mod intake_service;
+pub mod journal_service;
+pub mod reminder_service;
 pub mod settings_service;
+pub mod stats_service;
@@ -0,0 +1,78 @@
+//! Reminder use cases that log a reminder and publish it as a typed event.
+use crate::application::hydration_service::HydrationService;
+use crate::application::intake_service::IntakeService;
+use crate::application::settings_service::SettingsService;
+use crate::domain::hydration::{
+    HydrationGoal, HydrationProgress, HydrationReminder, ReminderEvent, ReminderKind,
+};
+use crate::error::CoreResult;
+use crate::ports::reminder_event_sink::ReminderEventSink;
+use std::sync::Arc;
+use tokio::sync::broadcast;
+
+/// Events buffered per subscriber before a slow one starts missing reminders.
+const REMINDER_EVENT_CAPACITY: usize = 64;
+
+#[derive(Clone)]
+pub struct ReminderService {
+    hydration_service: HydrationService,
+    intake_service: IntakeService,
+    settings_service: SettingsService,
+    sink: Arc<dyn ReminderEventSink>,
+    events_tx: broadcast::Sender<ReminderEvent>,
+}
+
+impl ReminderService {
+    /// Builds the reminder use-case service over the hydration services and an event sink.
+    pub fn new(
+        hydration_service: HydrationService,
+        intake_service: IntakeService,
+        settings_service: SettingsService,
+        sink: Arc<dyn ReminderEventSink>,
+    ) -> Self {
+        let (events_tx, _events_rx): (broadcast::Sender<ReminderEvent>, broadcast::Receiver<ReminderEvent>) =
+            broadcast::channel(REMINDER_EVENT_CAPACITY);
+
+        Self {
+            hydration_service,
+            intake_service,
+            settings_service,
+            sink,
+            events_tx,
+        }
+    }
+
+    /// Logs one reminder, then emits it with today's progress through the sink and
+    /// to every subscriber.
+    ///
+    /// Progress is read before the reminder is logged, so a failed read can be retried
+    /// without logging the reminder twice.
+    pub async fn remind(&self, kind: ReminderKind) -> CoreResult<ReminderEvent> {
+        let goal: HydrationGoal = self.settings_service.hydration_goal().await?;
+        let progress: HydrationProgress = self.intake_service.progress_today(goal).await?;
+        let reminder: HydrationReminder = self.hydration_service.trigger_reminder().await?;
+        let event: ReminderEvent = ReminderEvent::new(reminder, kind, progress);
+
+        let emitted: CoreResult<()> = self.sink.emit_reminder(&event).await;
+        // Having no subscribers is not an error.
+        let _subscribers: Result<usize, broadcast::error::SendError<ReminderEvent>> =
+            self.events_tx.send(event.clone());
+        emitted?;
+        Ok(event)
+    }
+
+    /// Waits for the sink to deliver every reminder it has accepted.
+    pub async fn flush_sink(&self) -> CoreResult<()> {
+        self.sink.flush().await
+    }
+
+    /// Subscribes to reminder events; only reminders fired after this call are seen.
+    pub fn subscribe_events(&self) -> broadcast::Receiver<ReminderEvent> {
+        self.events_tx.subscribe()
+    }
+
+    /// Returns the hydration service whose log, clock and snoozes drive reminders.
+    pub fn hydration_service(&self) -> HydrationService {
+        self.hydration_service.clone()
+    }
+}
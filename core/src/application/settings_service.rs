@@ -1,37 +1,188 @@
 //! Settings use cases for reading and writing reminder configuration.
-use crate::domain::hydration::ReminderConfig;
-use crate::error::CoreResult;
+use crate::domain::hydration::{
+    ActiveWindow, CatchUpPolicy, CronSchedule, HydrationGoal, QuietHours, ReminderConfig, ReminderPause,
+    SavedReminderSettings, MAX_INTERVAL_MINUTES,
+};
+use crate::error::{CoreError, CoreResult};
 use crate::ports::settings_repository::SettingsRepository;
 use std::sync::Arc;
+use tokio::sync::{watch, Mutex, MutexGuard};
+
+/// Reminder settings that apply while nothing is saved for them, e.g. from a config file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReminderDefaults {
+    pub interval_minutes: u64,
+    pub quiet_hours: Option<QuietHours>,
+}
+
+impl ReminderDefaults {
+    fn config(self) -> ReminderConfig {
+        ReminderConfig::new(self.interval_minutes).with_quiet_hours(self.quiet_hours)
+    }
+}
+
+impl Default for ReminderDefaults {
+    fn default() -> Self {
+        Self {
+            interval_minutes: ReminderConfig::default().interval_minutes,
+            quiet_hours: None,
+        }
+    }
+}
 
 #[derive(Clone)]
 pub struct SettingsService {
     repository: Arc<dyn SettingsRepository>,
+    reminder_defaults: ReminderDefaults,
+    reminder_config_tx: Arc<watch::Sender<ReminderConfig>>,
+    /// Held across each write and the publish that follows, so concurrent setters
+    /// always publish a config that includes every change saved before them.
+    write_lock: Arc<Mutex<()>>,
 }
 
 impl SettingsService {
     /// Builds the settings use-case service with a settings repository.
     pub fn new(repository: Arc<dyn SettingsRepository>) -> Self {
-        Self { repository }
+        Self::with_reminder_defaults(repository, ReminderDefaults::default())
+    }
+
+    /// Builds the service with the interval and quiet hours used until others are saved.
+    pub fn with_reminder_defaults(repository: Arc<dyn SettingsRepository>, defaults: ReminderDefaults) -> Self {
+        let (reminder_config_tx, _reminder_config_rx): (
+            watch::Sender<ReminderConfig>,
+            watch::Receiver<ReminderConfig>,
+        ) = watch::channel(defaults.config());
+
+        Self {
+            repository,
+            reminder_defaults: defaults,
+            reminder_config_tx: Arc::new(reminder_config_tx),
+            write_lock: Arc::new(Mutex::new(())),
+        }
+    }
+
+    /// Subscribes to reminder config changes saved through this service.
+    ///
+    /// Only changes published after this call are reported by `changed()`.
+    pub fn subscribe_reminder_config(&self) -> watch::Receiver<ReminderConfig> {
+        self.reminder_config_tx.subscribe()
+    }
+
+    /// Blocks other settings writes until the guard drops, e.g. while an import saves settings.
+    pub(crate) async fn lock_writes(&self) -> MutexGuard<'_, ()> {
+        self.write_lock.lock().await
+    }
+
+    /// Re-reads the stored config and publishes it; call with `write_lock` held.
+    pub(crate) async fn publish_stored_reminder_config(&self) -> CoreResult<ReminderConfig> {
+        let config: ReminderConfig = self.hydration_reminder_config().await?;
+        let _previous: ReminderConfig = self.reminder_config_tx.send_replace(config);
+        Ok(config)
     }
 
     /// Persists hydration interval minutes and returns the normalized config.
+    ///
+    /// Intervals longer than `MAX_INTERVAL_MINUTES` are rejected; zero becomes one minute.
     pub async fn set_hydration_interval_minutes(&self, interval_minutes: u64) -> CoreResult<ReminderConfig> {
-        let config: ReminderConfig = ReminderConfig::new(interval_minutes);
+        if interval_minutes > MAX_INTERVAL_MINUTES {
+            return Err(CoreError::InvalidIntervalMinutes(interval_minutes));
+        }
+        let _write: MutexGuard<'_, ()> = self.write_lock.lock().await;
         self.repository
-            .set_hydration_interval_minutes(config.interval_minutes)
+            .set_hydration_interval_minutes(ReminderConfig::new(interval_minutes).interval_minutes)
             .await?;
-        Ok(config)
+        self.publish_stored_reminder_config().await
+    }
+
+    /// Persists (or clears) the reminder cron schedule and returns the updated config.
+    pub async fn set_cron_schedule(&self, cron: Option<CronSchedule>) -> CoreResult<ReminderConfig> {
+        let _write: MutexGuard<'_, ()> = self.write_lock.lock().await;
+        self.repository.set_cron_schedule(cron).await?;
+        self.publish_stored_reminder_config().await
+    }
+
+    /// Persists (or clears) the reminder active window and returns the updated config.
+    pub async fn set_active_window(&self, active_window: Option<ActiveWindow>) -> CoreResult<ReminderConfig> {
+        let _write: MutexGuard<'_, ()> = self.write_lock.lock().await;
+        self.repository.set_active_window(active_window).await?;
+        self.publish_stored_reminder_config().await
+    }
+
+    /// Persists the reminder quiet hours and returns the updated config.
+    ///
+    /// `None` turns quiet hours off, even when the defaults configure some.
+    pub async fn set_quiet_hours(&self, quiet_hours: Option<QuietHours>) -> CoreResult<ReminderConfig> {
+        let _write: MutexGuard<'_, ()> = self.write_lock.lock().await;
+        self.repository.set_quiet_hours(quiet_hours).await?;
+        self.publish_stored_reminder_config().await
+    }
+
+    /// Removes saved quiet hours so the default quiet hours, if any, apply again.
+    pub async fn reset_quiet_hours(&self) -> CoreResult<ReminderConfig> {
+        let _write: MutexGuard<'_, ()> = self.write_lock.lock().await;
+        self.repository.clear_quiet_hours().await?;
+        self.publish_stored_reminder_config().await
+    }
+
+    /// Persists the missed-reminder policy and returns the updated config.
+    pub async fn set_catch_up_policy(&self, policy: CatchUpPolicy) -> CoreResult<ReminderConfig> {
+        let _write: MutexGuard<'_, ()> = self.write_lock.lock().await;
+        self.repository.set_catch_up_policy(policy).await?;
+        self.publish_stored_reminder_config().await
+    }
+
+    /// Persists (or lifts) the pause on reminders and returns the updated config.
+    pub async fn set_reminder_pause(&self, pause: Option<ReminderPause>) -> CoreResult<ReminderConfig> {
+        let _write: MutexGuard<'_, ()> = self.write_lock.lock().await;
+        self.repository.set_reminder_pause(pause).await?;
+        self.publish_stored_reminder_config().await
     }
 
     /// Loads hydration reminder config from storage or falls back to defaults.
     pub async fn hydration_reminder_config(&self) -> CoreResult<ReminderConfig> {
-        let interval: u64 = self
+        let saved: SavedReminderSettings = self.repository.reminder_settings().await?;
+        let interval: u64 = saved.interval_minutes.unwrap_or(self.reminder_defaults.interval_minutes);
+        let quiet_hours: Option<QuietHours> = saved.quiet_hours.unwrap_or(self.reminder_defaults.quiet_hours);
+
+        Ok(ReminderConfig::new(interval)
+            .with_cron(saved.cron)
+            .with_active_window(saved.active_window)
+            .with_quiet_hours(quiet_hours)
+            .with_catch_up(saved.catch_up.unwrap_or_default())
+            .with_pause(saved.pause))
+    }
+
+    /// Persists the daily intake goal and returns the normalized goal settings.
+    pub async fn set_daily_goal_ml(&self, daily_goal_ml: u32) -> CoreResult<HydrationGoal> {
+        let _write: MutexGuard<'_, ()> = self.write_lock.lock().await;
+        self.repository.set_daily_goal_ml(daily_goal_ml.max(1)).await?;
+        self.hydration_goal().await
+    }
+
+    /// Persists the local hour at which a new hydration day begins.
+    pub async fn set_day_start_hour(&self, day_start_hour: u32) -> CoreResult<HydrationGoal> {
+        let _write: MutexGuard<'_, ()> = self.write_lock.lock().await;
+        let current: HydrationGoal = self.hydration_goal().await?;
+        let goal: HydrationGoal = HydrationGoal::new(current.daily_goal_ml, day_start_hour)
+            .ok_or(CoreError::InvalidDayStartHour(day_start_hour))?;
+        self.repository.set_day_start_hour(goal.day_start_hour).await?;
+        self.hydration_goal().await
+    }
+
+    /// Loads the daily goal settings from storage or falls back to defaults.
+    pub async fn hydration_goal(&self) -> CoreResult<HydrationGoal> {
+        let defaults: HydrationGoal = HydrationGoal::default();
+        let daily_goal_ml: u32 = self
+            .repository
+            .daily_goal_ml()
+            .await?
+            .unwrap_or(defaults.daily_goal_ml);
+        let day_start_hour: u32 = self
             .repository
-            .hydration_interval_minutes()
+            .day_start_hour()
             .await?
-            .unwrap_or(ReminderConfig::default().interval_minutes);
+            .unwrap_or(defaults.day_start_hour);
 
-        Ok(ReminderConfig::new(interval))
+        Ok(HydrationGoal::new(daily_goal_ml, day_start_hour).unwrap_or(defaults))
     }
 }
@@ -0,0 +1,110 @@
+//! Hydration statistics use case built from SQL aggregates over intake and reminder logs.
+use crate::domain::hydration::{day_bounds, hydration_day, resolve_local, HydrationGoal};
+use crate::domain::stats::{
+    DailyIntake, GoalStreaks, HourlyIntake, HydrationStats, ReminderResponseCounts, StatsPeriod,
+};
+use crate::error::CoreResult;
+use crate::ports::clock::Clock;
+use crate::ports::hydration_log_repository::HydrationLogRepository;
+use crate::ports::water_intake_repository::WaterIntakeRepository;
+use chrono::{DateTime, Days, Local, NaiveDate, NaiveTime, Utc};
+use std::sync::Arc;
+
+/// Days loaded per query while walking back along a goal streak.
+const GOAL_DAY_BATCH: u64 = 28;
+
+#[derive(Clone)]
+pub struct StatsService {
+    intake_repository: Arc<dyn WaterIntakeRepository>,
+    log_repository: Arc<dyn HydrationLogRepository>,
+    clock: Arc<dyn Clock>,
+}
+
+impl StatsService {
+    /// Builds the statistics service over the intake and reminder log repositories.
+    pub fn new(
+        intake_repository: Arc<dyn WaterIntakeRepository>,
+        log_repository: Arc<dyn HydrationLogRepository>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            intake_repository,
+            log_repository,
+            clock,
+        }
+    }
+
+    /// Computes statistics for the local hydration days of `period` ending today.
+    pub async fn stats(&self, period: StatsPeriod, goal: HydrationGoal) -> CoreResult<HydrationStats> {
+        let now: DateTime<Utc> = self.clock.now();
+        let today: NaiveDate = hydration_day(now, &Local, goal.day_start_hour);
+        let start: DateTime<Utc> = day_start(period.first_day(today), goal.day_start_hour);
+        let (_today_start, end): (DateTime<Utc>, DateTime<Utc>) = day_bounds(now, &Local, goal.day_start_hour);
+
+        let totals: Vec<DailyIntake> = self
+            .intake_repository
+            .daily_intake_totals(start, end, goal.day_start_hour)
+            .await?;
+        let hourly: Vec<HourlyIntake> = self.intake_repository.intake_by_hour(start, end).await?;
+        let goal_days: Vec<NaiveDate> =
+            goal_days_since(self.intake_repository.as_ref(), goal, period.first_day(today), end).await?;
+        let reminders: ReminderResponseCounts = self.log_repository.reminder_response_counts(start, end).await?;
+
+        Ok(HydrationStats::new(
+            period,
+            today,
+            goal.daily_goal_ml,
+            &totals,
+            &hourly,
+            GoalStreaks::from_goal_days(&goal_days, today),
+            reminders,
+        ))
+    }
+}
+
+/// Returns the instant at which the local hydration day `day` begins.
+fn day_start(day: NaiveDate, day_start_hour: u32) -> DateTime<Utc> {
+    let time: NaiveTime = NaiveTime::from_hms_opt(day_start_hour.min(23), 0, 0).unwrap_or(NaiveTime::MIN);
+    resolve_local(&Local, day.and_time(time))
+}
+
+/// Lists goal-met days from `first_day` until `end`, oldest first, plus the unbroken
+/// run of goal-met days just before `first_day`, so streaks reaching into the range
+/// count in full without reading all history.
+pub(crate) async fn goal_days_since(
+    intake: &dyn WaterIntakeRepository,
+    goal: HydrationGoal,
+    first_day: NaiveDate,
+    end: DateTime<Utc>,
+) -> CoreResult<Vec<NaiveDate>> {
+    let mut from: NaiveDate = first_day;
+    let mut days: Vec<NaiveDate> = intake
+        .goal_met_days(goal.daily_goal_ml, day_start(from, goal.day_start_hour), end, goal.day_start_hour)
+        .await?;
+    while days.first() == Some(&from) {
+        let Some(earlier) = from.checked_sub_days(Days::new(GOAL_DAY_BATCH)) else {
+            break;
+        };
+        let batch: Vec<NaiveDate> = intake
+            .goal_met_days(
+                goal.daily_goal_ml,
+                day_start(earlier, goal.day_start_hour),
+                day_start(from, goal.day_start_hour),
+                goal.day_start_hour,
+            )
+            .await?;
+        // Keep only the run that ends the day before `from`; anything older lies past a gap.
+        let mut run: Vec<NaiveDate> = Vec::new();
+        for day in batch.into_iter().rev() {
+            if day.succ_opt() != Some(run.last().copied().unwrap_or(from)) {
+                break;
+            }
+            run.push(day);
+        }
+        run.reverse();
+        run.append(&mut days);
+        days = run;
+        from = earlier;
+    }
+    Ok(days)
+}
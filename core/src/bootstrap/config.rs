@@ -0,0 +1,401 @@
+//! Layered configuration: a TOML file, then `DESK_BUDDY_*` environment variables, then
+//! command-line overrides, each replacing what the layers before it set.
+use crate::adapters::inbound::views::parse_clock_time;
+use crate::adapters::outbound::events::DEFAULT_SINK_TIMEOUT;
+use crate::application::settings_service::ReminderDefaults;
+use crate::bootstrap::core_builder::{CoreOptions, ReminderSinkKind, ReminderSinkOptions};
+use crate::domain::hydration::{QuietHours, MAX_INTERVAL_MINUTES};
+use crate::error::{CoreError, CoreResult};
+use std::path::{Path, PathBuf};
+use toml::{Table, Value};
+use tracing::level_filters::LevelFilter;
+
+/// Environment variable naming a config file to read instead of the default one.
+pub const CONFIG_PATH_ENV: &str = "DESK_BUDDY_CONFIG";
+const DATABASE_URL_ENV: &str = "DESK_BUDDY_DATABASE_URL";
+const LOG_LEVEL_ENV: &str = "DESK_BUDDY_LOG_LEVEL";
+const SINKS_ENV: &str = "DESK_BUDDY_SINKS";
+const INTERVAL_MINUTES_ENV: &str = "DESK_BUDDY_INTERVAL_MINUTES";
+const QUIET_HOURS_ENV: &str = "DESK_BUDDY_QUIET_HOURS";
+
+const APP_DIR: &str = "desk-buddy";
+const CONFIG_FILE_NAME: &str = "config.toml";
+const ENVIRONMENT: &str = "the environment";
+
+const EXPECTED_STRING: &str = "expected a non-empty string";
+const EXPECTED_LOG_LEVEL: &str = "expected off, error, warn, info, debug or trace";
+const EXPECTED_SINKS: &str = "expected a list of sink names (stdout)";
+const EXPECTED_INTERVAL: &str = "expected a whole number of minutes, from 1 to 10080 (one week)";
+const EXPECTED_QUIET_HOURS: &str = "expected HH:MM-HH:MM with two different times, or \"none\"";
+
+/// Settings the process runs with once every layer is applied.
+#[derive(Debug, Clone)]
+pub struct CoreConfig {
+    pub options: CoreOptions,
+    /// Most verbose log level to print; the core itself never installs a subscriber.
+    pub log_level: LevelFilter,
+    /// The config file that was read, if any.
+    pub file: Option<PathBuf>,
+}
+
+impl CoreConfig {
+    /// Reads the config file, the process environment and `overrides`, later layers winning.
+    ///
+    /// The file is `file` when given, else `$DESK_BUDDY_CONFIG`, else the platform default
+    /// (see `default_config_path`) if it exists. A named file must exist.
+    pub fn load(file: Option<&Path>, overrides: ConfigLayer) -> CoreResult<Self> {
+        Self::load_with(file, overrides, |name: &str| std::env::var(name).ok())
+    }
+
+    /// Like `load`, but reads environment variables through `vars`.
+    pub fn load_with(
+        file: Option<&Path>,
+        overrides: ConfigLayer,
+        vars: impl Fn(&str) -> Option<String>,
+    ) -> CoreResult<Self> {
+        let named: Option<PathBuf> = file
+            .map(Path::to_path_buf)
+            .or_else(|| vars(CONFIG_PATH_ENV).map(PathBuf::from));
+        let file: Option<PathBuf> = match named {
+            Some(path) => Some(path),
+            None => default_config_path(&vars).filter(|path: &PathBuf| path.is_file()),
+        };
+        let file_layer: ConfigLayer = match &file {
+            Some(path) => ConfigLayer::from_toml(&read_config_file(path)?, path)?,
+            None => ConfigLayer::default(),
+        };
+
+        let layer: ConfigLayer = overrides.or(ConfigLayer::from_env(&vars)?).or(file_layer);
+        Ok(Self::from_layer(layer, file))
+    }
+
+    /// Fills whatever no layer set with the built-in defaults.
+    pub fn from_layer(layer: ConfigLayer, file: Option<PathBuf>) -> Self {
+        let defaults: CoreOptions = CoreOptions::default();
+        let reminder_sinks: Vec<ReminderSinkOptions> = match layer.sinks {
+            Some(kinds) => kinds
+                .into_iter()
+                .map(|kind: ReminderSinkKind| ReminderSinkOptions {
+                    kind,
+                    timeout: DEFAULT_SINK_TIMEOUT,
+                })
+                .collect(),
+            None => defaults.reminder_sinks,
+        };
+
+        Self {
+            options: CoreOptions {
+                database_url: layer.database_url.unwrap_or(defaults.database_url),
+                reminder_sinks,
+                reminder_defaults: ReminderDefaults {
+                    interval_minutes: layer
+                        .interval_minutes
+                        .unwrap_or(defaults.reminder_defaults.interval_minutes),
+                    quiet_hours: layer.quiet_hours.unwrap_or(defaults.reminder_defaults.quiet_hours),
+                },
+            },
+            log_level: layer.log_level.unwrap_or(LevelFilter::INFO),
+            file,
+        }
+    }
+}
+
+/// Settings from one source; `None` leaves a value to the layers below.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConfigLayer {
+    pub database_url: Option<String>,
+    pub log_level: Option<LevelFilter>,
+    pub sinks: Option<Vec<ReminderSinkKind>>,
+    pub interval_minutes: Option<u64>,
+    /// `Some(None)` turns quiet hours off.
+    pub quiet_hours: Option<Option<QuietHours>>,
+}
+
+impl ConfigLayer {
+    /// Parses a config file; `origin` is only used to name the file in errors.
+    ///
+    /// ```toml
+    /// database_url = "sqlite:///home/me/desk-buddy.db"
+    /// log_level = "info"
+    /// sinks = ["stdout"]
+    ///
+    /// [reminders]
+    /// interval_minutes = 45
+    /// quiet_hours = "22:00-07:00"
+    /// ```
+    pub fn from_toml(text: &str, origin: &Path) -> CoreResult<Self> {
+        let origin: String = origin.display().to_string();
+        let table: Table = text.parse::<Table>().map_err(|err: toml::de::Error| {
+            let line: usize = err
+                .span()
+                .map_or(0, |span: std::ops::Range<usize>| text[..span.start].lines().count().max(1));
+            CoreError::ConfigSyntax {
+                origin: origin.clone(),
+                message: format!("line {line}: {}", err.message()),
+            }
+        })?;
+
+        let mut layer: Self = Self::default();
+        for (key, value) in &table {
+            let invalid = |reason: &str| invalid(key, &origin, reason);
+            match key.as_str() {
+                "database_url" => {
+                    layer.database_url = Some(value.as_str().and_then(non_empty).ok_or_else(|| invalid(EXPECTED_STRING))?);
+                }
+                "log_level" => {
+                    layer.log_level =
+                        Some(value.as_str().and_then(parse_log_level).ok_or_else(|| invalid(EXPECTED_LOG_LEVEL))?);
+                }
+                "sinks" => {
+                    let sinks: Option<Vec<ReminderSinkKind>> = value.as_array().and_then(|names: &Vec<Value>| {
+                        names
+                            .iter()
+                            .map(|name: &Value| name.as_str().and_then(|name: &str| name.parse::<ReminderSinkKind>().ok()))
+                            .collect()
+                    });
+                    layer.sinks = Some(sinks.ok_or_else(|| invalid(EXPECTED_SINKS))?);
+                }
+                "reminders" => {
+                    let reminders: &Table = value.as_table().ok_or_else(|| invalid("expected a table"))?;
+                    layer.read_reminders(reminders, &origin)?;
+                }
+                _ => return Err(invalid("unknown key")),
+            }
+        }
+        Ok(layer)
+    }
+
+    fn read_reminders(&mut self, reminders: &Table, origin: &str) -> CoreResult<()> {
+        for (key, value) in reminders {
+            let key: String = format!("reminders.{key}");
+            let invalid = |reason: &str| invalid(&key, origin, reason);
+            match key.as_str() {
+                "reminders.interval_minutes" => {
+                    let minutes: Option<u64> = value
+                        .as_integer()
+                        .and_then(|minutes: i64| u64::try_from(minutes).ok())
+                        .filter(|minutes: &u64| (1..=MAX_INTERVAL_MINUTES).contains(minutes));
+                    self.interval_minutes = Some(minutes.ok_or_else(|| invalid(EXPECTED_INTERVAL))?);
+                }
+                "reminders.quiet_hours" => {
+                    self.quiet_hours =
+                        Some(value.as_str().and_then(parse_quiet_hours).ok_or_else(|| invalid(EXPECTED_QUIET_HOURS))?);
+                }
+                _ => return Err(invalid("unknown key")),
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads the `DESK_BUDDY_*` variables through `vars`; `DESK_BUDDY_SINKS` is comma-separated.
+    pub fn from_env(vars: impl Fn(&str) -> Option<String>) -> CoreResult<Self> {
+        Ok(Self {
+            database_url: env_value(&vars, DATABASE_URL_ENV, EXPECTED_STRING, non_empty)?,
+            log_level: env_value(&vars, LOG_LEVEL_ENV, EXPECTED_LOG_LEVEL, parse_log_level)?,
+            sinks: env_value(&vars, SINKS_ENV, EXPECTED_SINKS, parse_sink_list)?,
+            interval_minutes: env_value(&vars, INTERVAL_MINUTES_ENV, EXPECTED_INTERVAL, |raw: &str| {
+                raw.trim().parse::<u64>().ok().filter(|minutes: &u64| (1..=MAX_INTERVAL_MINUTES).contains(minutes))
+            })?,
+            quiet_hours: env_value(&vars, QUIET_HOURS_ENV, EXPECTED_QUIET_HOURS, parse_quiet_hours)?,
+        })
+    }
+
+    /// Returns this layer with anything it leaves unset taken from `lower`.
+    pub fn or(self, lower: Self) -> Self {
+        Self {
+            database_url: self.database_url.or(lower.database_url),
+            log_level: self.log_level.or(lower.log_level),
+            sinks: self.sinks.or(lower.sinks),
+            interval_minutes: self.interval_minutes.or(lower.interval_minutes),
+            quiet_hours: self.quiet_hours.or(lower.quiet_hours),
+        }
+    }
+}
+
+/// Returns where the config file is looked for when none is named.
+///
+/// That is `desk-buddy/config.toml` under `$XDG_CONFIG_HOME`, falling back to `~/.config`;
+/// on macOS the fallback is `~/Library/Application Support` and on Windows `%APPDATA%`.
+pub fn default_config_path(vars: impl Fn(&str) -> Option<String>) -> Option<PathBuf> {
+    let absolute = |name: &str| vars(name).map(PathBuf::from).filter(|path: &PathBuf| path.is_absolute());
+    let config_dir: Option<PathBuf> = if cfg!(windows) {
+        absolute("APPDATA")
+    } else if cfg!(target_os = "macos") {
+        absolute("XDG_CONFIG_HOME").or_else(|| absolute("HOME").map(|home: PathBuf| home.join("Library/Application Support")))
+    } else {
+        // The XDG spec says to ignore relative paths.
+        absolute("XDG_CONFIG_HOME").or_else(|| absolute("HOME").map(|home: PathBuf| home.join(".config")))
+    };
+    config_dir.map(|dir: PathBuf| dir.join(APP_DIR).join(CONFIG_FILE_NAME))
+}
+
+fn read_config_file(path: &Path) -> CoreResult<String> {
+    std::fs::read_to_string(path).map_err(|err: std::io::Error| {
+        CoreError::Io(std::io::Error::new(
+            err.kind(),
+            format!("could not read config file {}: {err}", path.display()),
+        ))
+    })
+}
+
+fn env_value<T>(
+    vars: &impl Fn(&str) -> Option<String>,
+    name: &str,
+    expected: &str,
+    parse: impl Fn(&str) -> Option<T>,
+) -> CoreResult<Option<T>> {
+    vars(name)
+        .map(|raw: String| parse(&raw).ok_or_else(|| invalid(name, ENVIRONMENT, expected)))
+        .transpose()
+}
+
+fn invalid(key: &str, origin: &str, reason: &str) -> CoreError {
+    CoreError::InvalidConfig {
+        key: key.to_string(),
+        origin: origin.to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+fn non_empty(raw: &str) -> Option<String> {
+    Some(raw.trim().to_string()).filter(|value: &String| !value.is_empty())
+}
+
+fn parse_log_level(raw: &str) -> Option<LevelFilter> {
+    raw.trim().parse::<LevelFilter>().ok()
+}
+
+/// Parses comma-separated sink names; an empty value enables none.
+fn parse_sink_list(raw: &str) -> Option<Vec<ReminderSinkKind>> {
+    if raw.trim().is_empty() {
+        return Some(Vec::new());
+    }
+    raw.split(',')
+        .map(|name: &str| name.parse::<ReminderSinkKind>().ok())
+        .collect()
+}
+
+/// Parses `HH:MM-HH:MM`, or `none` for no quiet hours.
+fn parse_quiet_hours(raw: &str) -> Option<Option<QuietHours>> {
+    if raw.trim().eq_ignore_ascii_case("none") {
+        return Some(None);
+    }
+    let (start, end): (&str, &str) = raw.split_once('-')?;
+    QuietHours::new(parse_clock_time(start)?, parse_clock_time(end)?).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveTime;
+    use std::collections::HashMap;
+
+    fn vars(pairs: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = pairs
+            .iter()
+            .map(|(name, value): &(&str, &str)| (name.to_string(), value.to_string()))
+            .collect();
+        move |name: &str| vars.get(name).cloned()
+    }
+
+    fn time(raw: &str) -> NaiveTime {
+        NaiveTime::parse_from_str(raw, "%H:%M").expect("valid time")
+    }
+
+    fn invalid_key(result: CoreResult<impl std::fmt::Debug>) -> String {
+        match result {
+            Err(CoreError::InvalidConfig { key, .. }) => key,
+            other => panic!("expected an invalid config key, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn later_layers_override_earlier_ones() -> CoreResult<()> {
+        let dir: tempfile::TempDir = tempfile::tempdir()?;
+        let path: PathBuf = dir.path().join("config.toml");
+        std::fs::write(
+            &path,
+            r#"
+            database_url = "sqlite://from-file.db"
+            log_level = "warn"
+            sinks = ["stdout"]
+
+            [reminders]
+            interval_minutes = 45
+            quiet_hours = "22:00-07:00"
+            "#,
+        )?;
+        let env = vars(&[("DESK_BUDDY_INTERVAL_MINUTES", "20"), ("DESK_BUDDY_SINKS", "")]);
+        let overrides: ConfigLayer = ConfigLayer {
+            log_level: Some(LevelFilter::DEBUG),
+            ..ConfigLayer::default()
+        };
+
+        let config: CoreConfig = CoreConfig::load_with(Some(&path), overrides, env)?;
+
+        assert_eq!(config.options.database_url, "sqlite://from-file.db");
+        assert_eq!(config.log_level, LevelFilter::DEBUG);
+        assert!(config.options.reminder_sinks.is_empty());
+        assert_eq!(
+            config.options.reminder_defaults,
+            ReminderDefaults {
+                interval_minutes: 20,
+                quiet_hours: QuietHours::new(time("22:00"), time("07:00")),
+            }
+        );
+        assert_eq!(config.file, Some(path));
+
+        let defaults: CoreConfig = CoreConfig::from_layer(ConfigLayer::default(), None);
+        assert_eq!(defaults.options.database_url, CoreOptions::default().database_url);
+        assert_eq!(defaults.log_level, LevelFilter::INFO);
+        Ok(())
+    }
+
+    #[test]
+    fn errors_name_the_offending_key() {
+        let origin: &Path = Path::new("config.toml");
+
+        assert_eq!(invalid_key(ConfigLayer::from_toml("[reminders]\ninterval_minutes = 0", origin)), "reminders.interval_minutes");
+        assert_eq!(invalid_key(ConfigLayer::from_toml("[reminders]\nquiet_hours = \"22:00\"", origin)), "reminders.quiet_hours");
+        assert_eq!(invalid_key(ConfigLayer::from_toml("sinks = [\"pager\"]", origin)), "sinks");
+        assert_eq!(invalid_key(ConfigLayer::from_toml("colour = \"blue\"", origin)), "colour");
+        assert_eq!(invalid_key(ConfigLayer::from_env(vars(&[("DESK_BUDDY_LOG_LEVEL", "loud")]))), "DESK_BUDDY_LOG_LEVEL");
+        assert_eq!(
+            ConfigLayer::from_toml("log_level = 3", origin).map_err(|err: CoreError| err.to_string()),
+            Err("invalid config key log_level in config.toml: expected off, error, warn, info, debug or trace".to_string())
+        );
+        assert!(matches!(
+            ConfigLayer::from_toml("\n[reminders\n", origin),
+            Err(CoreError::ConfigSyntax { message, .. }) if message.starts_with("line 2:")
+        ));
+        assert!(matches!(
+            ConfigLayer::from_env(vars(&[("DESK_BUDDY_QUIET_HOURS", "none")])),
+            Ok(ConfigLayer { quiet_hours: Some(None), .. })
+        ));
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    #[test]
+    fn config_file_is_found_through_xdg() -> CoreResult<()> {
+        let dir: tempfile::TempDir = tempfile::tempdir()?;
+        let config_home: String = dir.path().display().to_string();
+        std::fs::create_dir(dir.path().join("desk-buddy"))?;
+        std::fs::write(dir.path().join("desk-buddy/config.toml"), "log_level = \"error\"")?;
+
+        let found: CoreConfig = CoreConfig::load_with(None, ConfigLayer::default(), vars(&[("XDG_CONFIG_HOME", &config_home)]))?;
+        assert_eq!(found.log_level, LevelFilter::ERROR);
+        assert_eq!(found.file, Some(dir.path().join("desk-buddy/config.toml")));
+
+        assert_eq!(
+            default_config_path(vars(&[("XDG_CONFIG_HOME", "relative"), ("HOME", "/home/me")])),
+            Some(PathBuf::from("/home/me/.config/desk-buddy/config.toml"))
+        );
+        let missing: CoreConfig = CoreConfig::load_with(None, ConfigLayer::default(), vars(&[("HOME", "/nonexistent")]))?;
+        assert_eq!(missing.file, None);
+        assert!(matches!(
+            CoreConfig::load_with(None, ConfigLayer::default(), vars(&[("DESK_BUDDY_CONFIG", "/nonexistent/config.toml")])),
+            Err(CoreError::Io(_))
+        ));
+        Ok(())
+    }
+}
@@ -1,20 +1,85 @@
 //! Composition root that wires concrete adapters into runtime + API objects.
-use crate::adapters::outbound::events::StdoutReminderSink;
+use crate::adapters::outbound::clock::SystemClock;
+use crate::adapters::outbound::events::{FanOutReminderSink, StdoutReminderSink, DEFAULT_SINK_TIMEOUT};
 use crate::adapters::outbound::sqlite::{
-    connect_sqlite, run_migrations, SqliteHydrationLogRepository, SqliteSettingsRepository,
+    connect_sqlite, run_migrations, SqliteAchievementRepository, SqliteHydrationLogRepository,
+    SqliteImportRepository, SqliteJournalEntryRepository,
+    SqlitePromptRepository, SqliteSettingsRepository, SqliteWaterIntakeRepository,
 };
 use crate::api::CoreApi;
+use crate::application::achievement_service::AchievementService;
+use crate::application::export_service::ExportService;
 use crate::application::hydration_service::HydrationService;
-use crate::application::settings_service::SettingsService;
+use crate::application::import_service::ImportService;
+use crate::application::intake_service::IntakeService;
+use crate::application::journal_service::JournalService;
+use crate::application::reminder_service::ReminderService;
+use crate::application::settings_service::{ReminderDefaults, SettingsService};
+use crate::application::stats_service::StatsService;
 use crate::bootstrap::core_runtime::CoreRuntime;
 use crate::error::CoreResult;
+use crate::ports::clock::Clock;
 use crate::ports::reminder_event_sink::ReminderEventSink;
 use sqlx::SqlitePool;
+use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
+
+/// Built-in reminder sinks that can be enabled from options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReminderSinkKind {
+    Stdout,
+}
+
+impl ReminderSinkKind {
+    /// Every built-in sink, for listing the accepted names.
+    pub const ALL: [Self; 1] = [Self::Stdout];
+
+    /// Returns the name used for the sink in logs and config.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Stdout => "stdout",
+        }
+    }
+}
+
+impl FromStr for ReminderSinkKind {
+    type Err = ();
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|kind: &Self| kind.as_str() == raw.trim().to_ascii_lowercase())
+            .ok_or(())
+    }
+}
+
+/// One built-in sink plus how long it may take to accept an event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReminderSinkOptions {
+    pub kind: ReminderSinkKind,
+    pub timeout: Duration,
+}
+
+impl ReminderSinkOptions {
+    /// Returns the stdout sink with the default delivery timeout.
+    pub fn stdout() -> Self {
+        Self {
+            kind: ReminderSinkKind::Stdout,
+            timeout: DEFAULT_SINK_TIMEOUT,
+        }
+    }
+}
+
+/// Database used when none is configured: `desk-buddy.db` in the working directory.
+pub const DEFAULT_DATABASE_URL: &str = "sqlite://desk-buddy.db";
 
 #[derive(Debug, Clone)]
 pub struct CoreOptions {
     pub database_url: String,
+    pub reminder_sinks: Vec<ReminderSinkOptions>,
+    /// Interval and quiet hours used until the user saves their own.
+    pub reminder_defaults: ReminderDefaults,
 }
 
 impl CoreOptions {
@@ -22,6 +87,7 @@ impl CoreOptions {
     pub fn in_memory() -> Self {
         Self {
             database_url: "sqlite::memory:".to_string(),
+            ..Self::default()
         }
     }
 }
@@ -29,19 +95,62 @@ impl CoreOptions {
 impl Default for CoreOptions {
     fn default() -> Self {
         Self {
-            database_url: "sqlite://desk-buddy.db".to_string(),
+            database_url: DEFAULT_DATABASE_URL.to_string(),
+            reminder_sinks: vec![ReminderSinkOptions::stdout()],
+            reminder_defaults: ReminderDefaults::default(),
         }
     }
 }
 
 pub struct CoreBuilder {
     options: CoreOptions,
+    clock: Arc<dyn Clock>,
+    extra_sinks: Vec<(String, Arc<dyn ReminderEventSink>, Duration)>,
 }
 
 impl CoreBuilder {
     /// Creates a builder that will construct a wired core runtime.
     pub fn new(options: CoreOptions) -> Self {
-        Self { options }
+        Self {
+            options,
+            clock: Arc::new(SystemClock),
+            extra_sinks: Vec::new(),
+        }
+    }
+
+    /// Replaces the system clock, mainly so tests can control "now".
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Adds a custom sink that receives every reminder alongside the configured ones.
+    pub fn with_reminder_sink(
+        mut self,
+        name: impl Into<String>,
+        sink: Arc<dyn ReminderEventSink>,
+        timeout: Duration,
+    ) -> Self {
+        self.extra_sinks.push((name.into(), sink, timeout));
+        self
+    }
+
+    /// Combines the configured built-in sinks and any custom sinks into one fan-out sink.
+    fn reminder_sink(
+        options: &[ReminderSinkOptions],
+        extra_sinks: Vec<(String, Arc<dyn ReminderEventSink>, Duration)>,
+    ) -> FanOutReminderSink {
+        let mut fan_out: FanOutReminderSink = FanOutReminderSink::new();
+        for option in options {
+            let sink: Arc<dyn ReminderEventSink> = match option.kind {
+                ReminderSinkKind::Stdout => Arc::new(StdoutReminderSink),
+            };
+            fan_out = fan_out.with_sink(option.kind.as_str(), sink, option.timeout);
+        }
+        for (name, sink, timeout) in extra_sinks {
+            fan_out = fan_out.with_sink(name, sink, timeout);
+        }
+        fan_out
     }
 
     /// Builds the runtime by wiring adapters, services, and the core API.
@@ -52,32 +161,247 @@ impl CoreBuilder {
         let hydration_repository: Arc<SqliteHydrationLogRepository> =
             Arc::new(SqliteHydrationLogRepository::new(pool.clone()));
         let settings_repository: Arc<SqliteSettingsRepository> =
-            Arc::new(SqliteSettingsRepository::new(pool));
-        let reminder_sink: Arc<dyn ReminderEventSink> = Arc::new(StdoutReminderSink);
+            Arc::new(SqliteSettingsRepository::new(pool.clone()));
+        let prompt_repository: Arc<SqlitePromptRepository> =
+            Arc::new(SqlitePromptRepository::new(pool.clone()));
+        let journal_entry_repository: Arc<SqliteJournalEntryRepository> =
+            Arc::new(SqliteJournalEntryRepository::new(pool.clone()));
+        let water_intake_repository: Arc<SqliteWaterIntakeRepository> =
+            Arc::new(SqliteWaterIntakeRepository::new(pool.clone()));
+        let achievement_repository: Arc<SqliteAchievementRepository> =
+            Arc::new(SqliteAchievementRepository::new(pool.clone()));
+        let import_repository: Arc<SqliteImportRepository> = Arc::new(SqliteImportRepository::new(pool.clone()));
+        let reminder_sink: Arc<dyn ReminderEventSink> =
+            Arc::new(Self::reminder_sink(&self.options.reminder_sinks, self.extra_sinks));
 
-        let hydration_service: HydrationService = HydrationService::new(hydration_repository);
-        let settings_service: SettingsService = SettingsService::new(settings_repository);
-        let api: CoreApi = CoreApi::new(hydration_service, settings_service, reminder_sink);
+        let achievement_service: AchievementService = AchievementService::new(
+            achievement_repository,
+            water_intake_repository.clone(),
+            journal_entry_repository.clone(),
+            reminder_sink.clone(),
+            self.clock.clone(),
+        );
+        let stats_service: StatsService = StatsService::new(
+            water_intake_repository.clone(),
+            hydration_repository.clone(),
+            self.clock.clone(),
+        );
+        let export_service: ExportService = ExportService::new(
+            hydration_repository.clone(),
+            water_intake_repository.clone(),
+            journal_entry_repository.clone(),
+            self.clock.clone(),
+        );
+        let hydration_service: HydrationService =
+            HydrationService::new(hydration_repository, self.clock.clone());
+        let settings_service: SettingsService =
+            SettingsService::with_reminder_defaults(settings_repository, self.options.reminder_defaults);
+        let import_service: ImportService = ImportService::new(import_repository, settings_service.clone());
+        let intake_service: IntakeService = IntakeService::new(water_intake_repository, self.clock);
+        let journal_service: JournalService =
+            JournalService::new(prompt_repository, journal_entry_repository);
+        let reminder_service: ReminderService = ReminderService::new(
+            hydration_service.clone(),
+            intake_service.clone(),
+            settings_service.clone(),
+            reminder_sink,
+        );
+        let api: CoreApi = CoreApi::new(
+            hydration_service,
+            settings_service,
+            intake_service,
+            journal_service,
+            reminder_service,
+            stats_service,
+            achievement_service,
+            export_service,
+            import_service,
+        );
 
-        Ok(CoreRuntime::new(api))
+        Ok(CoreRuntime::new(api).with_pool(pool))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::hydration::ReminderConfig;
+    use crate::domain::hydration::{
+        ActiveWindow, CatchUpPolicy, CronSchedule, HydrationGoal, HydrationProgress, IntakeSource, PauseLength,
+        QuietHours, ReminderAction, ReminderConfig, ReminderEvent, ReminderKind, ReminderPause, ReminderResponse,
+        WaterIntake, WeekdaySet, DEFAULT_DAILY_GOAL_ML, MAX_INTAKE_ML,
+    };
+    use crate::adapters::inbound::scheduler::{
+        FailureKind, HealthState, JobHandler, JobStatus, SchedulerError, SchedulerState, SchedulerStatus,
+    };
+    use crate::domain::schedule::JobSchedule;
+    use chrono::NaiveTime;
+    use tokio::sync::watch;
+    use crate::error::CoreError;
+    use crate::domain::journal::{EntryDate, JournalEntry, JournalPrompt};
+    use crate::bootstrap::ShutdownSignal;
+    use crate::domain::history::{HistoryPage, HistoryQuery, ReminderLogEntry, ReminderOutcome, SortOrder};
+    use crate::domain::achievements::Achievement;
+    use crate::ports::settings_repository::SettingsRepository;
+    use crate::ports::journal_entry_repository::JournalEntryRepository;
+    use crate::domain::hydration::resolve_local;
+    use crate::domain::stats::{DailyIntake, GoalStreaks, HydrationStats, ReminderResponseCounts, StatsPeriod};
+    use crate::adapters::outbound::export::{read_json_export, JsonExportWriter};
+    use crate::domain::export::{ExportSummary, ExportTable, ImportCounts, ImportDocument, ImportStrategy, ImportSummary};
 
     #[tokio::test]
     async fn trigger_once_writes_to_sqlite() -> CoreResult<()> {
         let runtime: CoreRuntime = CoreBuilder::new(CoreOptions::in_memory()).build().await?;
 
-        let _: String = runtime.api().trigger_hydration_reminder_once().await?;
+        let _: ReminderEvent = runtime.api().trigger_hydration_reminder_once().await?;
 
         assert_eq!(runtime.api().hydration_log_count().await?, 1);
         Ok(())
     }
 
+    #[tokio::test]
+    async fn reminder_events_carry_kind_progress_and_actions() -> CoreResult<()> {
+        let runtime: CoreRuntime = CoreBuilder::new(CoreOptions::in_memory()).build().await?;
+        let api: &CoreApi = runtime.api();
+        let _: WaterIntake = api.log_water_intake(500, None).await?;
+
+        let event: ReminderEvent = api.trigger_hydration_reminder_once().await?;
+
+        assert_eq!(event.kind, ReminderKind::Manual);
+        assert_eq!(event.progress, HydrationProgress::new(500, DEFAULT_DAILY_GOAL_ML));
+        assert_eq!(
+            event.actions,
+            vec![ReminderAction::Drink, ReminderAction::Snooze, ReminderAction::Dismiss]
+        );
+        assert_eq!(api.hydration_service().last_reminder_at().await?, Some(event.fired_at));
+        Ok(())
+    }
+
+    struct FailingSink;
+
+    #[async_trait::async_trait]
+    impl ReminderEventSink for FailingSink {
+        async fn emit_reminder(&self, event: &ReminderEvent) -> CoreResult<()> {
+            Err(CoreError::ReminderNotFound(event.id))
+        }
+    }
+
+    #[derive(Default)]
+    struct CollectingSink {
+        events: std::sync::Mutex<Vec<ReminderEvent>>,
+        achievements: std::sync::Mutex<Vec<&'static str>>,
+    }
+
+    #[async_trait::async_trait]
+    impl ReminderEventSink for CollectingSink {
+        async fn emit_reminder(&self, event: &ReminderEvent) -> CoreResult<()> {
+            self.events.lock().expect("sink lock").push(event.clone());
+            Ok(())
+        }
+
+        async fn emit_achievement(&self, achievement: &Achievement) -> CoreResult<()> {
+            self.achievements.lock().expect("sink lock").push(achievement.rule.id);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn reminders_fan_out_to_configured_and_custom_sinks() -> CoreResult<()> {
+        let collected: Arc<CollectingSink> = Arc::new(CollectingSink::default());
+        let options: CoreOptions = CoreOptions {
+            reminder_sinks: Vec::new(),
+            ..CoreOptions::in_memory()
+        };
+        let runtime: CoreRuntime = CoreBuilder::new(options)
+            .with_reminder_sink("broken", Arc::new(FailingSink), Duration::from_secs(1))
+            .with_reminder_sink("collected", collected.clone(), Duration::from_secs(1))
+            .build()
+            .await?;
+
+        let event: ReminderEvent = runtime.api().trigger_hydration_reminder_once().await?;
+
+        assert_eq!(*collected.events.lock().expect("sink lock"), vec![event]);
+        assert_eq!(CoreOptions::default().reminder_sinks, vec![ReminderSinkOptions::stdout()]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reminder_fails_when_every_sink_fails() -> CoreResult<()> {
+        let options: CoreOptions = CoreOptions {
+            reminder_sinks: Vec::new(),
+            ..CoreOptions::in_memory()
+        };
+        let runtime: CoreRuntime = CoreBuilder::new(options)
+            .with_reminder_sink("broken", Arc::new(FailingSink), Duration::from_secs(1))
+            .build()
+            .await?;
+
+        assert!(matches!(
+            runtime.api().trigger_hydration_reminder_once().await,
+            Err(CoreError::ReminderDeliveryFailed { failed: 1 })
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reminder_responses_are_recorded_against_the_log_row() -> CoreResult<()> {
+        let runtime: CoreRuntime = CoreBuilder::new(CoreOptions::in_memory()).build().await?;
+        let api: &CoreApi = runtime.api();
+
+        let first: ReminderEvent = api.trigger_hydration_reminder_once().await?;
+        let second: ReminderEvent = api.trigger_hydration_reminder_once().await?;
+        assert_ne!(first.id, second.id);
+
+        api.respond_to_hydration_reminder(
+            first.id,
+            ReminderResponse::Drank {
+                amount_ml: Some(300),
+            },
+        )
+        .await?;
+        api.respond_to_hydration_reminder(second.id, ReminderResponse::Snoozed { minutes: 10 })
+            .await?;
+
+        assert_eq!(api.water_intake_today_ml().await?, 300);
+        assert!(matches!(
+            api.respond_to_hydration_reminder(999, ReminderResponse::Dismissed).await,
+            Err(CoreError::ReminderNotFound(999))
+        ));
+        assert!(matches!(
+            api.respond_to_hydration_reminder(first.id, ReminderResponse::Snoozed { minutes: 0 })
+                .await,
+            Err(CoreError::InvalidSnoozeMinutes(0))
+        ));
+
+        let too_much: u32 = MAX_INTAKE_ML + 1;
+        assert!(matches!(
+            api.respond_to_hydration_reminder(
+                second.id,
+                ReminderResponse::Drank {
+                    amount_ml: Some(too_much)
+                }
+            )
+            .await,
+            Err(CoreError::InvalidIntakeAmount(amount_ml)) if amount_ml == too_much
+        ));
+        let page: HistoryPage<ReminderLogEntry> = api
+            .hydration_reminder_history(HistoryQuery {
+                order: SortOrder::OldestFirst,
+                ..HistoryQuery::default()
+            })
+            .await?;
+        let outcomes: Vec<Option<ReminderOutcome>> = page
+            .items
+            .iter()
+            .map(|entry: &ReminderLogEntry| entry.outcome)
+            .collect();
+        assert_eq!(
+            outcomes,
+            vec![Some(ReminderOutcome::Drank), Some(ReminderOutcome::Snoozed { minutes: 10 })]
+        );
+        assert_eq!(api.water_intake_today_ml().await?, 300);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn hydration_interval_defaults_then_round_trips() -> CoreResult<()> {
         let runtime: CoreRuntime = CoreBuilder::new(CoreOptions::in_memory()).build().await?;
@@ -93,14 +417,909 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn water_intake_logs_totals_and_undoes() -> CoreResult<()> {
+        let runtime: CoreRuntime = CoreBuilder::new(CoreOptions::in_memory()).build().await?;
+        let api: &CoreApi = runtime.api();
+
+        let _: WaterIntake = api.log_water_intake(250, Some(IntakeSource::Glass)).await?;
+        let bottle: WaterIntake = api.log_water_intake(500, Some(IntakeSource::Bottle)).await?;
+        assert_eq!(api.water_intake_today_ml().await?, 750);
+
+        let undone: Option<WaterIntake> = api.undo_last_water_intake().await?;
+        assert_eq!(undone, Some(bottle));
+        assert_eq!(api.water_intake_today_ml().await?, 250);
+
+        assert!(matches!(
+            api.log_water_intake(0, None).await,
+            Err(CoreError::InvalidIntakeAmount(0))
+        ));
+        Ok(())
+    }
+
+    /// Clock that moves one minute forward every time it is read.
+    struct SteppingClock {
+        next: std::sync::Mutex<chrono::DateTime<chrono::Utc>>,
+    }
+
+    impl Clock for SteppingClock {
+        fn now(&self) -> chrono::DateTime<chrono::Utc> {
+            let mut next: std::sync::MutexGuard<'_, chrono::DateTime<chrono::Utc>> =
+                self.next.lock().expect("clock lock");
+            let now: chrono::DateTime<chrono::Utc> = *next;
+            *next = now + chrono::Duration::minutes(1);
+            now
+        }
+    }
+
+    #[tokio::test]
+    async fn intake_history_pages_through_a_range_in_either_order() -> CoreResult<()> {
+        let clock: Arc<SteppingClock> = Arc::new(SteppingClock {
+            next: std::sync::Mutex::new(chrono::DateTime::UNIX_EPOCH + chrono::Duration::days(20_000)),
+        });
+        let runtime: CoreRuntime = CoreBuilder::new(CoreOptions::in_memory())
+            .with_clock(clock)
+            .build()
+            .await?;
+        let api: &CoreApi = runtime.api();
+        let mut logged: Vec<WaterIntake> = Vec::new();
+        for amount_ml in [100, 200, 300, 400, 500] {
+            logged.push(api.log_water_intake(amount_ml, None).await?);
+        }
+
+        let mut query: HistoryQuery = HistoryQuery {
+            limit: 2,
+            ..HistoryQuery::default()
+        };
+        let mut newest_first: Vec<u32> = Vec::new();
+        loop {
+            let page: HistoryPage<WaterIntake> = api.water_intake_history(query).await?;
+            assert!(page.items.len() <= 2);
+            newest_first.extend(page.items.iter().map(|intake: &WaterIntake| intake.amount_ml));
+            match page.next_cursor {
+                Some(cursor) => query.after = Some(cursor),
+                None => break,
+            }
+        }
+        assert_eq!(newest_first, vec![500, 400, 300, 200, 100]);
+
+        let ranged: HistoryPage<WaterIntake> = api
+            .water_intake_history(HistoryQuery {
+                from: Some(logged[1].consumed_at),
+                until: Some(logged[3].consumed_at),
+                order: SortOrder::OldestFirst,
+                ..HistoryQuery::default()
+            })
+            .await?;
+        assert_eq!(ranged.items, logged[1..3].to_vec());
+        assert_eq!(ranged.next_cursor, None);
+
+        assert!(matches!(
+            api.water_intake_history(HistoryQuery {
+                from: Some(logged[3].consumed_at),
+                until: Some(logged[1].consumed_at),
+                ..HistoryQuery::default()
+            })
+            .await,
+            Err(CoreError::InvalidHistoryRange { .. })
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn export_streams_every_page_of_each_table_oldest_first() -> CoreResult<()> {
+        let runtime: CoreRuntime = CoreBuilder::new(CoreOptions::in_memory()).build().await?;
+        let api: &CoreApi = runtime.api();
+        for amount_ml in 1..=501 {
+            let _: WaterIntake = api.log_water_intake(amount_ml, None).await?;
+        }
+        let _: ReminderEvent = api.trigger_hydration_reminder_once().await?;
+        let _: JournalEntry = api.save_todays_journal_entry("Today").await?;
+        let _: JournalEntry = api
+            .save_journal_entry(EntryDate::parse("2020-01-01").expect("valid date"), "Long ago")
+            .await?;
+        let _: u64 = api.set_hydration_interval_minutes(45).await?;
+
+        let mut writer: JsonExportWriter<Vec<u8>> = JsonExportWriter::new(Vec::new());
+        let summary: ExportSummary = api.export(&ExportTable::ALL, &mut writer).await?;
+        assert_eq!(
+            summary,
+            ExportSummary {
+                reminder_logs: 1,
+                water_intake: 501,
+                journal_entries: 2,
+            }
+        );
+
+        let document: serde_json::Value = serde_json::from_slice(&writer.into_inner()).expect("valid JSON");
+        let amounts: Vec<u64> = document["water_intake"]
+            .as_array()
+            .expect("intake array")
+            .iter()
+            .filter_map(|intake: &serde_json::Value| intake["amount_ml"].as_u64())
+            .collect();
+        assert_eq!(amounts, (1..=501).collect::<Vec<u64>>());
+        assert_eq!(document["journal_entries"][0]["body"], "Long ago");
+        assert_eq!(document["settings"]["interval_minutes"], 45);
+
+        let mut writer: JsonExportWriter<Vec<u8>> = JsonExportWriter::new(Vec::new());
+        let summary: ExportSummary = api.export(&[ExportTable::JournalEntries], &mut writer).await?;
+        assert_eq!(summary.water_intake, 0);
+        let document: serde_json::Value = serde_json::from_slice(&writer.into_inner()).expect("valid JSON");
+        assert!(document.get("water_intake").is_none());
+        assert_eq!(document["journal_entries"].as_array().map(Vec::len), Some(2));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn imports_merge_exports_with_each_strategy() -> CoreResult<()> {
+        let counts = |inserted: u64, skipped: u64, conflicting: u64| ImportCounts {
+            inserted,
+            skipped,
+            conflicting,
+        };
+        let source: CoreRuntime = CoreBuilder::new(CoreOptions::in_memory())
+            .with_clock(Arc::new(SteppingClock {
+                next: std::sync::Mutex::new(chrono::DateTime::UNIX_EPOCH + chrono::Duration::days(20_000)),
+            }))
+            .build()
+            .await?;
+        let _: WaterIntake = source.api().log_water_intake(250, Some(IntakeSource::Cup)).await?;
+        let _: WaterIntake = source.api().log_water_intake(500, None).await?;
+        let _: ReminderEvent = source.api().trigger_hydration_reminder_once().await?;
+        let long_ago: EntryDate = EntryDate::parse("2020-01-01").expect("valid date");
+        let _: JournalEntry = source.api().save_journal_entry(long_ago, "Long ago").await?;
+        let _: u64 = source.api().set_hydration_interval_minutes(45).await?;
+        let mut writer: JsonExportWriter<Vec<u8>> = JsonExportWriter::new(Vec::new());
+        let _: ExportSummary = source.api().export(&ExportTable::ALL, &mut writer).await?;
+        let mut document: ImportDocument = read_json_export(writer.into_inner().as_slice())?;
+
+        let runtime: CoreRuntime = CoreBuilder::new(CoreOptions::in_memory()).build().await?;
+        let api: &CoreApi = runtime.api();
+        let first: ImportSummary = api.import(&document, ImportStrategy::SkipDuplicates).await?;
+        assert_eq!(first.water_intake, counts(2, 0, 0));
+        assert_eq!(first.reminder_logs, counts(1, 0, 0));
+        assert_eq!(first.journal_entries, counts(1, 0, 0));
+        assert_eq!(first.settings, counts(0, 0, 1));
+        assert_eq!(api.hydration_reminder_config().await?.interval_minutes, 30);
+        assert!(!api.achievements().await?.iter().all(|achievement: &Achievement| !achievement.is_unlocked()));
+
+        let again: ImportSummary = api.import(&document, ImportStrategy::Overwrite).await?;
+        assert_eq!(again.water_intake, counts(0, 2, 0));
+        assert_eq!(again.journal_entries, counts(0, 1, 0));
+        assert_eq!(again.settings, counts(0, 0, 1));
+        assert_eq!(api.hydration_reminder_config().await?.interval_minutes, 45);
+
+        document.records.water_intake[0].amount_ml = 300;
+        document.records.journal_entries[0].body = "Edited".to_string();
+        let kept: ImportSummary = api.import(&document, ImportStrategy::SkipDuplicates).await?;
+        assert_eq!(kept.water_intake, counts(0, 1, 1));
+        assert_eq!(kept.settings, counts(0, 1, 0));
+        let amounts = |page: HistoryPage<WaterIntake>| -> Vec<u32> {
+            page.items.iter().map(|intake: &WaterIntake| intake.amount_ml).collect()
+        };
+        let oldest_first: HistoryQuery = HistoryQuery {
+            order: SortOrder::OldestFirst,
+            ..HistoryQuery::default()
+        };
+        assert_eq!(amounts(api.water_intake_history(oldest_first).await?), vec![250, 500]);
+
+        let overwritten: ImportSummary = api.import(&document, ImportStrategy::Overwrite).await?;
+        assert_eq!(overwritten.water_intake, counts(0, 1, 1));
+        assert_eq!(overwritten.journal_entries, counts(0, 0, 1));
+        assert_eq!(amounts(api.water_intake_history(oldest_first).await?), vec![300, 500]);
+        let edited: Option<JournalEntry> = api.journal_entry(long_ago).await?;
+        assert_eq!(edited.map(|entry: JournalEntry| entry.body), Some("Edited".to_string()));
+
+        document.records.journal_entries[0].body = "And more".to_string();
+        let appended: ImportSummary = api.import(&document, ImportStrategy::Append).await?;
+        assert_eq!(appended.water_intake, counts(2, 0, 0));
+        assert_eq!(appended.reminder_logs, counts(1, 0, 0));
+        assert_eq!(appended.journal_entries, counts(0, 0, 1));
+        assert_eq!(amounts(api.water_intake_history(oldest_first).await?), vec![300, 300, 500, 500]);
+        let merged: Option<JournalEntry> = api.journal_entry(long_ago).await?;
+        assert_eq!(merged.map(|entry: JournalEntry| entry.body), Some("Edited\n\nAnd more".to_string()));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn failed_imports_leave_settings_unchanged() -> CoreResult<()> {
+        let source: CoreRuntime = CoreBuilder::new(CoreOptions::in_memory()).build().await?;
+        let _: WaterIntake = source.api().log_water_intake(250, None).await?;
+        let _: u64 = source.api().set_hydration_interval_minutes(45).await?;
+        let mut writer: JsonExportWriter<Vec<u8>> = JsonExportWriter::new(Vec::new());
+        let _: ExportSummary = source.api().export(&ExportTable::ALL, &mut writer).await?;
+        let mut document: ImportDocument = read_json_export(writer.into_inner().as_slice())?;
+        // The water intake table rejects empty drinks, failing the import's transaction.
+        document.records.water_intake[0].amount_ml = 0;
+
+        let runtime: CoreRuntime = CoreBuilder::new(CoreOptions::in_memory()).build().await?;
+        let api: &CoreApi = runtime.api();
+        let updates: watch::Receiver<ReminderConfig> = api.settings_service().subscribe_reminder_config();
+
+        assert!(api.import(&document, ImportStrategy::Overwrite).await.is_err());
+        assert_eq!(api.hydration_reminder_config().await?.interval_minutes, 30);
+        assert_eq!(api.water_intake_today_ml().await?, 0);
+        assert!(!updates.has_changed().expect("settings service alive"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn stats_aggregate_days_hours_streaks_and_responses() -> CoreResult<()> {
+        let local = |day: u32, hour: u32| -> chrono::DateTime<chrono::Utc> {
+            let date: chrono::NaiveDate = chrono::NaiveDate::from_ymd_opt(2026, 3, day).expect("valid date");
+            resolve_local(&chrono::Local, date.and_hms_opt(hour, 0, 0).expect("valid time"))
+        };
+        let clock: Arc<SteppingClock> = Arc::new(SteppingClock {
+            next: std::sync::Mutex::new(local(7, 12)),
+        });
+        let runtime: CoreRuntime = CoreBuilder::new(CoreOptions::in_memory())
+            .with_clock(clock.clone())
+            .build()
+            .await?;
+        let api: &CoreApi = runtime.api();
+        let at = |day: u32, hour: u32| *clock.next.lock().expect("clock lock") = local(day, hour);
+
+        for day in [7, 8] {
+            at(day, 12);
+            let _: WaterIntake = api.log_water_intake(2_000, None).await?;
+        }
+        at(10, 9);
+        let _: WaterIntake = api.log_water_intake(700, None).await?;
+        let drank: ReminderEvent = api.trigger_hydration_reminder_once().await?;
+        let ignored: ReminderEvent = api.trigger_hydration_reminder_once().await?;
+        api.respond_to_hydration_reminder(drank.id, ReminderResponse::Drank { amount_ml: None })
+            .await?;
+        api.respond_to_hydration_reminder(ignored.id, ReminderResponse::Dismissed)
+            .await?;
+        at(10, 18);
+
+        let week: HydrationStats = api.hydration_stats(StatsPeriod::Week).await?;
+        assert_eq!(week.first_day.to_string(), "2026-03-04");
+        assert_eq!(week.last_day.to_string(), "2026-03-10");
+        let totals: Vec<u64> = week.days.iter().map(|day: &DailyIntake| day.total_ml).collect();
+        assert_eq!(totals, vec![0, 0, 0, 2_000, 2_000, 0, 700]);
+        assert_eq!(week.total_ml, 4_700);
+        assert!((week.goal_hit_percent - 200.0 / 7.0).abs() < 1e-9);
+        assert_eq!(
+            week.streaks,
+            GoalStreaks {
+                current_days: 0,
+                longest_days: 2
+            }
+        );
+        assert!((week.average_ml_by_hour[12] - 4_000.0 / 7.0).abs() < 1e-9);
+        assert!((week.average_ml_by_hour[9] - 100.0).abs() < 1e-9);
+        assert_eq!(
+            week.reminders,
+            ReminderResponseCounts {
+                fired: 2,
+                drank: 1,
+                snoozed: 0,
+                dismissed: 1
+            }
+        );
+        assert_eq!(week.reminders.drink_response_percent(), Some(50.0));
+
+        let day: HydrationStats = api.hydration_stats(StatsPeriod::Day).await?;
+        assert_eq!(day.total_ml, 700);
+        assert_eq!(day.goal_hit_percent, 0.0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn streaks_follow_goal_days_back_past_the_period_start() -> CoreResult<()> {
+        let noon = |days_ago: u64| -> chrono::DateTime<chrono::Utc> {
+            let date: chrono::NaiveDate = chrono::NaiveDate::from_ymd_opt(2026, 3, 31)
+                .and_then(|today: chrono::NaiveDate| today.checked_sub_days(chrono::Days::new(days_ago)))
+                .expect("valid date");
+            resolve_local(&chrono::Local, date.and_hms_opt(12, 0, 0).expect("valid time"))
+        };
+        let clock: Arc<SteppingClock> = Arc::new(SteppingClock {
+            next: std::sync::Mutex::new(noon(60)),
+        });
+        let runtime: CoreRuntime = CoreBuilder::new(CoreOptions::in_memory())
+            .with_clock(clock.clone())
+            .build()
+            .await?;
+        let api: &CoreApi = runtime.api();
+
+        // One goal day, a gap, then 40 goal days in a row ending today.
+        for days_ago in std::iter::once(45).chain((0..40).rev()) {
+            *clock.next.lock().expect("clock lock") = noon(days_ago);
+            let _: WaterIntake = api.log_water_intake(DEFAULT_DAILY_GOAL_ML, None).await?;
+        }
+
+        let day: HydrationStats = api.hydration_stats(StatsPeriod::Day).await?;
+        assert_eq!(
+            day.streaks,
+            GoalStreaks {
+                current_days: 40,
+                longest_days: 40
+            }
+        );
+        let achievements: Vec<Achievement> = api.achievements().await?;
+        assert!(achievements
+            .iter()
+            .filter(|achievement: &&Achievement| achievement.rule.id.starts_with("goal_streak_"))
+            .all(Achievement::is_unlocked));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn achievements_unlock_once_and_are_announced_through_the_sink() -> CoreResult<()> {
+        let local = |day: u32| -> chrono::DateTime<chrono::Utc> {
+            let date: chrono::NaiveDate = chrono::NaiveDate::from_ymd_opt(2026, 3, day).expect("valid date");
+            resolve_local(&chrono::Local, date.and_hms_opt(12, 0, 0).expect("valid time"))
+        };
+        let clock: Arc<SteppingClock> = Arc::new(SteppingClock {
+            next: std::sync::Mutex::new(local(1)),
+        });
+        let collected: Arc<CollectingSink> = Arc::new(CollectingSink::default());
+        let runtime: CoreRuntime = CoreBuilder::new(CoreOptions {
+            reminder_sinks: Vec::new(),
+            ..CoreOptions::in_memory()
+        })
+        .with_clock(clock.clone())
+        .with_reminder_sink("broken", Arc::new(FailingSink), Duration::from_secs(1))
+        .with_reminder_sink("collected", collected.clone(), Duration::from_secs(1))
+        .build()
+        .await?;
+        let api: &CoreApi = runtime.api();
+        let _: u32 = api.set_daily_hydration_goal_ml(1_000).await?;
+
+        for day in 1..=3 {
+            *clock.next.lock().expect("clock lock") = local(day);
+            let _: WaterIntake = api.log_water_intake(1_000, None).await?;
+        }
+        let _: JournalEntry = api
+            .save_journal_entry(EntryDate::new(local(3).date_naive()), "three good days")
+            .await?;
+        assert_eq!(
+            *collected.achievements.lock().expect("sink lock"),
+            vec!["first_goal", "goal_streak_3", "first_journal_entry"]
+        );
+        assert!(api.unlock_new_achievements().await?.is_empty());
+
+        let _: Option<WaterIntake> = api.undo_last_water_intake().await?;
+        let achievements: Vec<Achievement> = api.achievements().await?;
+        let status = |id: &str| -> Achievement {
+            *achievements
+                .iter()
+                .find(|achievement: &&Achievement| achievement.rule.id == id)
+                .expect("known achievement")
+        };
+        assert!(status("goal_streak_3").is_unlocked());
+        assert_eq!(status("goal_streak_7").progress, 2);
+        assert!(!status("goal_streak_7").is_unlocked());
+        assert_eq!(status("journal_entries_7").progress, 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reminder_history_lists_logged_reminders_with_their_answers() -> CoreResult<()> {
+        let runtime: CoreRuntime = CoreBuilder::new(CoreOptions::in_memory()).build().await?;
+        let api: &CoreApi = runtime.api();
+
+        let first: ReminderEvent = api.trigger_hydration_reminder_once().await?;
+        let second: ReminderEvent = api.trigger_hydration_reminder_once().await?;
+        let third: ReminderEvent = api.trigger_hydration_reminder_once().await?;
+        api.respond_to_hydration_reminder(first.id, ReminderResponse::Dismissed)
+            .await?;
+        api.respond_to_hydration_reminder(second.id, ReminderResponse::Snoozed { minutes: 15 })
+            .await?;
+
+        let page: HistoryPage<ReminderLogEntry> = api
+            .hydration_reminder_history(HistoryQuery {
+                order: SortOrder::OldestFirst,
+                limit: 2,
+                ..HistoryQuery::default()
+            })
+            .await?;
+        let outcomes: Vec<(i64, Option<ReminderOutcome>)> = page
+            .items
+            .iter()
+            .map(|entry: &ReminderLogEntry| (entry.id, entry.outcome))
+            .collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                (first.id, Some(ReminderOutcome::Dismissed)),
+                (second.id, Some(ReminderOutcome::Snoozed { minutes: 15 })),
+            ]
+        );
+        assert!(page.items.iter().all(|entry: &ReminderLogEntry| entry.responded_at.is_some()));
+
+        let rest: HistoryPage<ReminderLogEntry> = api
+            .hydration_reminder_history(HistoryQuery {
+                order: SortOrder::OldestFirst,
+                after: page.next_cursor,
+                ..HistoryQuery::default()
+            })
+            .await?;
+        assert_eq!(rest.items.len(), 1);
+        assert_eq!(rest.items[0].id, third.id);
+        assert_eq!(rest.items[0].outcome, None);
+        assert_eq!(rest.next_cursor, None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn hydration_goal_persists_and_drives_progress() -> CoreResult<()> {
+        let runtime: CoreRuntime = CoreBuilder::new(CoreOptions::in_memory()).build().await?;
+        let api: &CoreApi = runtime.api();
+
+        assert_eq!(api.hydration_goal().await?.daily_goal_ml, DEFAULT_DAILY_GOAL_ML);
+        assert_eq!(api.set_daily_hydration_goal_ml(1_500).await?, 1_500);
+        assert_eq!(api.set_hydration_day_start_hour(4).await?, 4);
+        assert!(matches!(
+            api.set_hydration_day_start_hour(24).await,
+            Err(CoreError::InvalidDayStartHour(24))
+        ));
+        assert_eq!(
+            api.hydration_goal().await?,
+            HydrationGoal {
+                daily_goal_ml: 1_500,
+                day_start_hour: 4
+            }
+        );
+
+        let _: WaterIntake = api.log_water_intake(600, None).await?;
+        let progress: HydrationProgress = api.hydration_progress_today().await?;
+        assert_eq!(progress.consumed_ml, 600);
+        assert_eq!(progress.goal_ml, 1_500);
+        assert_eq!(progress.remaining_ml, 900);
+        assert_eq!(progress.percent, 40.0);
+
+        // Interval settings still round-trip now that the settings row has nullable columns.
+        assert_eq!(
+            api.hydration_reminder_config().await?.interval_minutes,
+            ReminderConfig::default().interval_minutes
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn journal_entry_saves_updates_and_lists() -> CoreResult<()> {
+        let runtime: CoreRuntime = CoreBuilder::new(CoreOptions::in_memory()).build().await?;
+        let api: &CoreApi = runtime.api();
+
+        let prompt: JournalPrompt = api.todays_journal_prompt().await?.expect("seeded prompts");
+        let first: JournalEntry = api.save_todays_journal_entry("  First draft ").await?;
+        assert_eq!(first.body, "First draft");
+        assert_eq!(first.prompt_id, Some(prompt.id));
+
+        let updated: JournalEntry = api.save_todays_journal_entry("Final words").await?;
+        assert_eq!(updated.id, first.id);
+        assert_eq!(updated.body, "Final words");
+
+        let earlier: EntryDate = EntryDate::parse("2020-01-01").expect("valid date");
+        let _: JournalEntry = api.save_journal_entry(earlier, "Long ago").await?;
+
+        let entries: Vec<JournalEntry> = api.journal_entries(10).await?;
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].id, first.id);
+        assert_eq!(entries[1].entry_date, earlier);
+        assert!(matches!(
+            api.save_todays_journal_entry("   ").await,
+            Err(CoreError::EmptyJournalEntry)
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn journal_entry_upserts_replace_the_linked_prompt() -> CoreResult<()> {
+        let pool: SqlitePool = connect_sqlite("sqlite::memory:").await?;
+        run_migrations(&pool).await?;
+        let repository: SqliteJournalEntryRepository = SqliteJournalEntryRepository::new(pool);
+        let date: EntryDate = EntryDate::parse("2020-01-01").expect("valid date");
+
+        let first: JournalEntry = repository.upsert_journal_entry(date, Some(1), "First").await?;
+        let relinked: JournalEntry = repository.upsert_journal_entry(date, Some(2), "Second").await?;
+        assert_eq!(relinked.id, first.id);
+        assert_eq!(relinked.prompt_id, Some(2));
+
+        let unlinked: JournalEntry = repository.upsert_journal_entry(date, None, "Third").await?;
+        assert_eq!(unlinked.prompt_id, None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn todays_journal_entry_follows_the_injected_clock() -> CoreResult<()> {
+        let noon: chrono::DateTime<chrono::Utc> = chrono::DateTime::UNIX_EPOCH + chrono::Duration::days(18_793)
+            + chrono::Duration::hours(12);
+        let runtime: CoreRuntime = CoreBuilder::new(CoreOptions::in_memory())
+            .with_clock(Arc::new(SteppingClock {
+                next: std::sync::Mutex::new(noon),
+            }))
+            .build()
+            .await?;
+
+        let entry: JournalEntry = runtime.api().save_todays_journal_entry("Back then").await?;
+        assert_eq!(entry.entry_date.date(), noon.with_timezone(&chrono::Local).date_naive());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn active_window_and_quiet_hours_round_trip() -> CoreResult<()> {
+        let runtime: CoreRuntime = CoreBuilder::new(CoreOptions::in_memory()).build().await?;
+        let api: &CoreApi = runtime.api();
+        let at = |hour: u32| NaiveTime::from_hms_opt(hour, 0, 0).expect("valid time");
+        let window: Option<ActiveWindow> = ActiveWindow::new(at(9), at(18), WeekdaySet::WEEKDAYS);
+        let quiet: Option<QuietHours> = QuietHours::new(at(22), at(7));
+
+        let _: ReminderConfig = api.set_hydration_active_window(window).await?;
+        let _: ReminderConfig = api.set_hydration_quiet_hours(quiet).await?;
+        let _: u64 = api.set_hydration_interval_minutes(45).await?;
+
+        let config: ReminderConfig = api.hydration_reminder_config().await?;
+        assert_eq!(config.interval_minutes, 45);
+        assert_eq!(config.active_window, window);
+        assert_eq!(config.quiet_hours, quiet);
+
+        let cleared: ReminderConfig = api.set_hydration_active_window(None).await?;
+        assert_eq!(cleared.active_window, None);
+        assert_eq!(api.hydration_reminder_config().await?.active_window, None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reminder_defaults_apply_until_settings_are_saved() -> CoreResult<()> {
+        let at = |hour: u32| NaiveTime::from_hms_opt(hour, 0, 0).expect("valid time");
+        let quiet: Option<QuietHours> = QuietHours::new(at(22), at(7));
+        let options: CoreOptions = CoreOptions {
+            reminder_defaults: ReminderDefaults {
+                interval_minutes: 50,
+                quiet_hours: quiet,
+            },
+            ..CoreOptions::in_memory()
+        };
+        let runtime: CoreRuntime = CoreBuilder::new(options).build().await?;
+        let api: &CoreApi = runtime.api();
+
+        let config: ReminderConfig = api.hydration_reminder_config().await?;
+        assert_eq!((config.interval_minutes, config.quiet_hours), (50, quiet));
+        assert_eq!(*api.settings_service().subscribe_reminder_config().borrow(), config);
+
+        let _: u64 = api.set_hydration_interval_minutes(15).await?;
+        let early: Option<QuietHours> = QuietHours::new(at(20), at(6));
+        assert_eq!(api.set_hydration_quiet_hours(early).await?.quiet_hours, early);
+        // Turning quiet hours off is saved; only a reset falls back to the defaults.
+        assert_eq!(api.set_hydration_quiet_hours(None).await?.quiet_hours, None);
+        assert_eq!(api.hydration_reminder_config().await?.quiet_hours, None);
+        assert_eq!(api.reset_hydration_quiet_hours().await?.quiet_hours, quiet);
+        assert_eq!(api.hydration_reminder_config().await?.interval_minutes, 15);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn catch_up_policy_and_last_reminder_persist() -> CoreResult<()> {
+        let runtime: CoreRuntime = CoreBuilder::new(CoreOptions::in_memory()).build().await?;
+        let api: &CoreApi = runtime.api();
+        assert_eq!(api.hydration_reminder_config().await?.catch_up, CatchUpPolicy::FireOnce);
+        assert_eq!(api.hydration_service().last_reminder_at().await?, None);
+
+        let updated: ReminderConfig = api.set_hydration_catch_up_policy(CatchUpPolicy::FireAll).await?;
+        let reminder: ReminderEvent = api.trigger_hydration_reminder_once().await?;
+
+        assert_eq!(updated.catch_up, CatchUpPolicy::FireAll);
+        assert_eq!(api.hydration_reminder_config().await?.catch_up, CatchUpPolicy::FireAll);
+        assert_eq!(
+            api.hydration_service().last_reminder_at().await?,
+            Some(reminder.fired_at)
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cron_schedule_is_validated_and_persisted() -> CoreResult<()> {
+        let runtime: CoreRuntime = CoreBuilder::new(CoreOptions::in_memory()).build().await?;
+        let api: &CoreApi = runtime.api();
+
+        let updated: ReminderConfig = api.set_hydration_cron_schedule(Some("*/45 9-16 * * mon-fri")).await?;
+        let saved: Option<String> = api
+            .hydration_reminder_config()
+            .await?
+            .cron
+            .map(|cron: CronSchedule| cron.to_string());
+
+        assert_eq!(updated.cron, api.hydration_reminder_config().await?.cron);
+        assert_eq!(saved.as_deref(), Some("0,45 9-16 * * 1-5"));
+        assert!(matches!(
+            api.set_hydration_cron_schedule(Some("0 25 * * *")).await,
+            Err(CoreError::InvalidCronExpression(_))
+        ));
+        assert_eq!(api.set_hydration_cron_schedule(None).await?.cron, None);
+        assert_eq!(api.hydration_reminder_config().await?.cron, None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reminder_pause_survives_restart_until_resumed() -> CoreResult<()> {
+        let dir: tempfile::TempDir = tempfile::tempdir().expect("temp dir");
+        let options: CoreOptions = CoreOptions {
+            database_url: format!("sqlite://{}", dir.path().join("pause.db").display()),
+            ..CoreOptions::default()
+        };
+        let runtime: CoreRuntime = CoreBuilder::new(options.clone()).build().await?;
+        let pause: ReminderPause = runtime.pause_reminders(PauseLength::Minutes(60)).await?;
+        assert!(matches!(
+            runtime.pause_reminders(PauseLength::Minutes(0)).await,
+            Err(CoreError::InvalidPauseMinutes(0))
+        ));
+        drop(runtime);
+
+        let restarted: CoreRuntime = CoreBuilder::new(options).build().await?;
+        assert_eq!(restarted.api().hydration_reminder_pause().await?, Some(pause));
+        restarted.resume_reminders().await?;
+        assert_eq!(restarted.api().hydration_reminder_pause().await?, None);
+
+        let indefinite: ReminderPause = restarted.pause_reminders(PauseLength::Indefinitely).await?;
+        assert_eq!(restarted.api().hydration_reminder_config().await?.pause, Some(indefinite));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn saved_settings_are_published_to_running_schedulers() -> CoreResult<()> {
+        let runtime: CoreRuntime = CoreBuilder::new(CoreOptions::in_memory()).build().await?;
+        let mut updates: watch::Receiver<ReminderConfig> =
+            runtime.api().settings_service().subscribe_reminder_config();
+
+        let _: u64 = runtime.api().set_hydration_interval_minutes(12).await?;
+
+        assert!(updates.has_changed().expect("settings service alive"));
+        assert_eq!(updates.borrow_and_update().interval_minutes, 12);
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn concurrent_setters_publish_every_saved_change() -> CoreResult<()> {
+        let runtime: CoreRuntime = CoreBuilder::new(CoreOptions::in_memory()).build().await?;
+        let settings: SettingsService = runtime.api().settings_service().clone();
+        let updates: watch::Receiver<ReminderConfig> = settings.subscribe_reminder_config();
+
+        let (interval, catch_up): (CoreResult<ReminderConfig>, CoreResult<ReminderConfig>) = tokio::join!(
+            settings.set_hydration_interval_minutes(12),
+            settings.set_catch_up_policy(CatchUpPolicy::Skip),
+        );
+        let _: ReminderConfig = interval?;
+        let _: ReminderConfig = catch_up?;
+
+        let published: ReminderConfig = *updates.borrow();
+        assert_eq!(published, settings.hydration_reminder_config().await?);
+        assert_eq!(published.interval_minutes, 12);
+        assert_eq!(published.catch_up, CatchUpPolicy::Skip);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn scheduler_uses_saved_config_smoke_test() -> CoreResult<()> {
         let runtime: CoreRuntime = CoreBuilder::new(CoreOptions::in_memory()).build().await?;
 
         let _: u64 = runtime.api().set_hydration_interval_minutes(1).await?;
+        let mut status: watch::Receiver<SchedulerStatus> = runtime.subscribe_scheduler_status().await;
         runtime.start_with_saved_hydration_config().await?;
+        let running: SchedulerStatus = status
+            .wait_for(|status: &SchedulerStatus| status.next_fire_at.is_some())
+            .await
+            .expect("runtime alive")
+            .clone();
+        assert_eq!((running.state, running.health), (SchedulerState::Running, HealthState::Healthy));
         runtime.stop().await?;
+        assert_eq!(runtime.scheduler_status().await.state, SchedulerState::Stopped);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn huge_saved_intervals_are_capped_when_the_loop_starts() -> CoreResult<()> {
+        let dir: tempfile::TempDir = tempfile::tempdir().expect("temp dir");
+        let options: CoreOptions = CoreOptions {
+            database_url: format!("sqlite://{}", dir.path().join("interval.db").display()),
+            ..CoreOptions::in_memory()
+        };
+        // Older builds saved any interval, so write one straight to the database.
+        let pool: SqlitePool = connect_sqlite(&options.database_url).await?;
+        run_migrations(&pool).await?;
+        SqliteSettingsRepository::new(pool.clone())
+            .set_hydration_interval_minutes(1_000_000_000_000)
+            .await?;
+        pool.close().await;
+
+        let runtime: CoreRuntime = CoreBuilder::new(options).build().await?;
+        assert!(matches!(
+            runtime.api().set_hydration_interval_minutes(1_000_000_000_000).await,
+            Err(CoreError::InvalidIntervalMinutes(1_000_000_000_000))
+        ));
+        let mut status: watch::Receiver<SchedulerStatus> = runtime.subscribe_scheduler_status().await;
+        runtime.start_with_saved_hydration_config().await?;
+        let running: SchedulerStatus = status
+            .wait_for(|status: &SchedulerStatus| status.next_fire_at.is_some())
+            .await
+            .expect("runtime alive")
+            .clone();
+        assert_eq!(running.health, HealthState::Healthy);
+        assert!(running.next_fire_at <= Some(chrono::Utc::now() + chrono::Duration::weeks(1)));
+        runtime.stop().await
+    }
+
+    struct NoopJob;
+
+    #[async_trait::async_trait]
+    impl JobHandler for NoopJob {
+        async fn run(&self) -> CoreResult<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn runtime_manages_background_jobs() -> CoreResult<()> {
+        let runtime: CoreRuntime = CoreBuilder::new(CoreOptions::in_memory()).build().await?;
+
+        runtime
+            .schedule_job("stretch", JobSchedule::every_minutes(45), Arc::new(NoopJob))
+            .await?;
+        runtime.pause_job("stretch").await?;
+        assert_eq!(runtime.list_jobs().await[0].status, JobStatus::Paused);
+        runtime.resume_job("stretch").await?;
+        assert_eq!(runtime.list_jobs().await[0].status, JobStatus::Scheduled);
+
+        runtime.remove_job("stretch").await?;
+        assert!(runtime.list_jobs().await.is_empty());
+        assert!(matches!(
+            runtime.remove_job("stretch").await,
+            Err(CoreError::JobNotFound(_))
+        ));
+        Ok(())
+    }
+
+    /// System clock that can start behind real time and later catch up.
+    struct ShiftedClock {
+        behind: std::sync::Mutex<chrono::Duration>,
+    }
+
+    impl ShiftedClock {
+        fn behind_minutes(minutes: i64) -> Self {
+            Self {
+                behind: std::sync::Mutex::new(chrono::Duration::minutes(minutes)),
+            }
+        }
+
+        fn catch_up(&self) {
+            *self.behind.lock().expect("clock lock") = chrono::Duration::zero();
+        }
+    }
+
+    impl Clock for ShiftedClock {
+        fn now(&self) -> chrono::DateTime<chrono::Utc> {
+            chrono::Utc::now() - *self.behind.lock().expect("clock lock")
+        }
+    }
+
+    /// Sink that, once held, blocks each delivery until released.
+    #[derive(Default)]
+    struct GatedSink {
+        held: std::sync::atomic::AtomicBool,
+        entered: tokio::sync::Notify,
+        release: tokio::sync::Notify,
+        delivered: std::sync::Mutex<Vec<i64>>,
+        flushed: std::sync::atomic::AtomicBool,
+    }
+
+    impl GatedSink {
+        fn delivered(&self) -> Vec<i64> {
+            self.delivered.lock().expect("sink lock").clone()
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl ReminderEventSink for GatedSink {
+        async fn emit_reminder(&self, event: &ReminderEvent) -> CoreResult<()> {
+            if self.held.load(std::sync::atomic::Ordering::SeqCst) {
+                self.entered.notify_one();
+                self.release.notified().await;
+            }
+            self.delivered.lock().expect("sink lock").push(event.id);
+            Ok(())
+        }
+
+        async fn flush(&self) -> CoreResult<()> {
+            self.flushed.store(true, std::sync::atomic::Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn shutdown_mid_reminder_finishes_delivery_then_closes_the_database() -> CoreResult<()> {
+        let dir: tempfile::TempDir = tempfile::tempdir()?;
+        let options: CoreOptions = CoreOptions {
+            database_url: format!("sqlite://{}", dir.path().join("desk-buddy.db").display()),
+            reminder_sinks: Vec::new(),
+            ..CoreOptions::default()
+        };
+        let clock: Arc<ShiftedClock> = Arc::new(ShiftedClock::behind_minutes(2));
+        let sink: Arc<GatedSink> = Arc::new(GatedSink::default());
+        let runtime: CoreRuntime = CoreBuilder::new(options.clone())
+            .with_clock(clock.clone())
+            .with_reminder_sink("gated", sink.clone(), Duration::from_secs(5))
+            .build()
+            .await?;
+        // A reminder two minutes ago on a one-minute interval makes the next one due at once.
+        let _: u64 = runtime.api().set_hydration_interval_minutes(1).await?;
+        let _: ReminderEvent = runtime.api().trigger_hydration_reminder_once().await?;
+        clock.catch_up();
+        sink.held.store(true, std::sync::atomic::Ordering::SeqCst);
+
+        let signal: ShutdownSignal = runtime
+            .run_until(async {
+                sink.entered.notified().await;
+                let sink: Arc<GatedSink> = sink.clone();
+                // Let the delivery finish only once shutdown is already waiting on it.
+                tokio::spawn(async move {
+                    tokio::time::sleep(Duration::from_millis(50)).await;
+                    sink.release.notify_one();
+                });
+                ShutdownSignal::Terminate
+            })
+            .await?;
+
+        assert_eq!(signal, ShutdownSignal::Terminate);
+        assert_eq!(sink.delivered(), vec![1, 2]);
+        assert!(sink.flushed.load(std::sync::atomic::Ordering::SeqCst));
+        assert_eq!(runtime.scheduler_status().await.state, SchedulerState::Stopped);
+        assert!(runtime.api().hydration_log_count().await.is_err());
+
+        let reopened: CoreRuntime = CoreBuilder::new(options).build().await?;
+        assert_eq!(reopened.api().hydration_log_count().await?, 2);
+        reopened.shutdown().await
+    }
+
+    #[tokio::test]
+    async fn run_until_returns_start_errors_after_closing_the_database() -> CoreResult<()> {
+        let runtime: CoreRuntime = CoreBuilder::new(CoreOptions::in_memory()).build().await?;
+        runtime.start_with_saved_hydration_config().await?;
+
+        let stopped: CoreResult<ShutdownSignal> =
+            runtime.run_until(std::future::pending::<ShutdownSignal>()).await;
+
+        assert!(matches!(stopped, Err(CoreError::SchedulerAlreadyRunning)));
+        assert!(runtime.api().hydration_log_count().await.is_err());
+        Ok(())
+    }
+    /// System clock that panics on the first read after it is armed.
+    #[derive(Default)]
+    struct PanickingClock {
+        armed: std::sync::atomic::AtomicBool,
+    }
+
+    impl Clock for PanickingClock {
+        fn now(&self) -> chrono::DateTime<chrono::Utc> {
+            if self.armed.swap(false, std::sync::atomic::Ordering::SeqCst) {
+                panic!("clock bug");
+            }
+            chrono::Utc::now()
+        }
+    }
+
+    #[tokio::test]
+    async fn run_until_returns_as_soon_as_the_reminder_loop_panics() -> CoreResult<()> {
+        let clock: Arc<PanickingClock> = Arc::new(PanickingClock::default());
+        let runtime: CoreRuntime = CoreBuilder::new(CoreOptions::in_memory())
+            .with_clock(clock.clone())
+            .build()
+            .await?;
+        clock.armed.store(true, std::sync::atomic::Ordering::SeqCst);
+
+        let stopped: CoreResult<ShutdownSignal> = tokio::time::timeout(
+            Duration::from_secs(5),
+            runtime.run_until(std::future::pending::<ShutdownSignal>()),
+        )
+        .await
+        .expect("run ends without a signal");
 
+        assert!(matches!(stopped, Err(CoreError::TaskJoin(_))));
+        let status: SchedulerStatus = runtime.scheduler_status().await;
+        assert_eq!(status.last_error.map(|err: SchedulerError| err.kind), Some(FailureKind::Panic));
         Ok(())
     }
 }
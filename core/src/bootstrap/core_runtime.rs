@@ -1,24 +1,43 @@
 //! Runtime container that owns scheduler state and exposes a pure API.
-use crate::adapters::inbound::scheduler::HydrationScheduler;
+use crate::adapters::inbound::scheduler::{
+    HydrationScheduler, JobHandler, JobInfo, JobScheduler, SchedulerError, SchedulerState, SchedulerStatus,
+};
 use crate::api::CoreApi;
-use crate::domain::hydration::ReminderConfig;
-use crate::error::CoreResult;
-use tokio::sync::{Mutex, MutexGuard};
+use crate::bootstrap::shutdown::{shutdown_signal, ShutdownSignal};
+use crate::domain::hydration::{PauseLength, ReminderConfig, ReminderEvent, ReminderPause};
+use crate::domain::schedule::JobSchedule;
+use crate::error::{CoreError, CoreResult};
+use sqlx::SqlitePool;
+use std::future::Future;
+use std::sync::Arc;
+use tokio::sync::{broadcast, watch, Mutex, MutexGuard};
 
 pub struct CoreRuntime {
     api: CoreApi,
     scheduler: Mutex<HydrationScheduler>,
+    jobs: Mutex<JobScheduler>,
+    /// Closed by `shutdown` when the builder opened it.
+    pool: Option<SqlitePool>,
 }
 
 impl CoreRuntime {
     /// Creates a runtime wrapper around a prewired core API.
     pub fn new(api: CoreApi) -> Self {
+        let jobs: JobScheduler = JobScheduler::new(api.hydration_service().clock());
         Self {
             api,
             scheduler: Mutex::new(HydrationScheduler::new()),
+            jobs: Mutex::new(jobs),
+            pool: None,
         }
     }
 
+    /// Hands the runtime the database pool behind its API so `shutdown` can close it.
+    pub(crate) fn with_pool(mut self, pool: SqlitePool) -> Self {
+        self.pool = Some(pool);
+        self
+    }
+
     /// Returns the pure core API for use-case calls.
     pub fn api(&self) -> &CoreApi {
         &self.api
@@ -26,23 +45,145 @@ impl CoreRuntime {
 
     /// Starts the scheduler using interval configuration stored in settings.
     pub async fn start_with_saved_hydration_config(&self) -> CoreResult<()> {
+        // Subscribe before loading so a change saved in between is not missed.
+        let config_updates: watch::Receiver<ReminderConfig> =
+            self.api.settings_service().subscribe_reminder_config();
         let config: ReminderConfig = self.api.hydration_reminder_config().await?;
-        self.start(config).await
+        self.start_scheduler(config, config_updates).await
     }
 
     /// Starts the scheduler with an explicit reminder configuration.
+    ///
+    /// Settings saved through the API while running replace this configuration.
     pub async fn start(&self, config: ReminderConfig) -> CoreResult<()> {
+        let config_updates: watch::Receiver<ReminderConfig> =
+            self.api.settings_service().subscribe_reminder_config();
+        self.start_scheduler(config, config_updates).await
+    }
+
+    async fn start_scheduler(
+        &self,
+        config: ReminderConfig,
+        config_updates: watch::Receiver<ReminderConfig>,
+    ) -> CoreResult<()> {
         let mut scheduler: MutexGuard<'_, HydrationScheduler> = self.scheduler.lock().await;
-        scheduler.start(
-            self.api.hydration_service(),
-            self.api.reminder_sink(),
-            config,
-        )
+        scheduler.start(self.api.reminder_service(), config, config_updates)
+    }
+
+    /// Pauses reminders and saves the pause; a running loop holds them without restarting.
+    pub async fn pause_reminders(&self, length: PauseLength) -> CoreResult<ReminderPause> {
+        self.api.pause_hydration_reminders(length).await
+    }
+
+    /// Lifts the pause; a running loop starts a fresh interval from now.
+    pub async fn resume_reminders(&self) -> CoreResult<()> {
+        let _: ReminderConfig = self.api.resume_hydration_reminders().await?;
+        Ok(())
+    }
+
+    /// Returns the reminder loop's state, health, next and last fire times, and last error.
+    pub async fn scheduler_status(&self) -> SchedulerStatus {
+        self.scheduler.lock().await.status()
+    }
+
+    /// Subscribes to reminder loop status changes, e.g. to show a countdown to the next reminder.
+    pub async fn subscribe_scheduler_status(&self) -> watch::Receiver<SchedulerStatus> {
+        self.scheduler.lock().await.subscribe_status()
+    }
+
+    /// Subscribes to every reminder fired from now on, scheduled or manual.
+    pub fn subscribe_reminder_events(&self) -> broadcast::Receiver<ReminderEvent> {
+        self.api.reminder_service().subscribe_events()
+    }
+
+    /// Adds a named background job that runs `handler` on `schedule`.
+    pub async fn schedule_job(
+        &self,
+        name: &str,
+        schedule: JobSchedule,
+        handler: Arc<dyn JobHandler>,
+    ) -> CoreResult<()> {
+        self.jobs.lock().await.schedule(name, schedule, handler)
+    }
+
+    /// Lists background jobs with their status and next run, ordered by name.
+    pub async fn list_jobs(&self) -> Vec<JobInfo> {
+        self.jobs.lock().await.list()
+    }
+
+    /// Pauses a background job until it is resumed.
+    pub async fn pause_job(&self, name: &str) -> CoreResult<()> {
+        self.jobs.lock().await.pause(name)
+    }
+
+    /// Resumes a paused background job.
+    pub async fn resume_job(&self, name: &str) -> CoreResult<()> {
+        self.jobs.lock().await.resume(name)
     }
 
-    /// Stops the running scheduler task, if any.
+    /// Stops and removes a background job.
+    pub async fn remove_job(&self, name: &str) -> CoreResult<()> {
+        self.jobs.lock().await.remove(name).await
+    }
+
+    /// Stops the reminder scheduler task and removes every background job.
     pub async fn stop(&self) -> CoreResult<()> {
         let mut scheduler: MutexGuard<'_, HydrationScheduler> = self.scheduler.lock().await;
-        scheduler.stop().await
+        let stopped: CoreResult<()> = scheduler.stop().await;
+        let removed: CoreResult<()> = self.jobs.lock().await.remove_all().await;
+        stopped.and(removed)
+    }
+
+    /// Stops everything, waits for sinks to deliver pending reminders, then closes the database.
+    ///
+    /// A reminder already firing is logged and delivered before this returns; API calls
+    /// that need the database fail afterwards.
+    pub async fn shutdown(&self) -> CoreResult<()> {
+        let stopped: CoreResult<()> = self.stop().await;
+        let flushed: CoreResult<()> = self.api.reminder_service().flush_sink().await;
+        if let Some(pool) = &self.pool {
+            pool.close().await;
+        }
+        stopped.and(flushed)
+    }
+
+    /// Runs reminders from saved settings until SIGINT or SIGTERM, then shuts down.
+    ///
+    /// Returns the signal received, or the error that stopped the run: a failed start,
+    /// a fatal error or panic in the reminder loop, or a failed shutdown. The run ends
+    /// as soon as the loop's task does, without waiting for a signal.
+    pub async fn run_until_shutdown(&self) -> CoreResult<ShutdownSignal> {
+        self.run_until(shutdown_signal()?).await
+    }
+
+    /// Like `run_until_shutdown`, but stops when `shutdown` completes instead of on a signal.
+    pub async fn run_until(&self, shutdown: impl Future<Output = ShutdownSignal>) -> CoreResult<ShutdownSignal> {
+        let mut status: watch::Receiver<SchedulerStatus> = self.subscribe_scheduler_status().await;
+        let stopped_by: CoreResult<Option<ShutdownSignal>> = match self.start_with_saved_hydration_config().await {
+            Ok(()) => tokio::select! {
+                signal = shutdown => Ok(Some(signal)),
+                // The loop's task marks the status stopped whenever it ends, fatal error or panic.
+                _ = status.wait_for(|status: &SchedulerStatus| status.state == SchedulerState::Stopped) => Ok(None),
+            },
+            Err(err) => Err(err),
+        };
+        if let Ok(Some(signal)) = stopped_by {
+            tracing::info!("received {}, shutting down", signal.as_str());
+        }
+
+        let last_error: Option<SchedulerError> = status.borrow().last_error.clone();
+        let shut_down: CoreResult<()> = self.shutdown().await;
+        match (stopped_by?, shut_down) {
+            (_, Err(err)) => Err(err),
+            (Some(signal), Ok(())) => Ok(signal),
+            // Stopping a failed loop returns its error, unless a concurrent `stop` or
+            // `shutdown` already took it; report the error the status kept instead.
+            (None, Ok(())) => Err(CoreError::ReminderLoopStopped {
+                reason: last_error.map_or_else(
+                    || "the reminder loop ended before shutdown".to_string(),
+                    |err: SchedulerError| err.message,
+                ),
+            }),
+        }
     }
 }
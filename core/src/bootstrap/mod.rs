@@ -1,6 +1,11 @@
 //! Bootstrap/runtime layer that wires adapters and owns background tasks.
+mod config;
 mod core_builder;
 mod core_runtime;
+mod shutdown;
 
-pub use core_builder::{CoreBuilder, CoreOptions};
+pub use crate::application::settings_service::ReminderDefaults;
+pub use config::{default_config_path, ConfigLayer, CoreConfig, CONFIG_PATH_ENV};
+pub use core_builder::{CoreBuilder, CoreOptions, DEFAULT_DATABASE_URL, ReminderSinkKind, ReminderSinkOptions};
 pub use core_runtime::CoreRuntime;
+pub use shutdown::{shutdown_signal, ShutdownSignal};
@@ -0,0 +1,53 @@
+//! Process signals that ask a long-running core to shut down.
+use crate::error::CoreResult;
+use std::future::Future;
+
+/// Signal that asked the process to stop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShutdownSignal {
+    /// SIGINT, usually Ctrl-C.
+    Interrupt,
+    /// SIGTERM, e.g. from a service manager.
+    Terminate,
+}
+
+impl ShutdownSignal {
+    /// Returns the conventional signal name.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Interrupt => "SIGINT",
+            Self::Terminate => "SIGTERM",
+        }
+    }
+}
+
+/// Installs SIGINT and SIGTERM handlers and returns a future for the first to arrive.
+///
+/// Handlers are in place once this returns, so a signal sent before the future is
+/// polled is not lost. Platforms without SIGTERM only listen for Ctrl-C.
+pub fn shutdown_signal() -> CoreResult<impl Future<Output = ShutdownSignal>> {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, Signal, SignalKind};
+
+        let mut interrupt: Signal = signal(SignalKind::interrupt())?;
+        let mut terminate: Signal = signal(SignalKind::terminate())?;
+        Ok(async move {
+            tokio::select! {
+                _ = interrupt.recv() => ShutdownSignal::Interrupt,
+                _ = terminate.recv() => ShutdownSignal::Terminate,
+            }
+        })
+    }
+
+    #[cfg(not(unix))]
+    {
+        Ok(async {
+            if let Err(err) = tokio::signal::ctrl_c().await {
+                tracing::warn!("could not listen for Ctrl-C: {err}");
+                std::future::pending::<()>().await;
+            }
+            ShutdownSignal::Interrupt
+        })
+    }
+}
@@ -0,0 +1,206 @@
+//! Achievements unlocked by hydration streaks and journaling milestones.
+use chrono::{DateTime, Utc};
+
+/// What an achievement rule counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AchievementMetric {
+    /// Longest run of consecutive days meeting the hydration goal.
+    GoalStreakDays,
+    /// Days on which the hydration goal was met, in total.
+    GoalDays,
+    /// Journal entries written, one per date.
+    JournalEntries,
+}
+
+/// A milestone that unlocks once its metric reaches `threshold`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct AchievementRule {
+    /// Stable identifier stored with unlocks; never reuse or rename one.
+    pub id: &'static str,
+    pub title: &'static str,
+    pub description: &'static str,
+    pub metric: AchievementMetric,
+    pub threshold: u32,
+}
+
+/// Every achievement, in the order they are listed.
+pub static ACHIEVEMENTS: [AchievementRule; 7] = [
+    AchievementRule {
+        id: "first_goal",
+        title: "First goal",
+        description: "Meet your hydration goal for the first time",
+        metric: AchievementMetric::GoalDays,
+        threshold: 1,
+    },
+    AchievementRule {
+        id: "goal_streak_3",
+        title: "Three in a row",
+        description: "Meet your hydration goal 3 days in a row",
+        metric: AchievementMetric::GoalStreakDays,
+        threshold: 3,
+    },
+    AchievementRule {
+        id: "goal_streak_7",
+        title: "Hydrated week",
+        description: "Meet your hydration goal 7 days in a row",
+        metric: AchievementMetric::GoalStreakDays,
+        threshold: 7,
+    },
+    AchievementRule {
+        id: "goal_streak_30",
+        title: "Hydrated month",
+        description: "Meet your hydration goal 30 days in a row",
+        metric: AchievementMetric::GoalStreakDays,
+        threshold: 30,
+    },
+    AchievementRule {
+        id: "first_journal_entry",
+        title: "Dear diary",
+        description: "Write your first journal entry",
+        metric: AchievementMetric::JournalEntries,
+        threshold: 1,
+    },
+    AchievementRule {
+        id: "journal_entries_7",
+        title: "Regular writer",
+        description: "Write 7 journal entries",
+        metric: AchievementMetric::JournalEntries,
+        threshold: 7,
+    },
+    AchievementRule {
+        id: "journal_entries_30",
+        title: "Storyteller",
+        description: "Write 30 journal entries",
+        metric: AchievementMetric::JournalEntries,
+        threshold: 30,
+    },
+];
+
+/// Returns the highest goal-day threshold, i.e. how many recent days a check must read.
+pub fn goal_lookback_days() -> u32 {
+    ACHIEVEMENTS
+        .iter()
+        .filter(|rule: &&AchievementRule| rule.metric != AchievementMetric::JournalEntries)
+        .map(|rule: &AchievementRule| rule.threshold)
+        .max()
+        .unwrap_or(1)
+}
+
+impl AchievementRule {
+    /// Looks up a rule by its stored ID.
+    pub fn find(id: &str) -> Option<&'static Self> {
+        ACHIEVEMENTS.iter().find(|rule: &&AchievementRule| rule.id == id)
+    }
+
+    /// Returns the rule's metric value from `totals`, capped at the threshold.
+    pub fn progress(&self, totals: &AchievementTotals) -> u32 {
+        let value: u32 = match self.metric {
+            AchievementMetric::GoalStreakDays => totals.longest_goal_streak_days,
+            AchievementMetric::GoalDays => totals.goal_days,
+            AchievementMetric::JournalEntries => totals.journal_entries,
+        };
+        value.min(self.threshold)
+    }
+
+    /// Returns true once `totals` reach the threshold.
+    pub fn is_met(&self, totals: &AchievementTotals) -> bool {
+        self.progress(totals) >= self.threshold
+    }
+}
+
+/// Current values of every metric the rules count.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AchievementTotals {
+    pub longest_goal_streak_days: u32,
+    pub goal_days: u32,
+    pub journal_entries: u32,
+}
+
+/// A stored unlock; the ID may name a rule from a newer build.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AchievementUnlock {
+    pub id: String,
+    pub unlocked_at: DateTime<Utc>,
+}
+
+/// A rule with the user's progress towards it and when it unlocked, if it has.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Achievement {
+    pub rule: &'static AchievementRule,
+    pub progress: u32,
+    pub unlocked_at: Option<DateTime<Utc>>,
+}
+
+impl Achievement {
+    /// Builds the status of every rule from current totals and stored unlocks.
+    ///
+    /// Unlocks stay unlocked even if the totals later fall, for example after an undo.
+    pub fn all(totals: &AchievementTotals, unlocks: &[AchievementUnlock]) -> Vec<Self> {
+        ACHIEVEMENTS
+            .iter()
+            .map(|rule: &'static AchievementRule| Self {
+                rule,
+                progress: rule.progress(totals),
+                unlocked_at: unlocks
+                    .iter()
+                    .find(|unlock: &&AchievementUnlock| unlock.id == rule.id)
+                    .map(|unlock: &AchievementUnlock| unlock.unlocked_at),
+            })
+            .collect()
+    }
+
+    /// Returns true once the achievement has been unlocked.
+    pub fn is_unlocked(&self) -> bool {
+        self.unlocked_at.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn rule_ids_are_unique_and_findable() {
+        let ids: HashSet<&str> = ACHIEVEMENTS.iter().map(|rule: &AchievementRule| rule.id).collect();
+
+        assert_eq!(ids.len(), ACHIEVEMENTS.len());
+        assert_eq!(AchievementRule::find("goal_streak_7").map(|rule: &AchievementRule| rule.threshold), Some(7));
+        assert_eq!(AchievementRule::find("unknown"), None);
+        assert_eq!(goal_lookback_days(), 30);
+    }
+
+    #[test]
+    fn status_combines_progress_with_stored_unlocks() {
+        let totals: AchievementTotals = AchievementTotals {
+            longest_goal_streak_days: 4,
+            goal_days: 9,
+            journal_entries: 0,
+        };
+        let unlocks: Vec<AchievementUnlock> = vec![
+            AchievementUnlock {
+                id: "first_journal_entry".to_string(),
+                unlocked_at: DateTime::UNIX_EPOCH,
+            },
+            AchievementUnlock {
+                id: "from_a_newer_build".to_string(),
+                unlocked_at: DateTime::UNIX_EPOCH,
+            },
+        ];
+
+        let all: Vec<Achievement> = Achievement::all(&totals, &unlocks);
+        let status = |id: &str| -> Achievement {
+            *all.iter()
+                .find(|achievement: &&Achievement| achievement.rule.id == id)
+                .expect("known rule")
+        };
+
+        assert_eq!(all.len(), ACHIEVEMENTS.len());
+        assert!(status("goal_streak_3").rule.is_met(&totals));
+        assert_eq!(status("goal_streak_7").progress, 4);
+        assert!(!status("goal_streak_7").rule.is_met(&totals));
+        assert_eq!(status("first_goal").progress, 1);
+        assert!(status("first_journal_entry").is_unlocked());
+        assert!(!status("goal_streak_3").is_unlocked());
+    }
+}
@@ -0,0 +1,326 @@
+//! Versioned exports of settings and recorded history, and merging them back in.
+use crate::domain::history::ReminderLogEntry;
+use crate::domain::hydration::{HydrationGoal, ReminderConfig, WaterIntake};
+use crate::domain::journal::JournalEntry;
+use chrono::{DateTime, Utc};
+use std::str::FromStr;
+
+/// Name written into every export document so readers can recognize one.
+pub const EXPORT_FORMAT: &str = "desk-buddy-export";
+/// Version of the export document layout; bump it whenever a field changes meaning.
+pub const EXPORT_VERSION: u32 = 1;
+
+/// Recorded history an export can include, listed in the order it is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExportTable {
+    ReminderLogs,
+    WaterIntake,
+    JournalEntries,
+}
+
+impl ExportTable {
+    pub const ALL: [Self; 3] = [Self::ReminderLogs, Self::WaterIntake, Self::JournalEntries];
+
+    /// Returns the stable name used for document keys and file names.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::ReminderLogs => "reminder_logs",
+            Self::WaterIntake => "water_intake",
+            Self::JournalEntries => "journal_entries",
+        }
+    }
+}
+
+impl FromStr for ExportTable {
+    type Err = ();
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        match raw.trim().to_ascii_lowercase().replace('-', "_").as_str() {
+            "reminder_logs" | "reminders" => Ok(Self::ReminderLogs),
+            "water_intake" | "intake" => Ok(Self::WaterIntake),
+            "journal_entries" | "journal" => Ok(Self::JournalEntries),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Settings in effect when an export was taken.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportSettings {
+    pub reminder_config: ReminderConfig,
+    pub goal: HydrationGoal,
+}
+
+/// How many records an export wrote for each table.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExportSummary {
+    pub reminder_logs: u64,
+    pub water_intake: u64,
+    pub journal_entries: u64,
+}
+
+impl ExportSummary {
+    /// Returns the count written for `table`.
+    pub fn count(&self, table: ExportTable) -> u64 {
+        match table {
+            ExportTable::ReminderLogs => self.reminder_logs,
+            ExportTable::WaterIntake => self.water_intake,
+            ExportTable::JournalEntries => self.journal_entries,
+        }
+    }
+
+    /// Returns the count slot for `table`, for the service to fill in.
+    pub fn count_mut(&mut self, table: ExportTable) -> &mut u64 {
+        match table {
+            ExportTable::ReminderLogs => &mut self.reminder_logs,
+            ExportTable::WaterIntake => &mut self.water_intake,
+            ExportTable::JournalEntries => &mut self.journal_entries,
+        }
+    }
+}
+
+/// An export read back for importing; record IDs are those of the exporting database.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportDocument {
+    pub version: u32,
+    pub exported_at: DateTime<Utc>,
+    pub settings: ExportSettings,
+    pub records: ImportRecords,
+}
+
+/// Records to merge into the database, each table oldest first.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportRecords {
+    pub reminder_logs: Vec<ReminderLogEntry>,
+    pub water_intake: Vec<WaterIntake>,
+    pub journal_entries: Vec<JournalEntry>,
+}
+
+/// How an import treats a record whose key (reminder time, drink time or journal
+/// date) matches a stored one with different content.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ImportStrategy {
+    /// Keep the stored record.
+    #[default]
+    SkipDuplicates,
+    /// Replace the stored record with the imported one.
+    Overwrite,
+    /// Add every reminder and drink as a new row, even when a match exists; journal
+    /// entries, one per date, get the imported text appended instead.
+    Append,
+}
+
+impl ImportStrategy {
+    /// Decides what to do with one imported record.
+    ///
+    /// `one_per_key` marks records that can never be stored twice: journal entries
+    /// and settings.
+    pub fn resolve(self, found: RecordMatch, one_per_key: bool) -> ImportAction {
+        match (found, self) {
+            (RecordMatch::None, _) => ImportAction::Insert,
+            (_, Self::Append) if !one_per_key => ImportAction::Insert,
+            (RecordMatch::Identical, _) => ImportAction::Skip,
+            (RecordMatch::Different, Self::SkipDuplicates) => ImportAction::KeepExisting,
+            (RecordMatch::Different, Self::Overwrite) => ImportAction::Replace,
+            (RecordMatch::Different, Self::Append) => ImportAction::Merge,
+        }
+    }
+
+    /// Returns the stable lowercase name used by inbound adapters.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::SkipDuplicates => "skip_duplicates",
+            Self::Overwrite => "overwrite",
+            Self::Append => "append",
+        }
+    }
+}
+
+impl FromStr for ImportStrategy {
+    type Err = ();
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        match raw.trim().to_ascii_lowercase().replace('-', "_").as_str() {
+            "skip_duplicates" | "skip" => Ok(Self::SkipDuplicates),
+            "overwrite" => Ok(Self::Overwrite),
+            "append" => Ok(Self::Append),
+            _ => Err(()),
+        }
+    }
+}
+
+/// How an imported record compares with the stored record that has the same key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordMatch {
+    None,
+    Identical,
+    Different,
+}
+
+impl RecordMatch {
+    /// Compares `imported` with the stored records found under its key.
+    pub fn among<T: ImportRecord>(existing: &[T], imported: &T) -> Self {
+        if existing.is_empty() {
+            Self::None
+        } else if existing.iter().any(|stored: &T| stored.same_content(imported)) {
+            Self::Identical
+        } else {
+            Self::Different
+        }
+    }
+}
+
+/// A record an import can match against stored ones.
+pub trait ImportRecord {
+    /// Returns true when both records say the same thing, ignoring IDs.
+    fn same_content(&self, other: &Self) -> bool;
+}
+
+impl ImportRecord for ReminderLogEntry {
+    fn same_content(&self, other: &Self) -> bool {
+        self.triggered_at == other.triggered_at
+            && self.message == other.message
+            && self.outcome == other.outcome
+            && self.responded_at == other.responded_at
+    }
+}
+
+impl ImportRecord for WaterIntake {
+    fn same_content(&self, other: &Self) -> bool {
+        self.consumed_at == other.consumed_at && self.amount_ml == other.amount_ml && self.source == other.source
+    }
+}
+
+impl ImportRecord for JournalEntry {
+    fn same_content(&self, other: &Self) -> bool {
+        self.entry_date == other.entry_date && self.body == other.body
+    }
+}
+
+impl ImportRecord for ExportSettings {
+    fn same_content(&self, other: &Self) -> bool {
+        let without_pause = |config: &ReminderConfig| ReminderConfig {
+            pause: None,
+            ..*config
+        };
+        without_pause(&self.reminder_config) == without_pause(&other.reminder_config) && self.goal == other.goal
+    }
+}
+
+/// What an import does with one record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportAction {
+    /// Store it as a new record.
+    Insert,
+    /// Leave the identical stored record alone.
+    Skip,
+    /// Leave the differing stored record alone.
+    KeepExisting,
+    /// Replace the differing stored record.
+    Replace,
+    /// Combine it with the differing stored record; settings keep the stored values.
+    Merge,
+}
+
+impl ImportAction {
+    /// Returns true when the imported record conflicted with a stored one.
+    pub fn is_conflict(self) -> bool {
+        matches!(self, Self::KeepExisting | Self::Replace | Self::Merge)
+    }
+}
+
+/// Outcome counts for one table of an import.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ImportCounts {
+    /// Records added as new rows.
+    pub inserted: u64,
+    /// Records identical to a stored one, left as they were.
+    pub skipped: u64,
+    /// Records that differed from the stored one with the same key; the strategy
+    /// decided whether they were kept, replaced or merged.
+    pub conflicting: u64,
+}
+
+impl ImportCounts {
+    /// Counts one record handled with `action`.
+    pub fn record(&mut self, action: ImportAction) {
+        match action {
+            ImportAction::Insert => self.inserted += 1,
+            ImportAction::Skip => self.skipped += 1,
+            ImportAction::KeepExisting | ImportAction::Replace | ImportAction::Merge => self.conflicting += 1,
+        }
+    }
+}
+
+/// Outcome counts for a whole import; settings count as a single record.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ImportSummary {
+    pub settings: ImportCounts,
+    pub reminder_logs: ImportCounts,
+    pub water_intake: ImportCounts,
+    pub journal_entries: ImportCounts,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tables_round_trip_through_names() {
+        for table in ExportTable::ALL {
+            assert_eq!(table.as_str().parse::<ExportTable>(), Ok(table));
+        }
+        assert_eq!("journal".parse::<ExportTable>(), Ok(ExportTable::JournalEntries));
+        assert!("settings".parse::<ExportTable>().is_err());
+
+        let mut summary: ExportSummary = ExportSummary::default();
+        *summary.count_mut(ExportTable::WaterIntake) += 2;
+        assert_eq!(summary.count(ExportTable::WaterIntake), 2);
+        assert_eq!(summary.count(ExportTable::ReminderLogs), 0);
+    }
+
+    #[test]
+    fn strategies_resolve_matches_per_record_kind() {
+        use ImportAction::*;
+        use RecordMatch::{Different, Identical};
+
+        for strategy in [ImportStrategy::SkipDuplicates, ImportStrategy::Overwrite, ImportStrategy::Append] {
+            assert_eq!(strategy.resolve(RecordMatch::None, true), Insert);
+            assert_eq!(strategy.resolve(Identical, true), Skip);
+            assert_eq!(strategy.as_str().parse::<ImportStrategy>(), Ok(strategy));
+        }
+        assert_eq!(ImportStrategy::SkipDuplicates.resolve(Identical, false), Skip);
+        assert_eq!(ImportStrategy::SkipDuplicates.resolve(Different, false), KeepExisting);
+        assert_eq!(ImportStrategy::Overwrite.resolve(Different, false), Replace);
+        assert_eq!(ImportStrategy::Append.resolve(Identical, false), Insert);
+        assert_eq!(ImportStrategy::Append.resolve(Different, false), Insert);
+        assert_eq!(ImportStrategy::Append.resolve(Different, true), Merge);
+
+        let mut counts: ImportCounts = ImportCounts::default();
+        for action in [Insert, Skip, Replace, Merge] {
+            counts.record(action);
+        }
+        assert_eq!(
+            counts,
+            ImportCounts {
+                inserted: 1,
+                skipped: 1,
+                conflicting: 2
+            }
+        );
+    }
+
+    #[test]
+    fn records_match_on_content_and_ignore_ids() {
+        let drink = |id: i64, amount_ml: u32| WaterIntake {
+            id,
+            amount_ml,
+            consumed_at: DateTime::UNIX_EPOCH,
+            source: None,
+        };
+
+        assert_eq!(RecordMatch::among(&[], &drink(1, 250)), RecordMatch::None);
+        assert_eq!(RecordMatch::among(&[drink(8, 300), drink(9, 250)], &drink(1, 250)), RecordMatch::Identical);
+        assert_eq!(RecordMatch::among(&[drink(9, 300)], &drink(1, 250)), RecordMatch::Different);
+    }
+}
@@ -0,0 +1,223 @@
+//! Paged, time-ordered listings of past reminders and drinks.
+use chrono::{DateTime, Utc};
+use std::str::FromStr;
+
+/// Page size used when a caller does not choose one.
+pub const DEFAULT_HISTORY_PAGE_SIZE: u32 = 50;
+/// Largest page a history listing returns.
+pub const MAX_HISTORY_PAGE_SIZE: u32 = 500;
+
+/// Direction in which history is listed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortOrder {
+    #[default]
+    NewestFirst,
+    OldestFirst,
+}
+
+impl SortOrder {
+    /// Returns the stable lowercase name used by inbound adapters.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::NewestFirst => "newest_first",
+            Self::OldestFirst => "oldest_first",
+        }
+    }
+}
+
+impl FromStr for SortOrder {
+    type Err = ();
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        match raw.trim().to_ascii_lowercase().replace('-', "_").as_str() {
+            "newest_first" | "desc" => Ok(Self::NewestFirst),
+            "oldest_first" | "asc" => Ok(Self::OldestFirst),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Position of the last item on a page, as its timestamp and ID.
+///
+/// Listings continue strictly after it, so rows added in the meantime never shift
+/// later pages the way an offset would.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HistoryCursor {
+    pub at: DateTime<Utc>,
+    pub id: i64,
+}
+
+impl HistoryCursor {
+    /// Returns the opaque token handed to clients: `<unix nanoseconds>.<id>`.
+    pub fn token(self) -> String {
+        format!("{}.{}", self.at.timestamp_nanos_opt().unwrap_or(i64::MAX), self.id)
+    }
+}
+
+impl FromStr for HistoryCursor {
+    type Err = ();
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        let (nanos, id): (&str, &str) = raw.trim().split_once('.').ok_or(())?;
+        Ok(Self {
+            at: DateTime::from_timestamp_nanos(nanos.parse::<i64>().map_err(|_| ())?),
+            id: id.parse::<i64>().map_err(|_| ())?,
+        })
+    }
+}
+
+/// Time range, order and position for one page of history.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HistoryQuery {
+    /// Inclusive start of the range.
+    pub from: Option<DateTime<Utc>>,
+    /// Exclusive end of the range.
+    pub until: Option<DateTime<Utc>>,
+    pub order: SortOrder,
+    /// Requested page size; see `page_size`.
+    pub limit: u32,
+    /// Continue after this position, taken from the previous page's `next_cursor`.
+    pub after: Option<HistoryCursor>,
+}
+
+impl HistoryQuery {
+    /// Returns the page size clamped to `1..=MAX_HISTORY_PAGE_SIZE`.
+    pub fn page_size(&self) -> u32 {
+        self.limit.clamp(1, MAX_HISTORY_PAGE_SIZE)
+    }
+
+    /// Returns false when both bounds are set and the range is empty.
+    pub fn has_valid_range(&self) -> bool {
+        match (self.from, self.until) {
+            (Some(from), Some(until)) => from < until,
+            _ => true,
+        }
+    }
+}
+
+impl Default for HistoryQuery {
+    fn default() -> Self {
+        Self {
+            from: None,
+            until: None,
+            order: SortOrder::default(),
+            limit: DEFAULT_HISTORY_PAGE_SIZE,
+            after: None,
+        }
+    }
+}
+
+/// One page of history and where the next one starts, if there is more.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryPage<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<HistoryCursor>,
+}
+
+impl<T> HistoryPage<T> {
+    /// Builds a page from up to `page_size + 1` rows; an extra row only means more exist.
+    pub fn from_rows(mut rows: Vec<T>, page_size: u32, position: impl Fn(&T) -> HistoryCursor) -> Self {
+        let page_size: usize = usize::try_from(page_size).unwrap_or(usize::MAX);
+        let more: bool = rows.len() > page_size;
+        rows.truncate(page_size);
+        let next_cursor: Option<HistoryCursor> = if more { rows.last().map(position) } else { None };
+
+        Self {
+            items: rows,
+            next_cursor,
+        }
+    }
+}
+
+/// How a logged reminder was answered; drink amounts are stored as intake instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReminderOutcome {
+    Drank,
+    Snoozed { minutes: u32 },
+    Dismissed,
+}
+
+impl ReminderOutcome {
+    /// Rebuilds a stored outcome; unknown names read as unanswered.
+    pub fn from_stored(outcome: &str, snooze_minutes: Option<u32>) -> Option<Self> {
+        match outcome {
+            "drank" => Some(Self::Drank),
+            "snoozed" => Some(Self::Snoozed {
+                minutes: snooze_minutes.unwrap_or(0),
+            }),
+            "dismissed" => Some(Self::Dismissed),
+            _ => None,
+        }
+    }
+
+    /// Returns the stable outcome name, matching `ReminderResponse::outcome`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Drank => "drank",
+            Self::Snoozed { .. } => "snoozed",
+            Self::Dismissed => "dismissed",
+        }
+    }
+}
+
+/// A logged reminder with the user's answer, if any.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReminderLogEntry {
+    pub id: i64,
+    pub message: String,
+    pub triggered_at: DateTime<Utc>,
+    pub outcome: Option<ReminderOutcome>,
+    pub responded_at: Option<DateTime<Utc>>,
+}
+
+impl ReminderLogEntry {
+    /// Returns the cursor that continues a listing after this entry.
+    pub fn cursor(&self) -> HistoryCursor {
+        HistoryCursor {
+            at: self.triggered_at,
+            id: self.id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursors_round_trip_through_tokens() {
+        let cursor: HistoryCursor = HistoryCursor {
+            at: DateTime::from_timestamp_nanos(1_767_225_600_123_456_789),
+            id: 42,
+        };
+
+        assert_eq!(cursor.token(), "1767225600123456789.42");
+        assert_eq!(cursor.token().parse::<HistoryCursor>(), Ok(cursor));
+        assert!("42".parse::<HistoryCursor>().is_err());
+        assert!("soon.42".parse::<HistoryCursor>().is_err());
+    }
+
+    #[test]
+    fn pages_keep_the_limit_and_point_past_the_last_item() {
+        let at = |id: i64| HistoryCursor {
+            at: DateTime::from_timestamp_nanos(id),
+            id,
+        };
+
+        let full: HistoryPage<i64> = HistoryPage::from_rows(vec![5, 4, 3], 2, |id: &i64| at(*id));
+        assert_eq!(full.items, vec![5, 4]);
+        assert_eq!(full.next_cursor, Some(at(4)));
+        let last: HistoryPage<i64> = HistoryPage::from_rows(vec![2, 1], 2, |id: &i64| at(*id));
+        assert_eq!(last.next_cursor, None);
+
+        let query: HistoryQuery = HistoryQuery {
+            limit: 0,
+            from: Some(at(2).at),
+            until: Some(at(1).at),
+            ..HistoryQuery::default()
+        };
+        assert_eq!(query.page_size(), 1);
+        assert!(!query.has_valid_range());
+        assert_eq!(HistoryQuery::default().page_size(), DEFAULT_HISTORY_PAGE_SIZE);
+    }
+}
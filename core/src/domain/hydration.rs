@@ -1,38 +1,1006 @@
 //! Domain types and policies for hydration reminder behavior.
+use chrono::{
+    DateTime, Datelike, Days, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc, Weekday,
+};
+use crate::domain::history::HistoryCursor;
+use std::fmt::{Display, Formatter};
+use std::iter::Peekable;
+use std::str::FromStr;
 use std::time::Duration;
 
 pub const DEFAULT_REMINDER_MESSAGE: &str = "Reminder: drink water!";
+pub const DEFAULT_DAILY_GOAL_ML: u32 = 2_000;
+/// Largest single intake accepted, to catch unit mistakes (for example litres typed as ml).
+pub const MAX_INTAKE_ML: u32 = 5_000;
+/// Longest interval between reminders: one week.
+pub const MAX_INTERVAL_MINUTES: u64 = 7 * 24 * 60;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ReminderConfig {
     pub interval_minutes: u64,
+    /// When set, reminders follow this cron schedule instead of `interval_minutes`.
+    pub cron: Option<CronSchedule>,
+    /// When set, reminders only fire inside this daily window.
+    pub active_window: Option<ActiveWindow>,
+    /// When set, reminders never fire inside these hours, even within the active window.
+    pub quiet_hours: Option<QuietHours>,
+    /// What to do with reminders that came due while the machine was asleep.
+    pub catch_up: CatchUpPolicy,
+    /// When set, reminders are held until the pause ends or is lifted.
+    pub pause: Option<ReminderPause>,
 }
 
 impl ReminderConfig {
-    /// Creates a reminder config and clamps the interval to at least one minute.
+    /// Creates a reminder config and clamps the interval to 1-`MAX_INTERVAL_MINUTES` minutes.
     pub fn new(interval_minutes: u64) -> Self {
         Self {
-            interval_minutes: interval_minutes.max(1),
+            interval_minutes: interval_minutes.clamp(1, MAX_INTERVAL_MINUTES),
+            cron: None,
+            active_window: None,
+            quiet_hours: None,
+            catch_up: CatchUpPolicy::default(),
+            pause: None,
         }
     }
 
+    /// Returns a copy that follows the given cron schedule (or the interval, when `None`).
+    pub fn with_cron(self, cron: Option<CronSchedule>) -> Self {
+        Self { cron, ..self }
+    }
+
+    /// Returns a copy restricted to the given active window.
+    pub fn with_active_window(self, active_window: Option<ActiveWindow>) -> Self {
+        Self {
+            active_window,
+            ..self
+        }
+    }
+
+    /// Returns a copy that stays silent during the given quiet hours.
+    pub fn with_quiet_hours(self, quiet_hours: Option<QuietHours>) -> Self {
+        Self {
+            quiet_hours,
+            ..self
+        }
+    }
+
+    /// Returns a copy using the given missed-reminder policy.
+    pub fn with_catch_up(self, catch_up: CatchUpPolicy) -> Self {
+        Self { catch_up, ..self }
+    }
+
+    /// Returns a copy that holds reminders for the given pause.
+    pub fn with_pause(self, pause: Option<ReminderPause>) -> Self {
+        Self { pause, ..self }
+    }
+
+    /// Returns the pause still in effect at `now`; expired pauses are ignored.
+    pub fn active_pause(self, now: DateTime<Utc>) -> Option<ReminderPause> {
+        self.pause.filter(|pause: &ReminderPause| pause.is_active_at(now))
+    }
+
     /// Converts the configured interval (minutes) into a `Duration`.
     pub fn interval_duration(self) -> Duration {
         Duration::from_secs(self.interval_minutes.saturating_mul(60))
     }
+
+    /// Returns when the reminder after one at `previous` is due: the next cron match,
+    /// or one interval later (the end of time if that is out of range).
+    pub fn next_due<Tz: TimeZone>(self, previous: DateTime<Utc>, timezone: &Tz) -> DateTime<Utc> {
+        self.cron
+            .and_then(|cron: CronSchedule| cron.next_after(previous, timezone))
+            .unwrap_or_else(|| saturating_add(previous, self.interval_duration()))
+    }
+
+    /// Counts the reminders that came due between `due` and `now`, inclusive, up to
+    /// `MAX_CATCH_UP_REMINDERS`; no policy replays more than that.
+    pub fn missed_reminders<Tz: TimeZone>(self, due: DateTime<Utc>, now: DateTime<Utc>, timezone: &Tz) -> u32 {
+        let Some(cron) = self.cron else {
+            return missed_slots(due, now, self.interval_duration()).min(MAX_CATCH_UP_REMINDERS);
+        };
+        if now < due {
+            return 0;
+        }
+
+        let mut missed: u32 = 1;
+        let mut slot: DateTime<Utc> = due;
+        while missed < MAX_CATCH_UP_REMINDERS {
+            match cron.next_after(slot, timezone) {
+                Some(next) if next <= now => {
+                    missed += 1;
+                    slot = next;
+                }
+                _ => break,
+            }
+        }
+
+        missed
+    }
+
+    /// Returns true when a reminder may fire at the given local wall-clock time.
+    pub fn allows(self, local: NaiveDateTime) -> bool {
+        let in_window: bool = self
+            .active_window
+            .is_none_or(|window: ActiveWindow| window.contains(local));
+        let in_quiet_hours: bool = self
+            .quiet_hours
+            .is_some_and(|quiet: QuietHours| quiet.contains(local.time()));
+
+        in_window && !in_quiet_hours
+    }
+
+    /// Returns the earliest instant at or after `now` when reminders are allowed, or
+    /// `None` when the window and quiet hours leave no time at all.
+    pub fn next_allowed<Tz: TimeZone>(self, now: DateTime<Utc>, timezone: &Tz) -> Option<DateTime<Utc>> {
+        let local_now: NaiveDateTime = now.with_timezone(timezone).naive_local();
+        if self.allows(local_now) {
+            return Some(now);
+        }
+
+        // Allowed time can only begin where a window opens or quiet hours end, so
+        // checking those boundaries over the next week finds the earliest one.
+        let mut boundaries: Vec<NaiveDateTime> = Vec::new();
+        for offset in 0..=7 {
+            let Some(date) = local_now.date().checked_add_days(Days::new(offset)) else {
+                break;
+            };
+            if let Some(window) = self.active_window {
+                boundaries.push(date.and_time(window.start));
+            }
+            if let Some(quiet) = self.quiet_hours {
+                boundaries.push(date.and_time(quiet.end));
+            }
+        }
+        boundaries.sort();
+
+        boundaries
+            .into_iter()
+            .filter(|boundary: &NaiveDateTime| *boundary > local_now && self.allows(*boundary))
+            .map(|boundary: NaiveDateTime| resolve_local(timezone, boundary))
+            .find(|candidate: &DateTime<Utc>| *candidate >= now)
+    }
 }
 
 impl Default for ReminderConfig {
     fn default() -> Self {
         Self {
             interval_minutes: 30,
+            cron: None,
+            active_window: None,
+            quiet_hours: None,
+            catch_up: CatchUpPolicy::default(),
+            pause: None,
+        }
+    }
+}
+
+/// Reminder settings as saved in storage; `None` fields were never saved and use defaults.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SavedReminderSettings {
+    pub interval_minutes: Option<u64>,
+    pub cron: Option<CronSchedule>,
+    pub active_window: Option<ActiveWindow>,
+    /// `Some(None)` when quiet hours were turned off.
+    pub quiet_hours: Option<Option<QuietHours>>,
+    pub catch_up: Option<CatchUpPolicy>,
+    /// May already have expired.
+    pub pause: Option<ReminderPause>,
+}
+
+/// How long a reminder may be overdue before it counts as missed rather than late.
+pub const MISSED_REMINDER_GRACE: Duration = Duration::from_secs(2 * 60);
+/// Upper bound on reminders replayed at once by `CatchUpPolicy::FireAll`.
+pub const MAX_CATCH_UP_REMINDERS: u32 = 12;
+
+/// What the scheduler does with reminders that came due during sleep or suspend.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CatchUpPolicy {
+    /// Fire a single reminder on wake, however many were missed.
+    #[default]
+    FireOnce,
+    /// Drop missed reminders and continue from now.
+    Skip,
+    /// Fire every missed reminder (up to `MAX_CATCH_UP_REMINDERS`).
+    FireAll,
+}
+
+impl CatchUpPolicy {
+    /// Returns the stable lowercase name used for persistence.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::FireOnce => "fire_once",
+            Self::Skip => "skip",
+            Self::FireAll => "fire_all",
+        }
+    }
+
+    /// Returns how many reminders to fire for `missed` overdue slots.
+    pub fn reminders_to_fire(self, missed: u32) -> u32 {
+        match self {
+            Self::FireOnce => missed.min(1),
+            Self::Skip => 0,
+            Self::FireAll => missed.min(MAX_CATCH_UP_REMINDERS),
+        }
+    }
+}
+
+impl FromStr for CatchUpPolicy {
+    type Err = ();
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        match raw.trim().to_ascii_lowercase().replace('-', "_").as_str() {
+            "fire_once" => Ok(Self::FireOnce),
+            "skip" => Ok(Self::Skip),
+            "fire_all" => Ok(Self::FireAll),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Returns `at + delay`, or `DateTime::<Utc>::MAX_UTC` when that is out of range.
+pub fn saturating_add(at: DateTime<Utc>, delay: Duration) -> DateTime<Utc> {
+    chrono::Duration::from_std(delay)
+        .ok()
+        .and_then(|delay: chrono::Duration| at.checked_add_signed(delay))
+        .unwrap_or(DateTime::<Utc>::MAX_UTC)
+}
+
+/// Counts scheduled slots (`due`, `due + interval`, ...) that have passed by `now`.
+pub fn missed_slots(due: DateTime<Utc>, now: DateTime<Utc>, interval: Duration) -> u32 {
+    if now < due {
+        return 0;
+    }
+
+    let overdue_secs: u64 = u64::try_from((now - due).num_seconds()).unwrap_or(0);
+    let interval_secs: u64 = interval.as_secs().max(1);
+    u32::try_from(overdue_secs / interval_secs + 1).unwrap_or(u32::MAX)
+}
+
+/// Set of weekdays stored as a Monday-first bitmask.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WeekdaySet(u8);
+
+impl WeekdaySet {
+    pub const ALL: Self = Self(0b111_1111);
+    pub const WEEKDAYS: Self = Self(0b001_1111);
+
+    /// Builds a set from a persisted bitmask, ignoring bits beyond Sunday.
+    pub fn from_bits(bits: u8) -> Self {
+        Self(bits & Self::ALL.0)
+    }
+
+    /// Builds a set containing the given days.
+    pub fn from_days(days: &[Weekday]) -> Self {
+        Self(days.iter().fold(0, |bits: u8, day: &Weekday| {
+            bits | (1 << day.num_days_from_monday())
+        }))
+    }
+
+    /// Returns the Monday-first bitmask.
+    pub fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Returns true when the set contains `day`.
+    pub fn contains(self, day: Weekday) -> bool {
+        self.0 & (1 << day.num_days_from_monday()) != 0
+    }
+
+    /// Returns true when no day is selected.
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+/// Same-day local window (for example 09:00-18:00 on weekdays) in which reminders fire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ActiveWindow {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+    pub days: WeekdaySet,
+}
+
+impl ActiveWindow {
+    /// Creates a window; `start` must precede `end` and at least one day must be selected.
+    pub fn new(start: NaiveTime, end: NaiveTime, days: WeekdaySet) -> Option<Self> {
+        if start >= end || days.is_empty() {
+            return None;
+        }
+
+        Some(Self { start, end, days })
+    }
+
+    /// Returns true when `local` falls on a selected day within `[start, end)`.
+    pub fn contains(self, local: NaiveDateTime) -> bool {
+        self.days.contains(local.weekday()) && local.time() >= self.start && local.time() < self.end
+    }
+}
+
+/// Daily local span (for example 22:00-07:00) during which reminders stay silent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuietHours {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+impl QuietHours {
+    /// Creates quiet hours; spans may cross midnight but must not be empty.
+    pub fn new(start: NaiveTime, end: NaiveTime) -> Option<Self> {
+        if start == end {
+            return None;
+        }
+
+        Some(Self { start, end })
+    }
+
+    /// Returns true when `time` falls within `[start, end)`, wrapping past midnight.
+    pub fn contains(self, time: NaiveTime) -> bool {
+        if self.start < self.end {
+            time >= self.start && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+}
+
+/// Field of a five-field cron expression, named in validation errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CronField {
+    Minute,
+    Hour,
+    DayOfMonth,
+    Month,
+    DayOfWeek,
+}
+
+impl CronField {
+    const ALL: [Self; 5] = [Self::Minute, Self::Hour, Self::DayOfMonth, Self::Month, Self::DayOfWeek];
+
+    /// Returns the field name used in error messages.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Minute => "minute",
+            Self::Hour => "hour",
+            Self::DayOfMonth => "day-of-month",
+            Self::Month => "month",
+            Self::DayOfWeek => "day-of-week",
+        }
+    }
+
+    /// Returns the inclusive range of accepted values (day-of-week allows `7` for Sunday).
+    fn bounds(self) -> (u32, u32) {
+        match self {
+            Self::Minute => (0, 59),
+            Self::Hour => (0, 23),
+            Self::DayOfMonth => (1, 31),
+            Self::Month => (1, 12),
+            Self::DayOfWeek => (0, 7),
+        }
+    }
+
+    /// Returns the three-letter names accepted for this field, indexed from its minimum.
+    fn names(self) -> &'static [&'static str] {
+        match self {
+            Self::Month => &[
+                "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+            ],
+            Self::DayOfWeek => &["sun", "mon", "tue", "wed", "thu", "fri", "sat"],
+            Self::Minute | Self::Hour | Self::DayOfMonth => &[],
+        }
+    }
+
+    fn full_mask(self) -> u64 {
+        let (min, max): (u32, u32) = self.bounds();
+        let max: u32 = if self == Self::DayOfWeek { 6 } else { max };
+        (min..=max).fold(0, |mask: u64, value: u32| mask | (1 << value))
+    }
+}
+
+/// Why a cron expression was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CronError {
+    /// The expression did not have exactly five whitespace-separated fields.
+    FieldCount(usize),
+    /// A token is neither a number, a known name, nor `*`.
+    InvalidValue { field: CronField, token: String },
+    /// A number lies outside the field's allowed range.
+    OutOfRange { field: CronField, value: u32 },
+    /// A range such as `17-9` runs backwards.
+    ReversedRange { field: CronField, token: String },
+    /// A step such as `*/0` is not a positive number.
+    InvalidStep { field: CronField, token: String },
+    /// The day-of-month never occurs in the selected months (for example `31 2`).
+    NeverMatches,
+}
+
+impl Display for CronError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FieldCount(count) => write!(
+                f,
+                "expected 5 fields (minute hour day-of-month month day-of-week), found {count}"
+            ),
+            Self::InvalidValue { field, token } => {
+                write!(f, "invalid {} value {token:?}", field.name())
+            }
+            Self::OutOfRange { field, value } => {
+                let (min, max): (u32, u32) = field.bounds();
+                write!(f, "{} value {value} is out of range ({min}-{max})", field.name())
+            }
+            Self::ReversedRange { field, token } => {
+                write!(f, "{} range {token:?} ends before it starts", field.name())
+            }
+            Self::InvalidStep { field, token } => {
+                write!(f, "{} step in {token:?} must be a positive number", field.name())
+            }
+            Self::NeverMatches => write!(f, "day-of-month never occurs in the selected months"),
+        }
+    }
+}
+
+impl std::error::Error for CronError {}
+
+/// How far ahead `CronSchedule::next_after` searches; covers leap-day schedules.
+const CRON_SEARCH_DAYS: u64 = 8 * 366;
+
+/// Standard five-field cron schedule evaluated against local wall-clock time.
+///
+/// Fields accept `*`, numbers, ranges (`9-17`), lists (`0,30`), steps (`*/15`, `9-17/2`)
+/// and three-letter month and weekday names. As in cron, when both day-of-month and
+/// day-of-week are restricted a day matching either one fires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+}
+
+impl CronSchedule {
+    /// Returns the first fire time strictly after `after`.
+    ///
+    /// Local times skipped by a spring-forward transition do not fire; local times
+    /// repeated by a fall-back transition fire once, at their first occurrence.
+    pub fn next_after<Tz: TimeZone>(&self, after: DateTime<Utc>, timezone: &Tz) -> Option<DateTime<Utc>> {
+        let local_after: NaiveDateTime = after.with_timezone(timezone).naive_local();
+        // Starting a day early covers fire times pulled back across midnight by an offset change.
+        let first_date: NaiveDate = local_after.date().pred_opt()?;
+
+        for offset in 0..=CRON_SEARCH_DAYS {
+            let date: NaiveDate = first_date.checked_add_days(Days::new(offset))?;
+            if !self.matches_date(date) {
+                continue;
+            }
+
+            for hour in set_bits(self.hours) {
+                for minute in set_bits(self.minutes) {
+                    let Some(local) = date.and_hms_opt(hour, minute, 0) else {
+                        continue;
+                    };
+                    let candidate: DateTime<Utc> = match timezone.from_local_datetime(&local) {
+                        LocalResult::Single(resolved) => resolved.with_timezone(&Utc),
+                        LocalResult::Ambiguous(earliest, _) => earliest.with_timezone(&Utc),
+                        LocalResult::None => continue,
+                    };
+                    if candidate > after {
+                        return Some(candidate);
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    fn matches_date(&self, date: NaiveDate) -> bool {
+        if self.months & (1 << date.month()) == 0 {
+            return false;
+        }
+
+        let dom_matches: bool = self.days_of_month & (1 << date.day()) != 0;
+        let dow_matches: bool = self.days_of_week & (1 << date.weekday().num_days_from_sunday()) != 0;
+        match (self.restricts(CronField::DayOfMonth), self.restricts(CronField::DayOfWeek)) {
+            (true, true) => dom_matches || dow_matches,
+            (true, false) => dom_matches,
+            (false, true) => dow_matches,
+            (false, false) => true,
+        }
+    }
+
+    fn mask(&self, field: CronField) -> u64 {
+        match field {
+            CronField::Minute => self.minutes,
+            CronField::Hour => self.hours,
+            CronField::DayOfMonth => self.days_of_month,
+            CronField::Month => self.months,
+            CronField::DayOfWeek => self.days_of_week,
+        }
+    }
+
+    /// A field restricts matching unless it covers every value.
+    fn restricts(&self, field: CronField) -> bool {
+        self.mask(field) != field.full_mask()
+    }
+}
+
+impl FromStr for CronSchedule {
+    type Err = CronError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = value.split_whitespace().collect();
+        if fields.len() != CronField::ALL.len() {
+            return Err(CronError::FieldCount(fields.len()));
         }
+
+        let schedule: Self = Self {
+            minutes: parse_cron_field(CronField::Minute, fields[0])?,
+            hours: parse_cron_field(CronField::Hour, fields[1])?,
+            days_of_month: parse_cron_field(CronField::DayOfMonth, fields[2])?,
+            months: parse_cron_field(CronField::Month, fields[3])?,
+            days_of_week: parse_cron_field(CronField::DayOfWeek, fields[4])?,
+        };
+
+        // Only day-of-month can make a schedule impossible, and only when day-of-week
+        // cannot match instead. February counts as 29 days so leap days stay valid.
+        if schedule.restricts(CronField::DayOfMonth) && !schedule.restricts(CronField::DayOfWeek) {
+            let reachable: bool = set_bits(schedule.months).any(|month: u32| {
+                let longest: u32 = match month {
+                    2 => 29,
+                    4 | 6 | 9 | 11 => 30,
+                    _ => 31,
+                };
+                set_bits(schedule.days_of_month).any(|day: u32| day <= longest)
+            });
+            if !reachable {
+                return Err(CronError::NeverMatches);
+            }
+        }
+
+        Ok(schedule)
+    }
+}
+
+/// Prints the canonical form, which parses back to the same schedule.
+impl Display for CronSchedule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let fields: Vec<String> = CronField::ALL
+            .iter()
+            .map(|field: &CronField| format_cron_field(*field, self.mask(*field)))
+            .collect();
+        write!(f, "{}", fields.join(" "))
+    }
+}
+
+fn set_bits(mask: u64) -> impl Iterator<Item = u32> {
+    (0..64).filter(move |bit: &u32| mask & (1 << bit) != 0)
+}
+
+fn parse_cron_field(field: CronField, raw: &str) -> Result<u64, CronError> {
+    let (min, max): (u32, u32) = field.bounds();
+    let mut mask: u64 = 0;
+
+    for part in raw.split(',') {
+        let (range, step): (&str, Option<&str>) = match part.split_once('/') {
+            Some((range, step)) => (range, Some(step)),
+            None => (part, None),
+        };
+        let step: u32 = match step {
+            Some(step) => step
+                .parse::<u32>()
+                .ok()
+                .filter(|step: &u32| *step > 0)
+                .ok_or_else(|| CronError::InvalidStep {
+                    field,
+                    token: part.to_string(),
+                })?,
+            None => 1,
+        };
+
+        let (start, end): (u32, u32) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            let start: u32 = parse_cron_value(field, start)?;
+            let end: u32 = parse_cron_value(field, end)?;
+            if start > end {
+                return Err(CronError::ReversedRange {
+                    field,
+                    token: part.to_string(),
+                });
+            }
+            (start, end)
+        } else {
+            let value: u32 = parse_cron_value(field, range)?;
+            // `5/15` means "from 5 to the end, every 15", as in Vixie cron.
+            (value, if step > 1 { max } else { value })
+        };
+
+        for value in (start..=end).step_by(usize::try_from(step).unwrap_or(usize::MAX)) {
+            // Day-of-week 7 is another name for Sunday.
+            let value: u32 = if field == CronField::DayOfWeek && value == 7 { 0 } else { value };
+            mask |= 1 << value;
+        }
+    }
+
+    Ok(mask)
+}
+
+fn parse_cron_value(field: CronField, token: &str) -> Result<u32, CronError> {
+    let (min, max): (u32, u32) = field.bounds();
+    let lowercase: String = token.to_ascii_lowercase();
+    if let Some(index) = field.names().iter().position(|name: &&str| *name == lowercase) {
+        return Ok(min + u32::try_from(index).unwrap_or(0));
+    }
+
+    let value: u32 = token.parse::<u32>().map_err(|_| CronError::InvalidValue {
+        field,
+        token: token.to_string(),
+    })?;
+    if value < min || value > max {
+        return Err(CronError::OutOfRange { field, value });
+    }
+
+    Ok(value)
+}
+
+fn format_cron_field(field: CronField, mask: u64) -> String {
+    if mask == field.full_mask() {
+        return "*".to_string();
+    }
+
+    // Collapse consecutive values into ranges: 1,2,3,5 -> 1-3,5.
+    let mut parts: Vec<String> = Vec::new();
+    let mut values: Peekable<std::vec::IntoIter<u32>> = set_bits(mask).collect::<Vec<u32>>().into_iter().peekable();
+    while let Some(start) = values.next() {
+        let mut end: u32 = start;
+        while values.peek() == Some(&(end + 1)) {
+            end += 1;
+            values.next();
+        }
+        parts.push(if start == end {
+            start.to_string()
+        } else {
+            format!("{start}-{end}")
+        });
     }
+
+    parts.join(",")
+}
+
+/// Longest snooze accepted for a single reminder.
+pub const MAX_SNOOZE_MINUTES: u32 = 24 * 60;
+
+/// One persisted hydration reminder, identified so user responses can refer back to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HydrationReminder {
+    pub id: i64,
+    pub message: String,
+    pub triggered_at: DateTime<Utc>,
+}
+
+/// How the user answered a reminder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReminderResponse {
+    /// The user drank; an amount also logs water intake.
+    Drank { amount_ml: Option<u32> },
+    /// Fire the next reminder after `minutes` instead of the regular interval.
+    Snoozed { minutes: u32 },
+    Dismissed,
+}
+
+impl ReminderResponse {
+    /// Returns the stable outcome name used for persistence.
+    pub fn outcome(self) -> &'static str {
+        match self {
+            Self::Drank { .. } => "drank",
+            Self::Snoozed { .. } => "snoozed",
+            Self::Dismissed => "dismissed",
+        }
+    }
+
+    /// Returns the snooze delay when this response postpones the next reminder.
+    pub fn snooze_duration(self) -> Option<Duration> {
+        match self {
+            Self::Snoozed { minutes } => Some(Duration::from_secs(u64::from(minutes) * 60)),
+            Self::Drank { .. } | Self::Dismissed => None,
+        }
+    }
+}
+
+/// Longest timed pause accepted, in minutes.
+pub const MAX_PAUSE_MINUTES: u32 = 7 * 24 * 60;
+
+/// How long the user asked to pause reminders for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PauseLength {
+    Minutes(u32),
+    /// Until the next hydration day begins.
+    UntilTomorrow,
+    /// Until reminders are resumed.
+    Indefinitely,
+}
+
+/// A pause on reminders as persisted in settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReminderPause {
+    Until(DateTime<Utc>),
+    Indefinite,
+}
+
+/// Returns true when a timed pause length is within `1..=MAX_PAUSE_MINUTES`.
+pub fn is_valid_pause_minutes(minutes: u32) -> bool {
+    (1..=MAX_PAUSE_MINUTES).contains(&minutes)
+}
+
+impl ReminderPause {
+    /// Resolves a requested length from `now`; "tomorrow" is the start of the next
+    /// hydration day.
+    pub fn starting_at<Tz: TimeZone>(
+        length: PauseLength,
+        now: DateTime<Utc>,
+        timezone: &Tz,
+        day_start_hour: u32,
+    ) -> Self {
+        match length {
+            PauseLength::Minutes(minutes) => Self::Until(now + chrono::Duration::minutes(i64::from(minutes))),
+            PauseLength::UntilTomorrow => Self::Until(day_bounds(now, timezone, day_start_hour).1),
+            PauseLength::Indefinitely => Self::Indefinite,
+        }
+    }
+
+    /// Returns when the pause ends, or `None` when it lasts until resumed.
+    pub fn ends_at(self) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Until(until) => Some(until),
+            Self::Indefinite => None,
+        }
+    }
+
+    /// Returns true while the pause holds reminders at `now`.
+    pub fn is_active_at(self, now: DateTime<Utc>) -> bool {
+        self.ends_at().is_none_or(|until: DateTime<Utc>| now < until)
+    }
+}
+
+/// Request for the scheduler to fire the next reminder after `delay`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnoozeRequest {
+    pub reminder_id: i64,
+    pub delay: Duration,
+}
+
+/// Container the user drank from, when they chose to record it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntakeSource {
+    Glass,
+    Bottle,
+    Cup,
+    Other,
+}
+
+impl IntakeSource {
+    /// Returns the stable lowercase name used for persistence.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Glass => "glass",
+            Self::Bottle => "bottle",
+            Self::Cup => "cup",
+            Self::Other => "other",
+        }
+    }
+}
+
+impl FromStr for IntakeSource {
+    type Err = ();
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "glass" => Ok(Self::Glass),
+            "bottle" => Ok(Self::Bottle),
+            "cup" => Ok(Self::Cup),
+            "other" => Ok(Self::Other),
+            _ => Err(()),
+        }
+    }
+}
+
+/// One recorded drink.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WaterIntake {
+    pub id: i64,
+    pub amount_ml: u32,
+    pub consumed_at: DateTime<Utc>,
+    pub source: Option<IntakeSource>,
+}
+
+impl WaterIntake {
+    /// Returns the cursor that continues a listing after this drink.
+    pub fn cursor(&self) -> HistoryCursor {
+        HistoryCursor {
+            at: self.consumed_at,
+            id: self.id,
+        }
+    }
+}
+
+/// Returns true when an intake amount is positive and within `MAX_INTAKE_ML`.
+pub fn is_valid_intake_amount(amount_ml: u32) -> bool {
+    (1..=MAX_INTAKE_ML).contains(&amount_ml)
+}
+
+/// Daily intake target and the local hour at which a new hydration day begins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HydrationGoal {
+    pub daily_goal_ml: u32,
+    pub day_start_hour: u32,
+}
+
+impl HydrationGoal {
+    /// Creates a goal, clamping the target to at least 1 ml and rejecting hours past 23.
+    pub fn new(daily_goal_ml: u32, day_start_hour: u32) -> Option<Self> {
+        if day_start_hour > 23 {
+            return None;
+        }
+
+        Some(Self {
+            daily_goal_ml: daily_goal_ml.max(1),
+            day_start_hour,
+        })
+    }
+}
+
+impl Default for HydrationGoal {
+    fn default() -> Self {
+        Self {
+            daily_goal_ml: DEFAULT_DAILY_GOAL_ML,
+            day_start_hour: 0,
+        }
+    }
+}
+
+/// Snapshot of how much of the daily goal has been consumed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HydrationProgress {
+    pub consumed_ml: u64,
+    pub goal_ml: u32,
+    pub remaining_ml: u64,
+    /// Share of the goal consumed; exceeds `100.0` once the goal is beaten.
+    pub percent: f64,
+}
+
+impl HydrationProgress {
+    /// Derives remaining amount and percentage from consumed intake and a goal.
+    pub fn new(consumed_ml: u64, goal_ml: u32) -> Self {
+        let goal: u64 = u64::from(goal_ml.max(1));
+        Self {
+            consumed_ml,
+            goal_ml,
+            remaining_ml: goal.saturating_sub(consumed_ml),
+            percent: consumed_ml as f64 * 100.0 / goal as f64,
+        }
+    }
+
+    /// Returns true once consumed intake reaches the goal.
+    pub fn goal_met(&self) -> bool {
+        self.remaining_ml == 0
+    }
+}
+
+/// What caused a reminder to fire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReminderKind {
+    /// The regular interval or cron schedule came due.
+    Scheduled,
+    /// A snoozed reminder came back.
+    Snoozed,
+    /// Fired on wake for reminders missed during sleep or suspend.
+    CatchUp,
+    /// Requested directly, outside the schedule.
+    Manual,
+}
+
+impl ReminderKind {
+    /// Returns the stable lowercase name used by sinks and clients.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Scheduled => "scheduled",
+            Self::Snoozed => "snoozed",
+            Self::CatchUp => "catch_up",
+            Self::Manual => "manual",
+        }
+    }
+}
+
+/// Response a reminder offers the user, mirroring `ReminderResponse`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReminderAction {
+    Drink,
+    Snooze,
+    Dismiss,
+}
+
+impl ReminderAction {
+    /// Returns the stable lowercase name used by sinks and clients.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Drink => "drink",
+            Self::Snooze => "snooze",
+            Self::Dismiss => "dismiss",
+        }
+    }
+}
+
+/// Everything a sink needs to present one fired reminder.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReminderEvent {
+    /// ID of the logged reminder, used when responding to it.
+    pub id: i64,
+    pub kind: ReminderKind,
+    pub fired_at: DateTime<Utc>,
+    pub message: String,
+    /// Intake against the daily goal at the moment the reminder fired.
+    pub progress: HydrationProgress,
+    pub actions: Vec<ReminderAction>,
+}
+
+impl ReminderEvent {
+    /// Builds the event for a logged reminder, offering every response.
+    pub fn new(reminder: HydrationReminder, kind: ReminderKind, progress: HydrationProgress) -> Self {
+        Self {
+            id: reminder.id,
+            kind,
+            fired_at: reminder.triggered_at,
+            message: reminder.message,
+            progress,
+            actions: vec![ReminderAction::Drink, ReminderAction::Snooze, ReminderAction::Dismiss],
+        }
+    }
+}
+
+/// Returns the UTC `[start, end)` range of the hydration day containing `now`, where
+/// each day starts at `day_start_hour` local time instead of midnight.
+pub fn day_bounds<Tz: TimeZone>(
+    now: DateTime<Utc>,
+    timezone: &Tz,
+    day_start_hour: u32,
+) -> (DateTime<Utc>, DateTime<Utc>) {
+    let local_date: NaiveDate = hydration_day(now, timezone, day_start_hour);
+    let start_time: NaiveTime =
+        NaiveTime::from_hms_opt(day_start_hour.min(23), 0, 0).unwrap_or(NaiveTime::MIN);
+    let start: NaiveDateTime = local_date.and_time(start_time);
+    let end: NaiveDateTime = start
+        .checked_add_days(Days::new(1))
+        .unwrap_or(NaiveDateTime::MAX);
+
+    (resolve_local(timezone, start), resolve_local(timezone, end))
+}
+
+/// Returns the local date that the hydration day containing `now` is named after.
+pub fn hydration_day<Tz: TimeZone>(now: DateTime<Utc>, timezone: &Tz, day_start_hour: u32) -> NaiveDate {
+    let local: NaiveDateTime = now.with_timezone(timezone).naive_local();
+    (local - chrono::Duration::hours(i64::from(day_start_hour.min(23)))).date()
+}
+
+/// Maps a local wall-clock time to UTC, picking the earlier instant on DST overlaps
+/// and the first valid instant after a DST gap.
+pub fn resolve_local<Tz: TimeZone>(timezone: &Tz, local: NaiveDateTime) -> DateTime<Utc> {
+    let mut candidate: NaiveDateTime = local;
+    for _ in 0..4 {
+        match timezone.from_local_datetime(&candidate) {
+            LocalResult::Single(resolved) => return resolved.with_timezone(&Utc),
+            LocalResult::Ambiguous(earliest, _) => return earliest.with_timezone(&Utc),
+            LocalResult::None => candidate += chrono::Duration::minutes(30),
+        }
+    }
+
+    local.and_utc()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::FixedOffset;
+    use chrono_tz::America::New_York;
 
     #[test]
     fn default_config_is_30_minutes() {
@@ -43,4 +1011,279 @@ mod tests {
     fn zero_interval_is_clamped_to_one_minute() {
         assert_eq!(ReminderConfig::new(0).interval_minutes, 1);
     }
+
+    fn local(raw: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(raw, "%Y-%m-%d %H:%M").expect("valid local time")
+    }
+
+    fn time(raw: &str) -> NaiveTime {
+        NaiveTime::parse_from_str(raw, "%H:%M").expect("valid time")
+    }
+
+    fn office_hours() -> ReminderConfig {
+        let window: ActiveWindow =
+            ActiveWindow::new(time("09:00"), time("18:00"), WeekdaySet::WEEKDAYS).expect("valid window");
+        let lunch: QuietHours = QuietHours::new(time("12:00"), time("13:00")).expect("valid quiet hours");
+        ReminderConfig::new(30)
+            .with_active_window(Some(window))
+            .with_quiet_hours(Some(lunch))
+    }
+
+    #[test]
+    fn allows_only_inside_window_and_outside_quiet_hours() {
+        let config: ReminderConfig = office_hours();
+
+        // 2026-01-05 is a Monday.
+        assert!(config.allows(local("2026-01-05 09:00")));
+        assert!(!config.allows(local("2026-01-05 08:59")));
+        assert!(!config.allows(local("2026-01-05 12:30")));
+        assert!(!config.allows(local("2026-01-05 18:00")));
+        assert!(!config.allows(local("2026-01-10 10:00")));
+    }
+
+    #[test]
+    fn quiet_hours_can_wrap_past_midnight() {
+        let night: QuietHours = QuietHours::new(time("22:00"), time("07:00")).expect("valid quiet hours");
+
+        assert!(night.contains(time("23:30")));
+        assert!(night.contains(time("06:59")));
+        assert!(!night.contains(time("07:00")));
+        assert!(QuietHours::new(time("22:00"), time("22:00")).is_none());
+    }
+
+    #[test]
+    fn next_allowed_skips_to_next_window_opening() {
+        let config: ReminderConfig = office_hours();
+        let utc = |raw: &str| local(raw).and_utc();
+
+        assert_eq!(config.next_allowed(utc("2026-01-05 10:00"), &Utc), Some(utc("2026-01-05 10:00")));
+        assert_eq!(config.next_allowed(utc("2026-01-05 12:10"), &Utc), Some(utc("2026-01-05 13:00")));
+        // Friday evening waits for Monday morning.
+        assert_eq!(config.next_allowed(utc("2026-01-09 18:30"), &Utc), Some(utc("2026-01-12 09:00")));
+    }
+
+    #[test]
+    fn next_allowed_is_none_when_quiet_hours_cover_the_window() {
+        let window: ActiveWindow =
+            ActiveWindow::new(time("09:00"), time("17:00"), WeekdaySet::ALL).expect("valid window");
+        let config: ReminderConfig = ReminderConfig::new(30)
+            .with_active_window(Some(window))
+            .with_quiet_hours(QuietHours::new(time("08:00"), time("18:00")));
+
+        assert_eq!(config.next_allowed(local("2026-01-05 10:00").and_utc(), &Utc), None);
+    }
+
+    #[test]
+    fn missed_slots_count_every_passed_interval() {
+        let due: DateTime<Utc> = local("2026-01-05 11:00").and_utc();
+        let interval: Duration = Duration::from_secs(30 * 60);
+
+        assert_eq!(missed_slots(due, local("2026-01-05 10:59").and_utc(), interval), 0);
+        assert_eq!(missed_slots(due, local("2026-01-05 11:00").and_utc(), interval), 1);
+        assert_eq!(missed_slots(due, local("2026-01-05 12:31").and_utc(), interval), 4);
+    }
+
+    #[test]
+    fn catch_up_policies_limit_replayed_reminders() {
+        assert_eq!(CatchUpPolicy::FireOnce.reminders_to_fire(4), 1);
+        assert_eq!(CatchUpPolicy::Skip.reminders_to_fire(4), 0);
+        assert_eq!(CatchUpPolicy::FireAll.reminders_to_fire(4), 4);
+        assert_eq!(CatchUpPolicy::FireAll.reminders_to_fire(100), MAX_CATCH_UP_REMINDERS);
+        assert_eq!("fire-all".parse::<CatchUpPolicy>(), Ok(CatchUpPolicy::FireAll));
+    }
+
+    fn cron(expression: &str) -> CronSchedule {
+        expression.parse::<CronSchedule>().expect("valid cron expression")
+    }
+
+    fn utc(raw: &str) -> DateTime<Utc> {
+        local(raw).and_utc()
+    }
+
+    #[test]
+    fn cron_expressions_print_in_canonical_form() {
+        let schedule: CronSchedule = cron("*/15 9-17 * * mon-fri");
+
+        assert_eq!(schedule.to_string(), "0,15,30,45 9-17 * * 1-5");
+        assert_eq!(cron(&schedule.to_string()), schedule);
+        assert_eq!(cron("0 12 * JAN,dec 7"), cron("0 12 * 1,12 0"));
+        assert_eq!(cron("5/20 * * * *").to_string(), "5,25,45 * * * *");
+    }
+
+    #[test]
+    fn invalid_cron_expressions_explain_what_is_wrong() {
+        let error = |expression: &str| expression.parse::<CronSchedule>().expect_err("invalid expression");
+
+        assert_eq!(error("* * * *"), CronError::FieldCount(4));
+        assert_eq!(
+            error("0 24 * * *"),
+            CronError::OutOfRange { field: CronField::Hour, value: 24 }
+        );
+        assert_eq!(
+            error("0 17-9 * * *"),
+            CronError::ReversedRange { field: CronField::Hour, token: "17-9".to_string() }
+        );
+        assert_eq!(
+            error("*/0 * * * *"),
+            CronError::InvalidStep { field: CronField::Minute, token: "*/0".to_string() }
+        );
+        assert_eq!(
+            error("0 9 * * someday"),
+            CronError::InvalidValue { field: CronField::DayOfWeek, token: "someday".to_string() }
+        );
+        assert_eq!(error("0 0 31 2 *"), CronError::NeverMatches);
+        assert!("0 0 29 2 *".parse::<CronSchedule>().is_ok());
+        assert_eq!(error("0 24 * * *").to_string(), "hour value 24 is out of range (0-23)");
+    }
+
+    #[test]
+    fn cron_finds_the_next_matching_minute() {
+        // 2026-01-09 is a Friday.
+        let office: CronSchedule = cron("*/45 9-16 * * 1-5");
+
+        assert_eq!(office.next_after(utc("2026-01-09 09:00"), &Utc), Some(utc("2026-01-09 09:45")));
+        assert_eq!(office.next_after(utc("2026-01-09 16:45"), &Utc), Some(utc("2026-01-12 09:00")));
+        assert_eq!(cron("30 10 * * *").next_after(utc("2026-12-31 11:00"), &Utc), Some(utc("2027-01-01 10:30")));
+    }
+
+    #[test]
+    fn cron_day_of_month_or_day_of_week_fires() {
+        // Both restricted: the 1st of the month or any Monday.
+        let schedule: CronSchedule = cron("0 9 1 * mon");
+
+        assert_eq!(schedule.next_after(utc("2026-01-02 00:00"), &Utc), Some(utc("2026-01-05 09:00")));
+        assert_eq!(schedule.next_after(utc("2026-01-31 00:00"), &Utc), Some(utc("2026-02-01 09:00")));
+        assert_eq!(cron("0 0 29 2 *").next_after(utc("2026-01-01 00:00"), &Utc), Some(utc("2028-02-29 00:00")));
+    }
+
+    #[test]
+    fn cron_skips_local_times_lost_to_spring_forward() {
+        // New York jumps from 02:00 EST to 03:00 EDT on 2026-03-08.
+        let daily: CronSchedule = cron("30 2 * * *");
+        let hourly: CronSchedule = cron("0 * * * *");
+
+        assert_eq!(daily.next_after(utc("2026-03-07 17:00"), &New_York), Some(utc("2026-03-09 06:30")));
+        // 01:00 EST is followed directly by 03:00 EDT.
+        assert_eq!(hourly.next_after(utc("2026-03-08 06:00"), &New_York), Some(utc("2026-03-08 07:00")));
+    }
+
+    #[test]
+    fn cron_fires_repeated_local_times_once_on_fall_back() {
+        // New York repeats 01:00-02:00 on 2026-11-01 (EDT, then EST).
+        let daily: CronSchedule = cron("30 1 * * *");
+        let half_hourly: CronSchedule = cron("*/30 * * * *");
+
+        assert_eq!(daily.next_after(utc("2026-10-31 16:00"), &New_York), Some(utc("2026-11-01 05:30")));
+        assert_eq!(daily.next_after(utc("2026-11-01 05:30"), &New_York), Some(utc("2026-11-02 06:30")));
+        // After 01:30 EDT the repeated 01:00 and 01:30 EST are skipped; 02:00 EST is next.
+        assert_eq!(half_hourly.next_after(utc("2026-11-01 05:30"), &New_York), Some(utc("2026-11-01 07:00")));
+    }
+
+    #[test]
+    fn cron_schedule_replaces_the_interval_when_set() {
+        let config: ReminderConfig = ReminderConfig::new(30).with_cron(Some(cron("0 * * * *")));
+
+        assert_eq!(config.next_due(utc("2026-01-05 10:10"), &Utc), utc("2026-01-05 11:00"));
+        assert_eq!(
+            ReminderConfig::new(30).next_due(utc("2026-01-05 10:10"), &Utc),
+            utc("2026-01-05 10:40")
+        );
+        assert_eq!(config.missed_reminders(utc("2026-01-05 10:00"), utc("2026-01-05 13:10"), &Utc), 4);
+        assert_eq!(ReminderConfig::new(u64::MAX).interval_minutes, MAX_INTERVAL_MINUTES);
+        let unclamped: ReminderConfig = ReminderConfig {
+            interval_minutes: u64::MAX,
+            ..ReminderConfig::new(30)
+        };
+        assert_eq!(unclamped.next_due(utc("2026-01-05 10:10"), &Utc), DateTime::<Utc>::MAX_UTC);
+        assert_eq!(config.missed_reminders(utc("2026-01-05 10:00"), utc("2026-01-05 09:59"), &Utc), 0);
+        assert_eq!(
+            config.missed_reminders(utc("2026-01-05 10:00"), utc("2026-01-12 10:00"), &Utc),
+            MAX_CATCH_UP_REMINDERS
+        );
+        assert_eq!(
+            ReminderConfig::new(30).missed_reminders(utc("2026-01-05 10:00"), utc("2026-01-12 10:00"), &Utc),
+            MAX_CATCH_UP_REMINDERS
+        );
+    }
+
+    #[test]
+    fn intake_amounts_are_bounded() {
+        assert!(!is_valid_intake_amount(0));
+        assert!(is_valid_intake_amount(250));
+        assert!(!is_valid_intake_amount(MAX_INTAKE_ML + 1));
+    }
+
+    #[test]
+    fn intake_source_parses_case_insensitively() {
+        assert_eq!("Bottle".parse::<IntakeSource>(), Ok(IntakeSource::Bottle));
+        assert!("bucket".parse::<IntakeSource>().is_err());
+    }
+
+    #[test]
+    fn day_bounds_follow_the_local_calendar_day() {
+        let tz: FixedOffset = FixedOffset::west_opt(5 * 3600).expect("valid offset");
+        // 02:30 UTC is still the previous evening at UTC-5.
+        let now: DateTime<Utc> = "2026-03-10T02:30:00Z".parse().expect("valid timestamp");
+
+        let (start, end): (DateTime<Utc>, DateTime<Utc>) = day_bounds(now, &tz, 0);
+
+        assert_eq!(start, "2026-03-09T05:00:00Z".parse::<DateTime<Utc>>().expect("valid"));
+        assert_eq!(end, "2026-03-10T05:00:00Z".parse::<DateTime<Utc>>().expect("valid"));
+    }
+
+    #[test]
+    fn late_night_intake_counts_toward_previous_day_with_day_start_hour() {
+        let tz: FixedOffset = FixedOffset::east_opt(3600).expect("valid offset");
+        // 02:30 local on the 10th is before a 04:00 day start, so it belongs to the 9th.
+        let now: DateTime<Utc> = "2026-03-10T01:30:00Z".parse().expect("valid timestamp");
+
+        let (start, end): (DateTime<Utc>, DateTime<Utc>) = day_bounds(now, &tz, 4);
+
+        assert_eq!(hydration_day(now, &tz, 4).to_string(), "2026-03-09");
+        assert_eq!(start, "2026-03-09T03:00:00Z".parse::<DateTime<Utc>>().expect("valid"));
+        assert_eq!(end, "2026-03-10T03:00:00Z".parse::<DateTime<Utc>>().expect("valid"));
+    }
+
+    #[test]
+    fn progress_reports_remaining_and_percentage() {
+        let halfway: HydrationProgress = HydrationProgress::new(1_000, 2_000);
+        assert_eq!(halfway.remaining_ml, 1_000);
+        assert_eq!(halfway.percent, 50.0);
+        assert!(!halfway.goal_met());
+
+        let beaten: HydrationProgress = HydrationProgress::new(2_500, 2_000);
+        assert_eq!(beaten.remaining_ml, 0);
+        assert_eq!(beaten.percent, 125.0);
+        assert!(beaten.goal_met());
+    }
+
+    #[test]
+    fn goal_rejects_out_of_range_day_start_hour() {
+        assert_eq!(HydrationGoal::new(0, 4), Some(HydrationGoal { daily_goal_ml: 1, day_start_hour: 4 }));
+        assert_eq!(HydrationGoal::new(2_000, 24), None);
+    }
+
+    #[test]
+    fn pauses_resolve_from_now_and_expire() {
+        let now: DateTime<Utc> = resolve_local(&New_York, local("2026-03-07 22:30"));
+        let pause = |length: PauseLength| ReminderPause::starting_at(length, now, &New_York, 4);
+
+        let hour: ReminderPause = pause(PauseLength::Minutes(60));
+        assert_eq!(hour.ends_at(), Some(now + chrono::Duration::hours(1)));
+        assert!(hour.is_active_at(now));
+        assert!(!hour.is_active_at(now + chrono::Duration::hours(1)));
+
+        // The next hydration day starts at 04:00 local, across the spring-forward night.
+        let tomorrow: ReminderPause = pause(PauseLength::UntilTomorrow);
+        assert_eq!(tomorrow.ends_at(), Some(resolve_local(&New_York, local("2026-03-08 04:00"))));
+
+        assert_eq!(pause(PauseLength::Indefinitely), ReminderPause::Indefinite);
+        assert!(ReminderPause::Indefinite.is_active_at(now + chrono::Duration::days(365)));
+        assert!(!is_valid_pause_minutes(0));
+        assert!(!is_valid_pause_minutes(MAX_PAUSE_MINUTES + 1));
+
+        let config: ReminderConfig = ReminderConfig::default().with_pause(Some(hour));
+        assert_eq!(config.active_pause(now), Some(hour));
+        assert_eq!(config.active_pause(now + chrono::Duration::hours(2)), None);
+    }
 }
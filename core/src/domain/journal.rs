@@ -0,0 +1,96 @@
+//! Domain types and rules for the daily prompt and journal entry flow.
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalPrompt {
+    pub id: i64,
+    pub text: String,
+}
+
+/// Calendar day a journal entry belongs to (one entry per day).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EntryDate(NaiveDate);
+
+impl EntryDate {
+    /// Wraps a calendar date as a journal entry date.
+    pub fn new(date: NaiveDate) -> Self {
+        Self(date)
+    }
+
+    /// Parses an ISO `YYYY-MM-DD` date string.
+    pub fn parse(raw: &str) -> Option<Self> {
+        NaiveDate::parse_from_str(raw.trim(), "%Y-%m-%d").ok().map(Self)
+    }
+
+    /// Returns the underlying calendar date.
+    pub fn date(self) -> NaiveDate {
+        self.0
+    }
+
+    /// Picks the prompt slot for this date so every day rotates to the next prompt.
+    pub fn prompt_index(self, prompt_count: usize) -> Option<usize> {
+        if prompt_count == 0 {
+            return None;
+        }
+
+        let day_number: usize = usize::try_from(self.0.num_days_from_ce()).unwrap_or(0);
+        Some(day_number % prompt_count)
+    }
+}
+
+impl Display for EntryDate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.format("%Y-%m-%d"))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalEntry {
+    pub id: i64,
+    pub entry_date: EntryDate,
+    pub prompt_id: Option<i64>,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Trims surrounding whitespace and rejects bodies that are empty afterwards.
+pub fn normalize_entry_body(body: &str) -> Option<String> {
+    let trimmed: &str = body.trim();
+    if trimmed.is_empty() {
+        None
+    } else {
+        Some(trimmed.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entry_date_round_trips_through_iso_format() {
+        let date: EntryDate = EntryDate::parse("2026-03-09").expect("valid date");
+        assert_eq!(date.to_string(), "2026-03-09");
+        assert!(EntryDate::parse("09/03/2026").is_none());
+    }
+
+    #[test]
+    fn consecutive_days_rotate_prompts() {
+        let first: EntryDate = EntryDate::parse("2026-03-09").expect("valid date");
+        let second: EntryDate = EntryDate::parse("2026-03-10").expect("valid date");
+
+        let first_index: usize = first.prompt_index(5).expect("prompts exist");
+        let second_index: usize = second.prompt_index(5).expect("prompts exist");
+
+        assert_eq!((first_index + 1) % 5, second_index);
+        assert_eq!(first.prompt_index(0), None);
+    }
+
+    #[test]
+    fn blank_entry_bodies_are_rejected() {
+        assert_eq!(normalize_entry_body("   \n"), None);
+        assert_eq!(normalize_entry_body("  Felt focused. "), Some("Felt focused.".to_string()));
+    }
+}
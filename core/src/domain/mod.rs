@@ -1,2 +1,8 @@
 //! Domain layer modules containing core business concepts and rules.
+pub mod achievements;
+pub mod export;
+pub mod history;
 pub mod hydration;
+pub mod journal;
+pub mod schedule;
+pub mod stats;
@@ -0,0 +1,122 @@
+//! Domain rules for when named background jobs run.
+use crate::domain::hydration::{resolve_local, CronSchedule, WeekdaySet};
+use chrono::{DateTime, Datelike, Days, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use std::time::Duration;
+
+/// When a scheduled job runs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JobSchedule {
+    /// Repeats a fixed duration after the previous run (or after the job was added).
+    Every(Duration),
+    /// Runs at each listed local time of day on the selected weekdays, like a cron entry.
+    Daily { times: Vec<NaiveTime>, days: WeekdaySet },
+    /// Runs whenever a five-field cron expression matches local time.
+    Cron(CronSchedule),
+    /// Runs a single time at the given instant.
+    Once(DateTime<Utc>),
+}
+
+impl JobSchedule {
+    /// Creates an interval schedule, clamped to at least one minute like reminder intervals.
+    pub fn every_minutes(minutes: u64) -> Self {
+        Self::Every(Duration::from_secs(minutes.max(1).saturating_mul(60)))
+    }
+
+    /// Creates a daily schedule; needs at least one time and one weekday.
+    pub fn daily_at(times: &[NaiveTime], days: WeekdaySet) -> Option<Self> {
+        if times.is_empty() || days.is_empty() {
+            return None;
+        }
+
+        let mut times: Vec<NaiveTime> = times.to_vec();
+        times.sort();
+        times.dedup();
+        Some(Self::Daily { times, days })
+    }
+
+    /// Returns the first run strictly after `after`, or `None` when the schedule is spent.
+    pub fn next_after<Tz: TimeZone>(&self, after: DateTime<Utc>, timezone: &Tz) -> Option<DateTime<Utc>> {
+        match self {
+            // An interval past the end of representable time never comes due.
+            Self::Every(every) => after.checked_add_signed(chrono::Duration::from_std(*every).ok()?),
+            Self::Daily { times, days } => {
+                let local_after: NaiveDateTime = after.with_timezone(timezone).naive_local();
+                // Starting a day early covers times pulled back across midnight by an offset change.
+                (0..=8)
+                    .filter_map(|offset: u64| {
+                        local_after.date().pred_opt()?.checked_add_days(Days::new(offset))
+                    })
+                    .filter(|date: &NaiveDate| days.contains(date.weekday()))
+                    .flat_map(|date: NaiveDate| {
+                        times.iter().map(move |time: &NaiveTime| date.and_time(*time))
+                    })
+                    .map(|local: NaiveDateTime| resolve_local(timezone, local))
+                    .find(|candidate: &DateTime<Utc>| *candidate > after)
+            }
+            Self::Cron(cron) => cron.next_after(after, timezone),
+            Self::Once(at) => (*at > after).then_some(*at),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{FixedOffset, Weekday};
+
+    fn at(raw: &str) -> DateTime<Utc> {
+        NaiveDateTime::parse_from_str(raw, "%Y-%m-%d %H:%M")
+            .expect("valid time")
+            .and_utc()
+    }
+
+    fn time(raw: &str) -> NaiveTime {
+        NaiveTime::parse_from_str(raw, "%H:%M").expect("valid time")
+    }
+
+    #[test]
+    fn interval_runs_one_period_after_the_previous_run() {
+        let schedule: JobSchedule = JobSchedule::every_minutes(0);
+
+        assert_eq!(schedule, JobSchedule::Every(Duration::from_secs(60)));
+        assert_eq!(schedule.next_after(at("2026-01-05 10:00"), &Utc), Some(at("2026-01-05 10:01")));
+        assert_eq!(JobSchedule::every_minutes(u64::MAX).next_after(at("2026-01-05 10:00"), &Utc), None);
+        let far: JobSchedule = JobSchedule::every_minutes(1_000_000_000_000);
+        assert_eq!(far.next_after(at("2026-01-05 10:00"), &Utc), None);
+    }
+
+    #[test]
+    fn daily_schedule_picks_the_next_listed_time_on_a_selected_day() {
+        // 2026-01-09 is a Friday.
+        let schedule: JobSchedule =
+            JobSchedule::daily_at(&[time("15:00"), time("10:30")], WeekdaySet::WEEKDAYS).expect("valid schedule");
+
+        assert_eq!(schedule.next_after(at("2026-01-09 09:00"), &Utc), Some(at("2026-01-09 10:30")));
+        assert_eq!(schedule.next_after(at("2026-01-09 10:30"), &Utc), Some(at("2026-01-09 15:00")));
+        assert_eq!(schedule.next_after(at("2026-01-09 16:00"), &Utc), Some(at("2026-01-12 10:30")));
+    }
+
+    #[test]
+    fn daily_schedule_uses_local_wall_clock_time() {
+        let plus_two: FixedOffset = FixedOffset::east_opt(2 * 3600).expect("valid offset");
+        let schedule: JobSchedule =
+            JobSchedule::daily_at(&[time("09:00")], WeekdaySet::from_days(&[Weekday::Mon])).expect("valid schedule");
+
+        assert_eq!(schedule.next_after(at("2026-01-05 06:00"), &plus_two), Some(at("2026-01-05 07:00")));
+        assert_eq!(schedule.next_after(at("2026-01-05 07:00"), &plus_two), Some(at("2026-01-12 07:00")));
+    }
+
+    #[test]
+    fn empty_daily_schedules_are_rejected() {
+        assert_eq!(JobSchedule::daily_at(&[], WeekdaySet::ALL), None);
+        assert_eq!(JobSchedule::daily_at(&[time("09:00")], WeekdaySet::from_bits(0)), None);
+    }
+
+    #[test]
+    fn one_shot_runs_once() {
+        let schedule: JobSchedule = JobSchedule::Once(at("2026-01-05 12:00"));
+
+        assert_eq!(schedule.next_after(at("2026-01-05 10:00"), &Utc), Some(at("2026-01-05 12:00")));
+        assert_eq!(schedule.next_after(at("2026-01-05 12:00"), &Utc), None);
+    }
+}
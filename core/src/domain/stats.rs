@@ -0,0 +1,272 @@
+//! Hydration statistics over recent days: totals, goal streaks and reminder responses.
+use chrono::{Days, NaiveDate};
+use std::str::FromStr;
+
+/// Span of hydration days that statistics cover, ending with the current day.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StatsPeriod {
+    /// The current hydration day only.
+    Day,
+    /// The current day and the six before it.
+    #[default]
+    Week,
+    /// The current day and the 29 before it.
+    Month,
+}
+
+impl StatsPeriod {
+    /// Returns how many hydration days the period covers.
+    pub fn days(self) -> u32 {
+        match self {
+            Self::Day => 1,
+            Self::Week => 7,
+            Self::Month => 30,
+        }
+    }
+
+    /// Returns the first hydration day of the period that ends on `today`.
+    pub fn first_day(self, today: NaiveDate) -> NaiveDate {
+        today
+            .checked_sub_days(Days::new(u64::from(self.days() - 1)))
+            .unwrap_or(NaiveDate::MIN)
+    }
+
+    /// Returns the stable lowercase name used by inbound adapters.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Day => "day",
+            Self::Week => "week",
+            Self::Month => "month",
+        }
+    }
+}
+
+impl FromStr for StatsPeriod {
+    type Err = ();
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "day" | "daily" => Ok(Self::Day),
+            "week" | "weekly" => Ok(Self::Week),
+            "month" | "monthly" => Ok(Self::Month),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Total intake logged during one hydration day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DailyIntake {
+    pub day: NaiveDate,
+    pub total_ml: u64,
+}
+
+impl DailyIntake {
+    /// Returns true when the day's total reached `goal_ml`.
+    pub fn goal_met(&self, goal_ml: u32) -> bool {
+        self.total_ml >= u64::from(goal_ml)
+    }
+}
+
+/// Total intake logged during one local hour of the day (0-23), across a range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HourlyIntake {
+    pub hour: u32,
+    pub total_ml: u64,
+}
+
+/// How the reminders fired in a range were answered.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReminderResponseCounts {
+    pub fired: u64,
+    pub drank: u64,
+    pub snoozed: u64,
+    pub dismissed: u64,
+}
+
+impl ReminderResponseCounts {
+    /// Share of fired reminders answered with a drink, or `None` when none fired.
+    pub fn drink_response_percent(&self) -> Option<f64> {
+        (self.fired > 0).then(|| self.drank as f64 * 100.0 / self.fired as f64)
+    }
+}
+
+/// Runs of consecutive hydration days on which the goal was met.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GoalStreaks {
+    /// Run ending today, or yesterday while today's goal is still open.
+    pub current_days: u32,
+    pub longest_days: u32,
+}
+
+impl GoalStreaks {
+    /// Finds streaks in goal-met days sorted oldest first; days after `today` are ignored.
+    pub fn from_goal_days(goal_days: &[NaiveDate], today: NaiveDate) -> Self {
+        let mut streaks: Self = Self::default();
+        let mut run: u32 = 0;
+        let mut previous: Option<NaiveDate> = None;
+        for &day in goal_days.iter().filter(|day: &&NaiveDate| **day <= today) {
+            run = match previous {
+                Some(previous) if previous.succ_opt() == Some(day) => run + 1,
+                Some(previous) if previous == day => run,
+                _ => 1,
+            };
+            streaks.longest_days = streaks.longest_days.max(run);
+            previous = Some(day);
+        }
+
+        let yesterday: Option<NaiveDate> = today.pred_opt();
+        if previous == Some(today) || (previous.is_some() && previous == yesterday) {
+            streaks.current_days = run;
+        }
+        streaks
+    }
+}
+
+/// Hydration statistics for one `StatsPeriod`, judged against the current goal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HydrationStats {
+    pub period: StatsPeriod,
+    pub first_day: NaiveDate,
+    pub last_day: NaiveDate,
+    pub goal_ml: u32,
+    /// Every day of the period, oldest first, including days without intake.
+    pub days: Vec<DailyIntake>,
+    pub total_ml: u64,
+    pub average_daily_ml: f64,
+    /// Share of the period's days on which the goal was met.
+    pub goal_hit_percent: f64,
+    /// Streaks over the period, including any run of goal days that led into it.
+    pub streaks: GoalStreaks,
+    /// Average intake per local hour of the day (index = hour) over the period's days.
+    pub average_ml_by_hour: [f64; 24],
+    pub reminders: ReminderResponseCounts,
+}
+
+impl HydrationStats {
+    /// Combines aggregated rows into statistics for the period ending on `today`.
+    pub fn new(
+        period: StatsPeriod,
+        today: NaiveDate,
+        goal_ml: u32,
+        totals: &[DailyIntake],
+        hourly: &[HourlyIntake],
+        streaks: GoalStreaks,
+        reminders: ReminderResponseCounts,
+    ) -> Self {
+        let first_day: NaiveDate = period.first_day(today);
+        let days: Vec<DailyIntake> = first_day
+            .iter_days()
+            .take_while(|day: &NaiveDate| *day <= today)
+            .map(|day: NaiveDate| DailyIntake {
+                day,
+                total_ml: totals
+                    .iter()
+                    .find(|total: &&DailyIntake| total.day == day)
+                    .map_or(0, |total: &DailyIntake| total.total_ml),
+            })
+            .collect();
+        let day_count: f64 = days.len().max(1) as f64;
+        let total_ml: u64 = days.iter().map(|day: &DailyIntake| day.total_ml).sum();
+        let met: usize = days.iter().filter(|day: &&DailyIntake| day.goal_met(goal_ml)).count();
+
+        let mut average_ml_by_hour: [f64; 24] = [0.0; 24];
+        for intake in hourly {
+            if let Some(average) = average_ml_by_hour.get_mut(intake.hour as usize) {
+                *average += intake.total_ml as f64 / day_count;
+            }
+        }
+
+        Self {
+            period,
+            first_day,
+            last_day: today,
+            goal_ml,
+            days,
+            total_ml,
+            average_daily_ml: total_ml as f64 / day_count,
+            goal_hit_percent: met as f64 * 100.0 / day_count,
+            streaks,
+            average_ml_by_hour,
+            reminders,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(day_of_month: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, day_of_month).expect("valid date")
+    }
+
+    #[test]
+    fn streaks_count_consecutive_goal_days_up_to_today() {
+        let goal_days: Vec<NaiveDate> = vec![day(1), day(2), day(3), day(5), day(8), day(9)];
+
+        assert_eq!(
+            GoalStreaks::from_goal_days(&goal_days, day(9)),
+            GoalStreaks {
+                current_days: 2,
+                longest_days: 3
+            }
+        );
+        assert_eq!(GoalStreaks::from_goal_days(&goal_days, day(10)).current_days, 2);
+        assert_eq!(GoalStreaks::from_goal_days(&goal_days, day(11)).current_days, 0);
+        assert_eq!(
+            GoalStreaks::from_goal_days(&goal_days, day(4)),
+            GoalStreaks {
+                current_days: 3,
+                longest_days: 3
+            }
+        );
+        assert_eq!(GoalStreaks::from_goal_days(&[], day(4)), GoalStreaks::default());
+    }
+
+    #[test]
+    fn stats_fill_missing_days_and_average_over_the_period() {
+        let totals: Vec<DailyIntake> = vec![
+            DailyIntake {
+                day: day(3),
+                total_ml: 2_100,
+            },
+            DailyIntake {
+                day: day(7),
+                total_ml: 700,
+            },
+        ];
+        let hourly: Vec<HourlyIntake> = vec![HourlyIntake {
+            hour: 9,
+            total_ml: 1_400,
+        }];
+        let reminders: ReminderResponseCounts = ReminderResponseCounts {
+            fired: 4,
+            drank: 1,
+            snoozed: 2,
+            dismissed: 0,
+        };
+
+        let stats: HydrationStats = HydrationStats::new(
+            StatsPeriod::Week,
+            day(7),
+            2_000,
+            &totals,
+            &hourly,
+            GoalStreaks::default(),
+            reminders,
+        );
+
+        assert_eq!(stats.first_day, day(1));
+        assert_eq!(stats.days.len(), 7);
+        assert_eq!(stats.days[2].total_ml, 2_100);
+        assert_eq!(stats.days[3].total_ml, 0);
+        assert_eq!(stats.total_ml, 2_800);
+        assert_eq!(stats.average_daily_ml, 400.0);
+        assert!((stats.goal_hit_percent - 100.0 / 7.0).abs() < 1e-9);
+        assert_eq!(stats.average_ml_by_hour[9], 200.0);
+        assert_eq!(stats.reminders.drink_response_percent(), Some(25.0));
+        assert_eq!(ReminderResponseCounts::default().drink_response_percent(), None);
+        assert_eq!("monthly".parse::<StatsPeriod>(), Ok(StatsPeriod::Month));
+    }
+}
@@ -1,4 +1,9 @@
 //! Shared core error types and result alias used across modules.
+use crate::domain::export::{EXPORT_FORMAT, EXPORT_VERSION};
+use crate::domain::hydration::{
+    CronError, MAX_INTAKE_ML, MAX_INTERVAL_MINUTES, MAX_PAUSE_MINUTES, MAX_SNOOZE_MINUTES,
+};
+use chrono::{DateTime, Utc};
 use std::fmt::{Display, Formatter};
 
 pub type CoreResult<T> = Result<T, CoreError>;
@@ -7,10 +12,61 @@ pub type CoreResult<T> = Result<T, CoreError>;
 pub enum CoreError {
     Sqlite(sqlx::Error),
     InvalidPersistedValue { field: &'static str, value: i64 },
+    SchemaTooNew { database_version: i64, supported_version: i64 },
     SchedulerAlreadyRunning,
+    EmptyJournalEntry,
+    InvalidIntakeAmount(u32),
+    InvalidDayStartHour(u32),
+    InvalidIntervalMinutes(u64),
+    InvalidSnoozeMinutes(u32),
+    InvalidPauseMinutes(u32),
+    ReminderNotFound(i64),
+    InvalidCronExpression(CronError),
+    JobAlreadyExists(String),
+    JobNotFound(String),
+    JobNeverRuns(String),
+    ReminderDeliveryFailed { failed: usize },
+    /// The reminder loop failed and its error was already taken by another caller;
+    /// `reason` is the last error the loop reported.
+    ReminderLoopStopped { reason: String },
+    /// A history range whose start is not before its end.
+    InvalidHistoryRange { from: DateTime<Utc>, until: DateTime<Utc> },
+    /// A history cursor token that was not produced by a previous page.
+    InvalidHistoryCursor(String),
     TaskJoin(tokio::task::JoinError),
+    Io(std::io::Error),
+    /// A config file that is not valid TOML.
+    ConfigSyntax { origin: String, message: String },
+    /// A config value that is the wrong type or out of range; `key` names it as written.
+    InvalidConfig { key: String, origin: String, reason: String },
+    /// An import document that is not a Desk Buddy export, or is from a newer export version.
+    UnsupportedExport { format: String, version: u64 },
+    /// An import document or record that does not parse; `record` names it, for example
+    /// `water_intake[3]`.
+    InvalidImport { record: String, reason: String },
 }
 
+impl CoreError {
+    /// Returns true for SQLite busy/locked errors and pool timeouts, which are worth retrying.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::Sqlite(sqlx::Error::PoolTimedOut) => true,
+            Self::Sqlite(sqlx::Error::Database(err)) => {
+                // Extended result codes keep the primary code in the low byte.
+                let primary: Option<i32> = err
+                    .code()
+                    .and_then(|code: std::borrow::Cow<'_, str>| code.parse::<i32>().ok())
+                    .map(|code: i32| code & 0xff);
+                matches!(primary, Some(SQLITE_BUSY | SQLITE_LOCKED))
+            }
+            _ => false,
+        }
+    }
+}
+
+const SQLITE_BUSY: i32 = 5;
+const SQLITE_LOCKED: i32 = 6;
+
 impl Display for CoreError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -18,8 +74,54 @@ impl Display for CoreError {
             Self::InvalidPersistedValue { field, value } => {
                 write!(f, "invalid persisted value for {field}: {value}")
             }
+            Self::SchemaTooNew {
+                database_version,
+                supported_version,
+            } => write!(
+                f,
+                "database schema version {database_version} is newer than supported version {supported_version}"
+            ),
             Self::SchedulerAlreadyRunning => write!(f, "scheduler is already running"),
+            Self::EmptyJournalEntry => write!(f, "journal entry body must not be empty"),
+            Self::InvalidIntakeAmount(amount_ml) => {
+                write!(f, "invalid intake amount: {amount_ml} ml (expected 1-{MAX_INTAKE_ML} ml)")
+            }
+            Self::InvalidDayStartHour(hour) => {
+                write!(f, "invalid day start hour: {hour} (expected 0-23)")
+            }
+            Self::InvalidIntervalMinutes(minutes) => {
+                write!(f, "invalid interval: {minutes} minute(s) (expected at most {MAX_INTERVAL_MINUTES})")
+            }
+            Self::InvalidSnoozeMinutes(minutes) => {
+                write!(f, "invalid snooze: {minutes} minute(s) (expected 1-{MAX_SNOOZE_MINUTES})")
+            }
+            Self::InvalidPauseMinutes(minutes) => {
+                write!(f, "invalid pause: {minutes} minute(s) (expected 1-{MAX_PAUSE_MINUTES})")
+            }
+            Self::ReminderNotFound(reminder_id) => write!(f, "reminder {reminder_id} not found"),
+            Self::InvalidCronExpression(err) => write!(f, "invalid cron expression: {err}"),
+            Self::JobAlreadyExists(name) => write!(f, "job {name:?} is already scheduled"),
+            Self::JobNotFound(name) => write!(f, "job {name:?} not found"),
+            Self::JobNeverRuns(name) => write!(f, "job {name:?} has no future run time"),
+            Self::ReminderDeliveryFailed { failed } => {
+                write!(f, "reminder was not delivered: all {failed} sink(s) failed")
+            }
+            Self::ReminderLoopStopped { reason } => write!(f, "reminder loop stopped: {reason}"),
+            Self::InvalidHistoryRange { from, until } => {
+                write!(f, "invalid history range: {from} is not before {until}")
+            }
+            Self::InvalidHistoryCursor(raw) => write!(f, "invalid history cursor: {raw:?}"),
             Self::TaskJoin(err) => write!(f, "scheduler task join error: {err}"),
+            Self::Io(err) => write!(f, "io error: {err}"),
+            Self::ConfigSyntax { origin, message } => write!(f, "invalid config in {origin}: {message}"),
+            Self::InvalidConfig { key, origin, reason } => {
+                write!(f, "invalid config key {key} in {origin}: {reason}")
+            }
+            Self::UnsupportedExport { format, version } => write!(
+                f,
+                "unsupported export: {format:?} version {version} (expected {EXPORT_FORMAT:?} version 1-{EXPORT_VERSION})"
+            ),
+            Self::InvalidImport { record, reason } => write!(f, "invalid import {record}: {reason}"),
         }
     }
 }
@@ -32,8 +134,53 @@ impl From<sqlx::Error> for CoreError {
     }
 }
 
+impl From<CronError> for CoreError {
+    fn from(value: CronError) -> Self {
+        Self::InvalidCronExpression(value)
+    }
+}
+
 impl From<tokio::task::JoinError> for CoreError {
     fn from(value: tokio::task::JoinError) -> Self {
         Self::TaskJoin(value)
     }
 }
+
+impl From<std::io::Error> for CoreError {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::{SqliteConnectOptions, SqliteConnection};
+    use sqlx::{Connection, Executor};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn busy_database_errors_are_transient() -> CoreResult<()> {
+        let dir: tempfile::TempDir = tempfile::tempdir().expect("temp dir");
+        let options: SqliteConnectOptions = SqliteConnectOptions::new()
+            .filename(dir.path().join("busy.db"))
+            .create_if_missing(true)
+            .busy_timeout(Duration::ZERO);
+        let mut holder: SqliteConnection = SqliteConnection::connect_with(&options).await?;
+        let mut waiter: SqliteConnection = SqliteConnection::connect_with(&options).await?;
+        holder.execute("BEGIN IMMEDIATE").await?;
+
+        let busy: CoreError = CoreError::from(
+            waiter
+                .execute("BEGIN IMMEDIATE")
+                .await
+                .expect_err("write lock is held"),
+        );
+
+        assert!(busy.is_transient());
+        assert!(CoreError::Sqlite(sqlx::Error::PoolTimedOut).is_transient());
+        assert!(!CoreError::Sqlite(sqlx::Error::RowNotFound).is_transient());
+        assert!(!CoreError::ReminderDeliveryFailed { failed: 1 }.is_transient());
+        Ok(())
+    }
+}
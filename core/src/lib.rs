@@ -7,7 +7,9 @@ pub mod domain;
 pub mod error;
 pub mod ports;
 
-pub use domain::hydration::{ReminderConfig, DEFAULT_REMINDER_MESSAGE};
+pub use domain::hydration::{
+    HydrationReminder, ReminderConfig, ReminderEvent, ReminderResponse, DEFAULT_REMINDER_MESSAGE,
+};
 pub use error::{CoreError, CoreResult};
 pub use api::CoreApi;
-pub use bootstrap::{CoreBuilder, CoreOptions, CoreRuntime};
+pub use bootstrap::{CoreBuilder, CoreOptions, CoreRuntime, ReminderSinkKind, ReminderSinkOptions};
@@ -1,25 +1,38 @@
-//! Minimal runnable entrypoint that exercises the current hydration slice.
-use desk_buddy_core::{CoreApi, CoreBuilder, CoreOptions, CoreRuntime};
+//! `desk-buddy` command-line entrypoint.
+use clap::Parser;
+use desk_buddy_core::adapters::inbound::cli::{self, Cli, CliError};
+use desk_buddy_core::bootstrap::CoreConfig;
+use std::process::ExitCode;
 
 #[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let options: CoreOptions = match std::env::var("DESK_BUDDY_DATABASE_URL") {
-        Ok(database_url) => CoreOptions { database_url },
-        Err(_) => CoreOptions::default(),
+async fn main() -> ExitCode {
+    let cli: Cli = Cli::parse();
+    let json: bool = cli.json;
+    let config: CoreConfig = match cli::load_config(&cli) {
+        Ok(config) => config,
+        Err(err) => {
+            report(&err, json);
+            return ExitCode::FAILURE;
+        }
     };
+    tracing_subscriber::fmt()
+        .with_writer(std::io::stderr)
+        .with_max_level(config.log_level)
+        .init();
 
-    let runtime: CoreRuntime = CoreBuilder::new(options).build().await?;
-    let api: &CoreApi = runtime.api();
-
-    if let Ok(raw_interval) = std::env::var("DESK_BUDDY_INTERVAL_MINUTES") {
-        let requested: u64 = raw_interval.parse::<u64>()?;
-        let normalized: u64 = api.set_hydration_interval_minutes(requested).await?;
-        println!("Hydration interval set to {normalized} minute(s).");
+    match cli::run(cli, config).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            report(&err, json);
+            ExitCode::FAILURE
+        }
     }
+}
 
-    let _: String = api.trigger_hydration_reminder_once().await?;
-    let total: i64 = api.hydration_log_count().await?;
-    println!("Total hydration reminders logged: {total}");
-
-    Ok(())
+/// Prints the error to stderr, and as `{"error": ...}` on stdout in JSON mode.
+fn report(err: &CliError, json: bool) {
+    if json {
+        println!("{}", serde_json::json!({ "error": err.to_string() }));
+    }
+    eprintln!("error: {err}");
 }
@@ -0,0 +1,13 @@
+//! Output port for persisting unlocked achievements.
+use crate::domain::achievements::AchievementUnlock;
+use crate::error::CoreResult;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[async_trait]
+pub trait AchievementRepository: Send + Sync {
+    /// Records an unlock; returns false when the achievement was already unlocked.
+    async fn insert_achievement_unlock(&self, id: &str, unlocked_at: DateTime<Utc>) -> CoreResult<bool>;
+    /// Lists every stored unlock, oldest first.
+    async fn achievement_unlocks(&self) -> CoreResult<Vec<AchievementUnlock>>;
+}
@@ -0,0 +1,7 @@
+//! Output port for reading the current wall-clock time.
+use chrono::{DateTime, Utc};
+
+pub trait Clock: Send + Sync {
+    /// Returns the current time in UTC.
+    fn now(&self) -> DateTime<Utc>;
+}
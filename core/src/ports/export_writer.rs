@@ -0,0 +1,24 @@
+//! Output port that serializes an export as records stream out of the database.
+use crate::domain::export::{ExportSettings, ExportTable};
+use crate::domain::history::ReminderLogEntry;
+use crate::domain::hydration::WaterIntake;
+use crate::domain::journal::JournalEntry;
+use crate::error::CoreResult;
+use chrono::{DateTime, Utc};
+
+/// Receives one export in order: `begin`, then each table's records after its
+/// `begin_table`, then `finish`. Writers should not buffer whole tables.
+pub trait ExportWriter: Send {
+    /// Starts the export with the settings in effect when it was taken.
+    fn begin(&mut self, exported_at: DateTime<Utc>, settings: &ExportSettings) -> CoreResult<()>;
+    /// Starts a table; records that follow belong to it until the next call.
+    fn begin_table(&mut self, table: ExportTable) -> CoreResult<()>;
+    /// Writes one logged reminder.
+    fn reminder_log(&mut self, entry: &ReminderLogEntry) -> CoreResult<()>;
+    /// Writes one logged drink.
+    fn water_intake(&mut self, intake: &WaterIntake) -> CoreResult<()>;
+    /// Writes one journal entry.
+    fn journal_entry(&mut self, entry: &JournalEntry) -> CoreResult<()>;
+    /// Completes the export and flushes everything written.
+    fn finish(&mut self) -> CoreResult<()>;
+}
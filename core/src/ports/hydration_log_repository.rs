@@ -1,11 +1,38 @@
 //! Output port for persisting and querying hydration reminder logs.
+use crate::domain::history::{HistoryPage, HistoryQuery, ReminderLogEntry};
+use crate::domain::hydration::{HydrationReminder, ReminderResponse};
+use crate::domain::stats::ReminderResponseCounts;
 use crate::error::CoreResult;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
 #[async_trait]
 pub trait HydrationLogRepository: Send + Sync {
-    /// Persists a hydration reminder log entry.
-    async fn insert_hydration_log(&self, message: &str) -> CoreResult<()>;
+    /// Persists a hydration reminder log entry and returns it with its assigned ID.
+    async fn insert_hydration_log(
+        &self,
+        message: &str,
+        triggered_at: DateTime<Utc>,
+    ) -> CoreResult<HydrationReminder>;
     /// Returns the number of hydration reminder log entries.
     async fn hydration_log_count(&self) -> CoreResult<i64>;
+    /// Returns when the most recent reminder fired, if any has.
+    async fn latest_reminder_at(&self) -> CoreResult<Option<DateTime<Utc>>>;
+    /// Stores the user's response against a reminder; returns false when the ID is unknown.
+    ///
+    /// `Drank` with an amount also logs that drink at `responded_at`, in the same transaction.
+    async fn record_reminder_response(
+        &self,
+        reminder_id: i64,
+        response: ReminderResponse,
+        responded_at: DateTime<Utc>,
+    ) -> CoreResult<bool>;
+    /// Lists logged reminders by `triggered_at` within the query's range, one page at a time.
+    async fn list_hydration_logs(&self, query: &HistoryQuery) -> CoreResult<HistoryPage<ReminderLogEntry>>;
+    /// Counts reminders fired in `[start, end)` by how they were answered.
+    async fn reminder_response_counts(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> CoreResult<ReminderResponseCounts>;
 }
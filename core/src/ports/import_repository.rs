@@ -0,0 +1,16 @@
+//! Output port for merging imported records into storage.
+use crate::domain::export::{ExportSettings, ImportRecords, ImportStrategy, ImportSummary};
+use crate::error::CoreResult;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait ImportRepository: Send + Sync {
+    /// Merges every record with `ImportStrategy::resolve` and saves `settings`, if given,
+    /// all or nothing; returns the counts per table, leaving the settings counts empty.
+    async fn import_records(
+        &self,
+        records: &ImportRecords,
+        settings: Option<&ExportSettings>,
+        strategy: ImportStrategy,
+    ) -> CoreResult<ImportSummary>;
+}
@@ -0,0 +1,23 @@
+//! Output port for persisting and querying journal entries.
+use crate::domain::journal::{EntryDate, JournalEntry};
+use crate::error::CoreResult;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait JournalEntryRepository: Send + Sync {
+    /// Inserts the entry for a date, or replaces its body when one already exists.
+    async fn upsert_journal_entry(
+        &self,
+        entry_date: EntryDate,
+        prompt_id: Option<i64>,
+        body: &str,
+    ) -> CoreResult<JournalEntry>;
+    /// Loads the entry written for a date, if any.
+    async fn journal_entry_for_date(&self, entry_date: EntryDate) -> CoreResult<Option<JournalEntry>>;
+    /// Lists the most recent entries, newest date first.
+    async fn list_journal_entries(&self, limit: u32) -> CoreResult<Vec<JournalEntry>>;
+    /// Lists up to `limit` entries dated after `after` (all when `None`), oldest date first.
+    async fn list_journal_entries_after(&self, after: Option<EntryDate>, limit: u32) -> CoreResult<Vec<JournalEntry>>;
+    /// Returns how many entries exist.
+    async fn journal_entry_count(&self) -> CoreResult<u64>;
+}
@@ -1,4 +1,11 @@
 //! Port trait definitions used by application services.
+pub mod achievement_repository;
+pub mod clock;
+pub mod export_writer;
 pub mod hydration_log_repository;
+pub mod import_repository;
+pub mod journal_entry_repository;
+pub mod prompt_repository;
 pub mod reminder_event_sink;
 pub mod settings_repository;
+pub mod water_intake_repository;
@@ -0,0 +1,10 @@
+//! Output port for reading the catalog of daily journal prompts.
+use crate::domain::journal::JournalPrompt;
+use crate::error::CoreResult;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait PromptRepository: Send + Sync {
+    /// Returns every available prompt in stable rotation order.
+    async fn list_prompts(&self) -> CoreResult<Vec<JournalPrompt>>;
+}
@@ -1,9 +1,21 @@
 //! Output port for emitting reminder events to external channels.
+use crate::domain::achievements::Achievement;
+use crate::domain::hydration::ReminderEvent;
 use crate::error::CoreResult;
 use async_trait::async_trait;
 
 #[async_trait]
 pub trait ReminderEventSink: Send + Sync {
-    /// Emits a hydration reminder event to the configured output channel.
-    async fn emit_hydration_reminder(&self, message: &str) -> CoreResult<()>;
+    /// Emits a fired reminder to the configured output channel.
+    async fn emit_reminder(&self, event: &ReminderEvent) -> CoreResult<()>;
+
+    /// Announces a newly unlocked achievement; sinks without a way to show one ignore it.
+    async fn emit_achievement(&self, _achievement: &Achievement) -> CoreResult<()> {
+        Ok(())
+    }
+
+    /// Finishes delivering anything accepted but not yet written; called on shutdown.
+    async fn flush(&self) -> CoreResult<()> {
+        Ok(())
+    }
 }
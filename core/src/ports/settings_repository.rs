@@ -1,4 +1,7 @@
 //! Output port for reading and writing persisted settings.
+use crate::domain::hydration::{
+    ActiveWindow, CatchUpPolicy, CronSchedule, QuietHours, ReminderPause, SavedReminderSettings,
+};
 use crate::error::CoreResult;
 use async_trait::async_trait;
 
@@ -6,6 +9,26 @@ use async_trait::async_trait;
 pub trait SettingsRepository: Send + Sync {
     /// Saves the hydration interval in minutes.
     async fn set_hydration_interval_minutes(&self, interval_minutes: u64) -> CoreResult<()>;
-    /// Loads the hydration interval in minutes, if configured.
-    async fn hydration_interval_minutes(&self) -> CoreResult<Option<u64>>;
+    /// Saves the daily intake goal in millilitres.
+    async fn set_daily_goal_ml(&self, daily_goal_ml: u32) -> CoreResult<()>;
+    /// Loads the daily intake goal in millilitres, if configured.
+    async fn daily_goal_ml(&self) -> CoreResult<Option<u32>>;
+    /// Saves the local hour (0-23) at which a new hydration day begins.
+    async fn set_day_start_hour(&self, day_start_hour: u32) -> CoreResult<()>;
+    /// Loads the local day start hour, if configured.
+    async fn day_start_hour(&self) -> CoreResult<Option<u32>>;
+    /// Saves or clears the cron schedule that replaces the fixed interval.
+    async fn set_cron_schedule(&self, cron: Option<CronSchedule>) -> CoreResult<()>;
+    /// Saves or clears the daily window in which reminders may fire.
+    async fn set_active_window(&self, active_window: Option<ActiveWindow>) -> CoreResult<()>;
+    /// Saves the daily quiet hours, or with `None` saves that there are none.
+    async fn set_quiet_hours(&self, quiet_hours: Option<QuietHours>) -> CoreResult<()>;
+    /// Removes any saved quiet hours, including a saved "none".
+    async fn clear_quiet_hours(&self) -> CoreResult<()>;
+    /// Saves the policy for reminders missed during sleep or suspend.
+    async fn set_catch_up_policy(&self, policy: CatchUpPolicy) -> CoreResult<()>;
+    /// Saves or clears the pause on reminders.
+    async fn set_reminder_pause(&self, pause: Option<ReminderPause>) -> CoreResult<()>;
+    /// Loads every saved reminder setting at once.
+    async fn reminder_settings(&self) -> CoreResult<SavedReminderSettings>;
 }
@@ -0,0 +1,41 @@
+//! Output port for persisting and querying recorded water intake.
+use crate::domain::history::{HistoryPage, HistoryQuery};
+use crate::domain::hydration::{IntakeSource, WaterIntake};
+use crate::domain::stats::{DailyIntake, HourlyIntake};
+use crate::error::CoreResult;
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+
+#[async_trait]
+pub trait WaterIntakeRepository: Send + Sync {
+    /// Persists one drink and returns the stored record.
+    async fn insert_water_intake(
+        &self,
+        amount_ml: u32,
+        consumed_at: DateTime<Utc>,
+        source: Option<IntakeSource>,
+    ) -> CoreResult<WaterIntake>;
+    /// Deletes the most recently logged drink and returns it, if any.
+    async fn delete_latest_water_intake(&self) -> CoreResult<Option<WaterIntake>>;
+    /// Sums intake consumed in the half-open UTC range `[start, end)`.
+    async fn total_intake_ml_between(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> CoreResult<u64>;
+    /// Lists drinks by `consumed_at` within the query's range, one page at a time.
+    async fn list_water_intake(&self, query: &HistoryQuery) -> CoreResult<HistoryPage<WaterIntake>>;
+    /// Sums intake in `[start, end)` per local hydration day, oldest first; days without intake are omitted.
+    async fn daily_intake_totals(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        day_start_hour: u32,
+    ) -> CoreResult<Vec<DailyIntake>>;
+    /// Lists the local hydration days in `[start, end)` whose total reached `goal_ml`, oldest first.
+    async fn goal_met_days(
+        &self,
+        goal_ml: u32,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        day_start_hour: u32,
+    ) -> CoreResult<Vec<NaiveDate>>;
+    /// Sums intake in `[start, end)` per local hour of the day; hours without intake are omitted.
+    async fn intake_by_hour(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> CoreResult<Vec<HourlyIntake>>;
+}
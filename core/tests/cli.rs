@@ -0,0 +1,215 @@
+//! Runs the `desk-buddy` binary against a temporary database.
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+/// Runs the binary with no config file or `DESK_BUDDY_*` variables from the caller.
+fn isolated(database: &Path) -> Command {
+    let mut command: Command = Command::new(env!("CARGO_BIN_EXE_desk-buddy"));
+    command
+        .arg("--database-url")
+        .arg(format!("sqlite://{}", database.display()))
+        .env("XDG_CONFIG_HOME", database.parent().expect("database in a directory"));
+    for (name, _) in std::env::vars().filter(|(name, _): &(String, String)| name.starts_with("DESK_BUDDY_")) {
+        command.env_remove(name);
+    }
+    command
+}
+
+fn desk_buddy(database: &Path, args: &[&str]) -> Output {
+    isolated(database).args(args).output().expect("binary runs")
+}
+
+fn stdout(output: &Output) -> String {
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout.clone()).expect("UTF-8 output")
+}
+
+fn json_stdout(output: &Output) -> Value {
+    serde_json::from_str(&stdout(output)).expect("JSON output")
+}
+
+#[test]
+fn commands_print_text_or_json() {
+    let dir: TempDir = tempfile::tempdir().expect("temp dir");
+    let database: &Path = &dir.path().join("desk-buddy.db");
+
+    let migrated: Value = json_stdout(&desk_buddy(database, &["db", "migrate", "--json"]));
+    assert_eq!(migrated["from_version"], 0);
+    assert_eq!(
+        stdout(&desk_buddy(database, &["db", "migrate"])).trim(),
+        format!("Database is already at schema version {}.", migrated["to_version"])
+    );
+
+    let set: Value = json_stdout(&desk_buddy(database, &["config", "set", "interval", "45", "--json"]));
+    assert_eq!(set, json!({"interval": 45}));
+    assert_eq!(
+        stdout(&desk_buddy(database, &["config", "set", "quiet-hours", "22:00-07:00"])).trim(),
+        "quiet-hours = 22:00-07:00"
+    );
+    let all: Value = json_stdout(&desk_buddy(database, &["config", "get", "--json"]));
+    assert_eq!(all["interval"], 45);
+    assert_eq!(all["quiet-hours"], json!({"start": "22:00", "end": "07:00"}));
+    assert_eq!(all["cron"], Value::Null);
+
+    let logged: String = stdout(&desk_buddy(database, &["log", "drink", "500", "--source", "glass"]));
+    assert_eq!(logged.trim(), "Logged 500 ml from a glass. Today: 500 / 2000 ml (25%), 1500 ml to go");
+    let reminder: Value = json_stdout(&desk_buddy(database, &["remind", "--json"]));
+    assert_eq!(reminder["kind"], "manual");
+    assert_eq!(reminder["progress"]["consumed_ml"], 500);
+
+    let stats: Value = json_stdout(&desk_buddy(database, &["stats", "--json"]));
+    assert_eq!(stats["reminders_logged"], 1);
+    assert_eq!(stats["goal"]["daily_goal_ml"], 2000);
+    assert_eq!(stats["stats"]["period"], "week");
+    assert_eq!(stats["stats"]["days"].as_array().map(Vec::len), Some(7));
+    assert_eq!(stats["stats"]["reminders"]["fired"], 1);
+    let month: Value = json_stdout(&desk_buddy(database, &["stats", "--period", "month", "--json"]));
+    assert_eq!(month["stats"]["total_ml"], 500);
+    let achievements: Value = json_stdout(&desk_buddy(database, &["achievements", "--json"]));
+    assert_eq!(achievements[0]["id"], "first_goal");
+    assert_eq!(achievements[0]["unlocked_at"], Value::Null);
+    let history: Value = json_stdout(&desk_buddy(database, &["history", "journal", "--json"]));
+    assert_eq!(history, json!([]));
+    let reminders: Value = json_stdout(&desk_buddy(database, &["history", "reminders", "--json"]));
+    assert_eq!(reminders["items"][0]["id"], reminder["id"]);
+    assert_eq!(reminders["items"][0]["outcome"], Value::Null);
+    let drinks: Value = json_stdout(&desk_buddy(database, &["history", "intake", "--limit", "1", "--json"]));
+    assert_eq!(drinks["items"][0]["amount_ml"], 500);
+    assert_eq!(drinks["next_cursor"], Value::Null);
+    assert!(stdout(&desk_buddy(database, &["history", "intake", "--to", "2000-01-01"])).contains("No drinks"));
+
+    let export_path: String = dir.path().join("export.json").display().to_string();
+    let exported: Value = json_stdout(&desk_buddy(database, &["export", "--output", &export_path, "--json"]));
+    assert_eq!(exported["journal_entries"], 0);
+    assert_eq!(exported["water_intake"], 1);
+    let document: Value =
+        serde_json::from_str(&std::fs::read_to_string(&export_path).expect("export written")).expect("JSON export");
+    assert_eq!(document["format"], "desk-buddy-export");
+    assert_eq!(document["version"], 1);
+    assert_eq!(document["settings"]["interval_minutes"], 45);
+    assert_eq!(document["settings"]["quiet_hours"], json!({"start": "22:00", "end": "07:00"}));
+    assert_eq!(document["reminder_logs"][0]["id"], reminder["id"]);
+    assert_eq!(document["water_intake"][0]["source"], "glass");
+    assert_eq!(document["journal_entries"], json!([]));
+
+    let moved: &Path = &dir.path().join("moved.db");
+    let imported: Value = json_stdout(&desk_buddy(moved, &["import", &export_path, "--strategy", "overwrite", "--json"]));
+    assert_eq!(imported["strategy"], "overwrite");
+    assert_eq!(imported["settings"], json!({"inserted": 0, "skipped": 0, "conflicting": 1}));
+    assert_eq!(imported["water_intake"], json!({"inserted": 1, "skipped": 0, "conflicting": 0}));
+    assert_eq!(imported["reminder_logs"]["inserted"], 1);
+    assert_eq!(stdout(&desk_buddy(moved, &["config", "get", "interval"])).trim(), "interval = 45");
+    let again: String = stdout(&desk_buddy(moved, &["import", &export_path]));
+    assert!(again.contains("with skip_duplicates:"), "{again}");
+    assert!(again.contains("water_intake: 0 inserted, 1 skipped, 0 conflicting"), "{again}");
+
+    let piped: Value = json_stdout(&desk_buddy(database, &["export", "--table", "intake"]));
+    assert_eq!(piped["water_intake"][0]["amount_ml"], 500);
+    assert!(piped.get("reminder_logs").is_none());
+
+    let csv_dir: PathBuf = dir.path().join("csv");
+    let written: String = stdout(&desk_buddy(
+        database,
+        &["export", "--format", "csv", "--output", &csv_dir.display().to_string()],
+    ));
+    assert!(written.starts_with("Exported 1 reminder(s), 1 drink(s) and 0 journal entries to "));
+    let intake_csv: String = std::fs::read_to_string(csv_dir.join("water_intake.csv")).expect("intake CSV");
+    assert_eq!(intake_csv.lines().next(), Some("id,amount_ml,consumed_at,source"));
+    assert!(intake_csv.lines().nth(1).is_some_and(|row: &str| row.ends_with(",glass")));
+    assert!(csv_dir.join("journal_entries.csv").exists());
+}
+
+#[test]
+fn failures_exit_non_zero_with_a_message() {
+    let dir: TempDir = tempfile::tempdir().expect("temp dir");
+    let database: &Path = &dir.path().join("desk-buddy.db");
+
+    let rejected: Output = desk_buddy(database, &["log", "drink", "0", "--json"]);
+    assert!(!rejected.status.success());
+    let reply: Value = serde_json::from_slice(&rejected.stdout).expect("JSON error");
+    assert_eq!(reply, json!({"error": "invalid intake amount: 0 ml (expected 1-5000 ml)"}));
+
+    let invalid: Output = desk_buddy(database, &["config", "set", "catch-up", "sometimes"]);
+    assert!(!invalid.status.success());
+    assert!(String::from_utf8_lossy(&invalid.stderr)
+        .contains("error: invalid value \"sometimes\" for catch-up: expected fire_once, skip or fire_all"));
+
+    let newer: PathBuf = dir.path().join("newer.json");
+    std::fs::write(&newer, r#"{"format":"desk-buddy-export","version":99}"#).expect("export written");
+    let unsupported: Output = desk_buddy(database, &["import", &newer.display().to_string()]);
+    assert!(!unsupported.status.success());
+    assert!(String::from_utf8_lossy(&unsupported.stderr).contains("unsupported export: \"desk-buddy-export\" version 99"));
+}
+
+#[test]
+fn config_files_and_environment_set_defaults() {
+    let dir: TempDir = tempfile::tempdir().expect("temp dir");
+    let database: &Path = &dir.path().join("desk-buddy.db");
+    std::fs::create_dir(dir.path().join("desk-buddy")).expect("config dir");
+    std::fs::write(
+        dir.path().join("desk-buddy/config.toml"),
+        "[reminders]\ninterval_minutes = 20\nquiet_hours = \"23:00-06:30\"\n",
+    )
+    .expect("config written");
+
+    let found: Value = json_stdout(&desk_buddy(database, &["config", "get", "--json"]));
+    assert_eq!(found["interval"], 20);
+    assert_eq!(found["quiet-hours"], json!({"start": "23:00", "end": "06:30"}));
+    assert_eq!(
+        stdout(&desk_buddy(database, &["config", "set", "quiet-hours", "none"])).trim(),
+        "quiet-hours = none"
+    );
+    assert_eq!(
+        stdout(&desk_buddy(database, &["config", "set", "quiet-hours", "default"])).trim(),
+        "quiet-hours = 23:00-06:30"
+    );
+    let from_env: Output = isolated(database)
+        .args(["config", "get", "interval"])
+        .env("DESK_BUDDY_INTERVAL_MINUTES", "25")
+        .output()
+        .expect("binary runs");
+    assert_eq!(stdout(&from_env).trim(), "interval = 25");
+
+    let broken: String = dir.path().join("broken.toml").display().to_string();
+    std::fs::write(&broken, "[reminders]\ninterval_minutes = \"often\"\n").expect("config written");
+    let rejected: Output = desk_buddy(database, &["--config", &broken, "stats"]);
+    assert!(!rejected.status.success());
+    assert!(String::from_utf8_lossy(&rejected.stderr).contains(&format!(
+        "error: invalid config key reminders.interval_minutes in {broken}: expected a whole number of minutes"
+    )));
+}
+
+#[cfg(unix)]
+#[test]
+fn run_stops_cleanly_on_sigterm() {
+    use std::io::{BufRead, BufReader, Read};
+    use std::process::{Child, ChildStderr, Stdio};
+
+    let dir: TempDir = tempfile::tempdir().expect("temp dir");
+    let mut child: Child = isolated(&dir.path().join("desk-buddy.db"))
+        .arg("run")
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("binary starts");
+    let mut stderr: BufReader<ChildStderr> = BufReader::new(child.stderr.take().expect("piped stderr"));
+    let mut line: String = String::new();
+    while !line.contains("is running") {
+        line.clear();
+        assert_ne!(stderr.read_line(&mut line).expect("stderr readable"), 0, "exited before running");
+    }
+
+    let killed: bool = Command::new("kill")
+        .args(["-TERM", &child.id().to_string()])
+        .status()
+        .expect("kill runs")
+        .success();
+    assert!(killed);
+    let mut rest: String = String::new();
+    stderr.read_to_string(&mut rest).expect("stderr readable");
+
+    assert!(child.wait().expect("binary exits").success(), "{rest}");
+    assert!(rest.contains("Desk Buddy stopped on SIGTERM."), "{rest}");
+}
@@ -0,0 +1,295 @@
+//! Drives the HTTP adapter over a real loopback socket with a minimal HTTP/1.1 client.
+use desk_buddy_core::adapters::inbound::http::HttpServer;
+use desk_buddy_core::domain::hydration::WaterIntake;
+use desk_buddy_core::{CoreBuilder, CoreOptions, CoreResult, CoreRuntime, ReminderEvent};
+use serde_json::{json, Value};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+/// Starts an in-memory runtime with no output sinks and serves it on a free loopback port.
+async fn serve() -> CoreResult<(Arc<CoreRuntime>, HttpServer)> {
+    let options: CoreOptions = CoreOptions {
+        reminder_sinks: Vec::new(),
+        ..CoreOptions::in_memory()
+    };
+    let runtime: Arc<CoreRuntime> = Arc::new(CoreBuilder::new(options).build().await?);
+    let server: HttpServer = HttpServer::start(SocketAddr::from(([127, 0, 0, 1], 0)), runtime.clone()).await?;
+    Ok((runtime, server))
+}
+
+async fn send(server: &HttpServer, method: &str, path: &str, body: Option<Value>) -> TcpStream {
+    send_with_headers(server, method, path, "host: localhost\r\n", body).await
+}
+
+/// Sends a request with the given header lines, each ending in CRLF, in place of the default `host`.
+async fn send_with_headers(
+    server: &HttpServer,
+    method: &str,
+    path: &str,
+    headers: &str,
+    body: Option<Value>,
+) -> TcpStream {
+    let mut stream: TcpStream = TcpStream::connect(server.local_addr()).await.expect("server listening");
+    let body: String = body.map(|body: Value| body.to_string()).unwrap_or_default();
+    let request: String = format!(
+        "{method} {path} HTTP/1.1\r\n{headers}connection: close\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{body}",
+        body.len()
+    );
+    stream.write_all(request.as_bytes()).await.expect("socket writable");
+    stream
+}
+
+/// Sends one request and returns the status code and the JSON body (`null` when empty).
+async fn request(server: &HttpServer, method: &str, path: &str, body: Option<Value>) -> (u16, Value) {
+    read_response(send(server, method, path, body).await).await
+}
+
+async fn read_response(mut stream: TcpStream) -> (u16, Value) {
+    let mut raw: String = String::new();
+    stream.read_to_string(&mut raw).await.expect("socket readable");
+
+    let (head, body): (&str, &str) = raw.split_once("\r\n\r\n").expect("complete response");
+    let status: u16 = head[9..12].parse::<u16>().expect("status code");
+    let json: Value = if body.is_empty() {
+        Value::Null
+    } else {
+        serde_json::from_str(body).expect("JSON body")
+    };
+    (status, json)
+}
+
+#[tokio::test]
+async fn reminder_endpoints_drive_the_api() -> CoreResult<()> {
+    let (_runtime, mut server): (Arc<CoreRuntime>, HttpServer) = serve().await?;
+
+    let (status, reminder): (u16, Value) = request(&server, "POST", "/reminders/trigger", None).await;
+    assert_eq!(status, 200);
+    assert_eq!(reminder["kind"], "manual");
+    let respond_path: String = format!("/reminders/{}/response", reminder["id"]);
+    let snoozed: (u16, Value) =
+        request(&server, "POST", &respond_path, Some(json!({"response": "snoozed", "minutes": 10}))).await;
+    assert_eq!(snoozed, (204, Value::Null));
+    let missing: (u16, Value) =
+        request(&server, "POST", "/reminders/99/response", Some(json!({"response": "dismissed"}))).await;
+    assert_eq!(missing, (404, json!({"error": "reminder 99 not found"})));
+    assert_eq!(request(&server, "GET", "/reminders/count", None).await.1, json!({"count": 1}));
+
+    let interval: Value = request(&server, "PUT", "/reminders/interval", Some(json!({"minutes": 45}))).await.1;
+    assert_eq!(interval, json!({"interval_minutes": 45}));
+    let cron: Value = request(&server, "PUT", "/reminders/cron", Some(json!({"expression": "*/30 9-17 * * mon-fri"})))
+        .await
+        .1;
+    assert_eq!(cron["cron"], "0,30 9-17 * * 1-5");
+    let (status, invalid): (u16, Value) =
+        request(&server, "PUT", "/reminders/cron", Some(json!({"expression": "0 24 * * *"}))).await;
+    assert_eq!(status, 400);
+    assert_eq!(invalid["error"], "invalid cron expression: hour value 24 is out of range (0-23)");
+
+    let window: Value = request(
+        &server,
+        "PUT",
+        "/reminders/active-window",
+        Some(json!({"start": "09:00", "end": "17:30", "days": ["mon", "Friday"]})),
+    )
+    .await
+    .1;
+    assert_eq!(
+        window["active_window"],
+        json!({"start": "09:00", "end": "17:30", "days": ["mon", "fri"]})
+    );
+    let quiet: Value = request(
+        &server,
+        "PUT",
+        "/reminders/quiet-hours",
+        Some(json!({"start": "22:00", "end": "07:00"})),
+    )
+    .await
+    .1;
+    assert_eq!(quiet["quiet_hours"], json!({"start": "22:00", "end": "07:00"}));
+    let quiet_off: Value = request(&server, "PUT", "/reminders/quiet-hours", Some(Value::Null)).await.1;
+    assert_eq!(quiet_off["quiet_hours"], Value::Null);
+    let quiet_default: Value = request(&server, "DELETE", "/reminders/quiet-hours", None).await.1;
+    assert_eq!(quiet_default["quiet_hours"], Value::Null);
+    let catch_up: Value =
+        request(&server, "PUT", "/reminders/catch-up", Some(json!({"policy": "skip"}))).await.1;
+    assert_eq!(catch_up["catch_up"], "skip");
+
+    let config: Value = request(&server, "GET", "/reminders/config", None).await.1;
+    assert_eq!(config["interval_minutes"], 45);
+    assert_eq!(config["pause"], Value::Null);
+    let cleared: Value = request(&server, "PUT", "/reminders/active-window", Some(Value::Null)).await.1;
+    assert_eq!(cleared["active_window"], Value::Null);
+
+    server.stop().await
+}
+
+#[tokio::test]
+async fn pause_intake_goal_and_journal_endpoints_drive_the_api() -> CoreResult<()> {
+    let (_runtime, mut server): (Arc<CoreRuntime>, HttpServer) = serve().await?;
+
+    let pause: Value =
+        request(&server, "PUT", "/reminders/pause", Some(json!({"length": {"minutes": 30}}))).await.1;
+    assert_eq!(pause["indefinite"], false);
+    assert_eq!(request(&server, "GET", "/reminders/pause", None).await.1, pause);
+    let resumed: Value = request(&server, "DELETE", "/reminders/pause", None).await.1;
+    assert_eq!(resumed["pause"], Value::Null);
+    let status: Value = request(&server, "GET", "/scheduler/status", None).await.1;
+    assert_eq!(status["state"], "stopped");
+
+    let (status, intake): (u16, Value) =
+        request(&server, "POST", "/intake", Some(json!({"amount_ml": 300, "source": "bottle"}))).await;
+    assert_eq!(status, 201);
+    assert_eq!(intake["source"], "bottle");
+    let (status, invalid): (u16, Value) = request(&server, "POST", "/intake", Some(json!({"amount_ml": 0}))).await;
+    assert_eq!(status, 400);
+    assert_eq!(invalid["error"], "invalid intake amount: 0 ml (expected 1-5000 ml)");
+    assert_eq!(request(&server, "GET", "/intake/today", None).await.1, json!({"total_ml": 300}));
+    assert_eq!(
+        request(&server, "GET", "/history/intake?order=oldest_first&limit=10", None).await.1,
+        json!({"items": [intake], "next_cursor": null})
+    );
+    let (status, invalid): (u16, Value) = request(&server, "GET", "/history/intake?cursor=next", None).await;
+    assert_eq!(status, 400);
+    assert_eq!(invalid["error"], "invalid history cursor: \"next\"");
+    let (status, _): (u16, Value) =
+        request(&server, "GET", "/history/reminders?from=2026-03-02&to=2026-03-01", None).await;
+    assert_eq!(status, 400);
+
+    let goal: Value = request(&server, "PUT", "/goal/daily", Some(json!({"daily_goal_ml": 1200}))).await.1;
+    assert_eq!(goal, json!({"daily_goal_ml": 1200}));
+    let (status, _): (u16, Value) = request(&server, "PUT", "/goal/day-start", Some(json!({"day_start_hour": 24}))).await;
+    assert_eq!(status, 400);
+    assert_eq!(
+        request(&server, "GET", "/goal", None).await.1,
+        json!({"daily_goal_ml": 1200, "day_start_hour": 0})
+    );
+    let progress: Value = request(&server, "GET", "/progress/today", None).await.1;
+    assert_eq!(progress["remaining_ml"], 900);
+    let stats: Value = request(&server, "GET", "/stats?period=day", None).await.1;
+    assert_eq!(stats["period"], "day");
+    assert_eq!(stats["total_ml"], 300);
+    let (status, _): (u16, Value) = request(&server, "GET", "/stats?period=year", None).await;
+    assert_eq!(status, 400);
+    assert_eq!(request(&server, "DELETE", "/intake/last", None).await.1, intake);
+    assert_eq!(request(&server, "DELETE", "/intake/last", None).await.1, Value::Null);
+
+    let prompt: Value = request(&server, "GET", "/journal/prompt/today", None).await.1;
+    assert!(prompt["text"].is_string());
+    let saved: Value =
+        request(&server, "PUT", "/journal/2026-03-01", Some(json!({"body": "  calm day  "}))).await.1;
+    assert_eq!(saved["body"], "calm day");
+    assert_eq!(request(&server, "GET", "/journal/2026-03-01", None).await.1, saved);
+    assert_eq!(request(&server, "GET", "/journal/2026-03-02", None).await.1, Value::Null);
+    let today: Value = request(&server, "PUT", "/journal/today", Some(json!({"body": "wrote tests"}))).await.1;
+    let entries: Value = request(&server, "GET", "/journal?limit=1", None).await.1;
+    assert_eq!(entries, json!([today]));
+    let achievements: Value = request(&server, "GET", "/achievements", None).await.1;
+    let first_entry: Option<&Value> = achievements
+        .as_array()
+        .and_then(|all: &Vec<Value>| all.iter().find(|achievement: &&Value| achievement["id"] == "first_journal_entry"));
+    assert!(first_entry.is_some_and(|achievement: &Value| achievement["unlocked_at"].is_string()));
+    let (status, _): (u16, Value) = request(&server, "GET", "/journal/yesterday", None).await;
+    assert_eq!(status, 400);
+    let (status, _): (u16, Value) = request(&server, "PUT", "/journal/today", Some(json!({"body": " "}))).await;
+    assert_eq!(status, 400);
+
+    server.stop().await
+}
+
+#[tokio::test]
+async fn event_stream_pushes_reminders_until_the_server_stops() -> CoreResult<()> {
+    let (runtime, mut server): (Arc<CoreRuntime>, HttpServer) = serve().await?;
+    let mut stream: TcpStream = send(&server, "GET", "/events", None).await;
+    let mut received: Vec<u8> = Vec::new();
+    let mut buffer: [u8; 1024] = [0; 1024];
+
+    // Trigger until the stream is subscribed and the reminder arrives.
+    let fired: ReminderEvent = loop {
+        let fired: ReminderEvent = runtime.api().trigger_hydration_reminder_once().await?;
+        if let Ok(read) = tokio::time::timeout(Duration::from_millis(100), stream.read(&mut buffer)).await {
+            received.extend_from_slice(&buffer[..read.expect("socket readable")]);
+            if String::from_utf8_lossy(&received).contains("event: reminder") {
+                break fired;
+            }
+        }
+    };
+    let text: String = String::from_utf8_lossy(&received).into_owned();
+    assert!(text.starts_with("HTTP/1.1 200 OK"));
+    assert!(text.contains("content-type: text/event-stream"));
+    let data: &str = text
+        .lines()
+        .find_map(|line: &str| line.strip_prefix("data: "))
+        .expect("event data");
+    let reminder: Value = serde_json::from_str(data).expect("JSON event data");
+    assert!(reminder["id"].as_i64().expect("reminder id") <= fired.id);
+    assert_eq!(reminder["kind"], "manual");
+
+    server.stop().await?;
+    let mut rest: Vec<u8> = Vec::new();
+    stream.read_to_end(&mut rest).await.expect("stream closes");
+    Ok(())
+}
+
+#[tokio::test]
+async fn export_documents_import_into_another_server() -> CoreResult<()> {
+    let (runtime, mut source): (Arc<CoreRuntime>, HttpServer) = serve().await?;
+    let _: WaterIntake = runtime.api().log_water_intake(250, None).await?;
+    let _: ReminderEvent = runtime.api().trigger_hydration_reminder_once().await?;
+
+    let (status, document): (u16, Value) = request(&source, "GET", "/export", None).await;
+    assert_eq!(status, 200);
+    assert_eq!(document["format"], "desk-buddy-export");
+    assert_eq!(document["water_intake"][0]["amount_ml"], 250);
+    let only_intake: Value = request(&source, "GET", "/export?tables=water_intake", None).await.1;
+    assert!(only_intake.get("reminder_logs").is_none());
+    let (status, _): (u16, Value) = request(&source, "GET", "/export?tables=settings", None).await;
+    assert_eq!(status, 400);
+    source.stop().await?;
+
+    let (_target, mut target): (Arc<CoreRuntime>, HttpServer) = serve().await?;
+    let (status, imported): (u16, Value) =
+        request(&target, "POST", "/import?strategy=overwrite", Some(document.clone())).await;
+    assert_eq!(status, 200);
+    assert_eq!(imported["strategy"], "overwrite");
+    assert_eq!(imported["water_intake"], json!({"inserted": 1, "skipped": 0, "conflicting": 0}));
+    assert_eq!(imported["reminder_logs"]["inserted"], 1);
+    let again: Value = request(&target, "POST", "/import", Some(document)).await.1;
+    assert_eq!(again["water_intake"], json!({"inserted": 0, "skipped": 1, "conflicting": 0}));
+
+    let (status, unsupported): (u16, Value) =
+        request(&target, "POST", "/import", Some(json!({"format": "desk-buddy-export", "version": 99}))).await;
+    assert_eq!(status, 400);
+    assert!(unsupported["error"].as_str().expect("message").starts_with("unsupported export"));
+    target.stop().await
+}
+
+#[tokio::test]
+async fn requests_for_other_hosts_are_refused() -> CoreResult<()> {
+    let (_runtime, mut server): (Arc<CoreRuntime>, HttpServer) = serve().await?;
+    let goal_status = async |headers: &str| -> u16 {
+        read_response(send_with_headers(&server, "GET", "/goal", headers, None).await).await.0
+    };
+
+    let (status, refused): (u16, Value) =
+        read_response(send_with_headers(&server, "GET", "/goal", "host: attacker.example\r\n", None).await).await;
+    assert_eq!(status, 403);
+    assert_eq!(refused["error"], "requests must be addressed to localhost or a loopback address");
+    assert_eq!(goal_status("host: localhost\r\norigin: http://attacker.example\r\n").await, 403);
+    assert_eq!(goal_status("").await, 403);
+
+    let by_address: String = format!("host: {}\r\norigin: http://localhost:3000\r\n", server.local_addr());
+    assert_eq!(goal_status(&by_address).await, 200);
+    assert_eq!(goal_status("host: [::1]:8080\r\n").await, 200);
+    server.stop().await
+}
+
+#[tokio::test]
+async fn only_loopback_addresses_are_served() -> CoreResult<()> {
+    let runtime: Arc<CoreRuntime> = Arc::new(CoreBuilder::new(CoreOptions::in_memory()).build().await?);
+    let exposed: CoreResult<HttpServer> = HttpServer::start(SocketAddr::from(([0, 0, 0, 0], 0)), runtime).await;
+    assert!(exposed.is_err());
+    Ok(())
+}
@@ -0,0 +1,176 @@
+//! Drives the IPC socket server with a local NDJSON client.
+#![cfg(unix)]
+
+use desk_buddy_core::adapters::inbound::ipc::{IpcServer, MAX_REQUEST_BYTES};
+use desk_buddy_core::{CoreBuilder, CoreOptions, CoreResult, CoreRuntime};
+use serde_json::{json, Value};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tempfile::TempDir;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, Lines};
+use tokio::net::unix::{OwnedReadHalf, OwnedWriteHalf};
+use tokio::net::UnixStream;
+
+/// Line-oriented test client for one socket connection.
+struct Client {
+    lines: Lines<BufReader<OwnedReadHalf>>,
+    writer: OwnedWriteHalf,
+}
+
+impl Client {
+    async fn connect(server: &IpcServer) -> Client {
+        let stream: UnixStream = UnixStream::connect(server.socket_path()).await.expect("server listening");
+        let (reader, writer): (OwnedReadHalf, OwnedWriteHalf) = stream.into_split();
+        Client {
+            lines: BufReader::new(reader).lines(),
+            writer,
+        }
+    }
+
+    async fn send_raw(&mut self, line: &str) {
+        self.writer.write_all(format!("{line}\n").as_bytes()).await.expect("socket writable");
+    }
+
+    async fn read(&mut self) -> Value {
+        let line: String = self
+            .lines
+            .next_line()
+            .await
+            .expect("socket readable")
+            .expect("server replied");
+        serde_json::from_str(&line).expect("reply is JSON")
+    }
+
+    async fn request(&mut self, request: Value) -> Value {
+        self.send_raw(&request.to_string()).await;
+        self.read().await
+    }
+}
+
+/// Starts an in-memory runtime with no output sinks and serves it from a temp socket.
+async fn serve() -> CoreResult<(TempDir, IpcServer)> {
+    let dir: TempDir = tempfile::tempdir().expect("temp dir");
+    let options: CoreOptions = CoreOptions {
+        reminder_sinks: Vec::new(),
+        ..CoreOptions::in_memory()
+    };
+    let runtime: Arc<CoreRuntime> = Arc::new(CoreBuilder::new(options).build().await?);
+    let server: IpcServer = IpcServer::start(dir.path().join("desk-buddy.sock"), runtime).await?;
+    Ok((dir, server))
+}
+
+#[tokio::test]
+async fn requests_run_core_operations() -> CoreResult<()> {
+    let (_dir, mut server): (TempDir, IpcServer) = serve().await?;
+    let mut client: Client = Client::connect(&server).await;
+
+    let triggered: Value = client.request(json!({"id": 1, "command": "trigger"})).await;
+    assert_eq!(triggered["id"], 1);
+    assert_eq!(triggered["ok"], true);
+    assert_eq!(triggered["result"]["kind"], "manual");
+    assert_eq!(triggered["result"]["actions"], json!(["drink", "snooze", "dismiss"]));
+
+    let interval: Value = client.request(json!({"command": "set_interval", "minutes": 0})).await;
+    assert_eq!(interval["result"], json!({"interval_minutes": 1}));
+
+    let intake: Value = client
+        .request(json!({"command": "log_intake", "amount_ml": 250, "source": "glass"}))
+        .await;
+    assert_eq!(intake["result"]["amount_ml"], 250);
+    assert_eq!(intake["result"]["source"], "glass");
+
+    let status: Value = client.request(json!({"command": "status"})).await;
+    assert_eq!(status["result"]["state"], "stopped");
+    assert_eq!(status["result"]["health"], "healthy");
+
+    let paused: Value = client
+        .request(json!({"command": "pause", "length": {"minutes": 60}}))
+        .await;
+    assert_eq!(paused["result"]["indefinite"], false);
+    assert!(paused["result"]["until"].is_string());
+    let forever: Value = client.request(json!({"command": "pause"})).await;
+    assert_eq!(forever["result"], json!({"until": null, "indefinite": true}));
+    let resumed: Value = client.request(json!({"id": "r", "command": "resume"})).await;
+    assert_eq!(resumed, json!({"id": "r", "ok": true, "result": null}));
+
+    server.stop().await
+}
+
+#[tokio::test]
+async fn bad_requests_get_error_replies_and_keep_the_connection() -> CoreResult<()> {
+    let (_dir, mut server): (TempDir, IpcServer) = serve().await?;
+    let mut client: Client = Client::connect(&server).await;
+
+    client.send_raw("not json").await;
+    let malformed: Value = client.read().await;
+    assert_eq!(malformed["ok"], false);
+    assert!(malformed["error"].as_str().expect("message").starts_with("invalid request"));
+
+    let unknown: Value = client
+        .request(json!({"command": "log_intake", "amount_ml": 250, "source": "bucket"}))
+        .await;
+    assert!(unknown["error"].as_str().expect("message").contains("unknown intake source"));
+
+    let rejected: Value = client
+        .request(json!({"id": 7, "command": "log_intake", "amount_ml": 0}))
+        .await;
+    assert_eq!(rejected["id"], 7);
+    assert_eq!(rejected["error"], "invalid intake amount: 0 ml (expected 1-5000 ml)");
+
+    let still_open: Value = client.request(json!({"command": "status"})).await;
+    assert_eq!(still_open["ok"], true);
+    server.stop().await
+}
+
+#[tokio::test]
+async fn overlong_requests_close_the_connection() -> CoreResult<()> {
+    let (_dir, mut server): (TempDir, IpcServer) = serve().await?;
+    let mut client: Client = Client::connect(&server).await;
+
+    let padding: String = " ".repeat(usize::try_from(MAX_REQUEST_BYTES).expect("small limit"));
+    let _: std::io::Result<()> = client.writer.write_all(format!("{padding}{{}}\n").as_bytes()).await;
+    let rejected: Value = client.read().await;
+    assert_eq!(rejected["ok"], false);
+    assert_eq!(rejected["error"], format!("invalid request: longer than {MAX_REQUEST_BYTES} bytes"));
+    // Unread bytes left behind may turn the close into a reset.
+    assert!(!matches!(client.lines.next_line().await, Ok(Some(_))));
+
+    let mut next: Client = Client::connect(&server).await;
+    assert_eq!(next.request(json!({"command": "status"})).await["ok"], true);
+    server.stop().await
+}
+
+#[tokio::test]
+async fn subscribers_receive_pushed_reminder_events() -> CoreResult<()> {
+    let (_dir, mut server): (TempDir, IpcServer) = serve().await?;
+    let mut listener: Client = Client::connect(&server).await;
+    let mut caller: Client = Client::connect(&server).await;
+
+    let subscribed: Value = listener.request(json!({"command": "subscribe"})).await;
+    assert_eq!(subscribed["result"], json!({"subscribed": true}));
+    let triggered: Value = caller.request(json!({"command": "trigger"})).await;
+
+    let pushed: Value = listener.read().await;
+    assert_eq!(pushed["event"], "reminder");
+    assert_eq!(pushed["reminder"], triggered["result"]);
+    server.stop().await
+}
+
+#[tokio::test]
+async fn stop_removes_the_socket_and_stale_sockets_are_replaced() -> CoreResult<()> {
+    let (dir, mut server): (TempDir, IpcServer) = serve().await?;
+    let socket_path: PathBuf = server.socket_path().to_path_buf();
+    let mut client: Client = Client::connect(&server).await;
+    assert_eq!(client.request(json!({"command": "status"})).await["ok"], true);
+
+    server.stop().await?;
+    assert!(!socket_path.exists());
+    assert!(client.lines.next_line().await.expect("clean close").is_none());
+
+    // A socket file nobody listens on, as left behind by a crash.
+    drop(std::os::unix::net::UnixListener::bind(&socket_path).expect("bind stale socket"));
+    let runtime: Arc<CoreRuntime> = Arc::new(CoreBuilder::new(CoreOptions::in_memory()).build().await?);
+    let mut replacement: IpcServer = IpcServer::start(dir.path().join("desk-buddy.sock"), runtime.clone()).await?;
+    assert!(IpcServer::start(&socket_path, runtime).await.is_err());
+    replacement.stop().await
+}